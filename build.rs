@@ -12,10 +12,13 @@ fn main() {
 
     if compiler >= 80 {
         println!("cargo:rustc-check-cfg=cfg(no_alloc_crate)");
+        println!("cargo:rustc-check-cfg=cfg(no_const_panic)");
         println!("cargo:rustc-check-cfg=cfg(no_const_vec_new)");
         println!("cargo:rustc-check-cfg=cfg(no_exhaustive_int_match)");
+        println!("cargo:rustc-check-cfg=cfg(no_lazy_lock)");
         println!("cargo:rustc-check-cfg=cfg(no_non_exhaustive)");
         println!("cargo:rustc-check-cfg=cfg(no_nonzero_bitscan)");
+        println!("cargo:rustc-check-cfg=cfg(no_once_cell)");
         println!("cargo:rustc-check-cfg=cfg(no_str_strip_prefix)");
         println!("cargo:rustc-check-cfg=cfg(no_track_caller)");
         println!("cargo:rustc-check-cfg=cfg(no_unsafe_op_in_unsafe_fn_lint)");
@@ -73,6 +76,24 @@ fn main() {
         // https://doc.rust-lang.org/std/num/struct.NonZeroU64.html#method.trailing_zeros
         println!("cargo:rustc-cfg=no_nonzero_bitscan");
     }
+
+    if compiler < 57 {
+        // panic! in const fn.
+        // https://blog.rust-lang.org/2021/12/02/Rust-1.57.0.html#panic-in-const-contexts
+        println!("cargo:rustc-cfg=no_const_panic");
+    }
+
+    if compiler < 70 {
+        // core::cell::OnceCell.
+        // https://blog.rust-lang.org/2023/06/01/Rust-1.70.0.html#oncecell-and-oncelock
+        println!("cargo:rustc-cfg=no_once_cell");
+    }
+
+    if compiler < 80 {
+        // std::sync::LazyLock.
+        // https://blog.rust-lang.org/2024/07/25/Rust-1.80.0.html#lazycell-and-lazylock
+        println!("cargo:rustc-cfg=no_lazy_lock");
+    }
 }
 
 fn rustc_minor_version() -> Option<u32> {
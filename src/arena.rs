@@ -0,0 +1,101 @@
+use crate::error::ErrorKind;
+use crate::identifier::Identifier;
+use crate::{parse, BuildMetadata, Error, Prerelease, Version};
+
+/// A version whose pre-release and build metadata are stored in an arena
+/// owned by the caller, rather than in allocations of their own.
+///
+/// Parsing millions of versions in one batch with [`Version::parse`] makes a
+/// heap allocation for every long pre-release or build metadata. Parsing them
+/// as `ArenaVersion` instead copies that text into an arena such as
+/// [bumpalo]'s `Bump`, which is freed all at once when the batch is done.
+/// An `ArenaVersion` is `Copy` and can be converted to an owned [`Version`]
+/// when one needs to outlive the arena.
+///
+/// [bumpalo]: https://docs.rs/bumpalo
+///
+/// # Example
+///
+/// ```
+/// use semver::ArenaVersion;
+///
+/// // With bumpalo this would be `|s| bump.alloc_str(s)`.
+/// let alloc = |s: &str| -> &'static str { Box::leak(Box::from(s)) };
+///
+/// let version = ArenaVersion::parse("1.0.0-rc.1+g8e6aa2a", alloc).unwrap();
+/// assert_eq!(version.pre(), "rc.1");
+/// assert_eq!(version.build(), "g8e6aa2a");
+/// assert_eq!(version.to_version().to_string(), "1.0.0-rc.1+g8e6aa2a");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ArenaVersion<'a> {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pre: &'a str,
+    build: &'a str,
+}
+
+impl<'a> ArenaVersion<'a> {
+    /// Parse a version, copying its pre-release and build metadata into the
+    /// arena with `alloc`.
+    ///
+    /// `alloc` is given the text to copy and returns the copy; with bumpalo
+    /// this is `|s| bump.alloc_str(s)`. It is not called for a version with
+    /// neither pre-release nor build metadata.
+    ///
+    /// # Errors
+    ///
+    /// Possible reasons for the parse to fail are the same as for
+    /// [`Version::parse`]. In addition, it is an error for `alloc` to return
+    /// a string different from the one it was given.
+    pub fn parse<F>(text: &str, mut alloc: F) -> Result<Self, Error>
+    where
+        F: FnMut(&str) -> &'a str,
+    {
+        let (major, minor, patch, pre, build) = parse::version_parts(text)?;
+        let mut copy = |part: &str| -> Result<&'a str, Error> {
+            if part.is_empty() {
+                return Ok("");
+            }
+            let copy = alloc(part);
+            if copy != part {
+                return Err(Error::new(ErrorKind::ArenaMismatch));
+            }
+            Ok(copy)
+        };
+        Ok(ArenaVersion {
+            major,
+            minor,
+            patch,
+            pre: copy(pre)?,
+            build: copy(build)?,
+        })
+    }
+
+    /// The pre-release, or the empty string if this is not a pre-release.
+    pub fn pre(&self) -> &'a str {
+        self.pre
+    }
+
+    /// The build metadata, or the empty string if there is none.
+    pub fn build(&self) -> &'a str {
+        self.build
+    }
+
+    /// Copy into a `Version` that does not borrow from the arena.
+    pub fn to_version(&self) -> Version {
+        // SAFETY: pre and build were validated by the parser.
+        Version {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease {
+                identifier: unsafe { Identifier::new_unchecked(self.pre) },
+            },
+            build: BuildMetadata {
+                identifier: unsafe { Identifier::new_unchecked(self.build) },
+            },
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::alloc::sync::Arc;
+use crate::backport::*;
+use crate::error::ErrorKind;
+use crate::parse::Error;
+use crate::{BuildMetadata, Prerelease, Version};
+use arrow::array::{Array, ArrayRef, StringArray, StructArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, Fields};
+
+/// Fields of the struct column produced by [`versions_to_arrow`].
+///
+/// `major`, `minor` and `patch` are non-nullable `UInt64`. `pre` and `build`
+/// are nullable `Utf8`, null when the version has no pre-release or no build
+/// metadata.
+///
+/// Sorting by `major`, `minor`, `patch` and then `pre` with nulls last orders
+/// releases after their pre-releases, as precedence does. The engine
+/// compares `pre` as text, which agrees with precedence as long as the
+/// pre-releases being compared do not contain numeric identifiers of
+/// different lengths: text orders `beta.10` before `beta.2`.
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub fn arrow_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("major", DataType::UInt64, false),
+        Field::new("minor", DataType::UInt64, false),
+        Field::new("patch", DataType::UInt64, false),
+        Field::new("pre", DataType::Utf8, true),
+        Field::new("build", DataType::Utf8, true),
+    ])
+}
+
+/// Encode versions as an Arrow struct column, one child array per part, for
+/// writing to Parquet or handing to a query engine.
+///
+/// See [`arrow_fields`] for the layout.
+///
+/// # Example
+///
+/// ```
+/// use semver::{versions_from_arrow, versions_to_arrow, Version};
+///
+/// let versions = vec![
+///     Version::parse("1.0.0-rc.1").unwrap(),
+///     Version::parse("1.0.0+build.5").unwrap(),
+/// ];
+/// let array = versions_to_arrow(&versions);
+/// assert_eq!(array.num_columns(), 5);
+/// assert_eq!(versions_from_arrow(&array).unwrap(), versions);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub fn versions_to_arrow(versions: &[Version]) -> StructArray {
+    let major = UInt64Array::from_iter_values(versions.iter().map(|v| v.major));
+    let minor = UInt64Array::from_iter_values(versions.iter().map(|v| v.minor));
+    let patch = UInt64Array::from_iter_values(versions.iter().map(|v| v.patch));
+    let pre = versions
+        .iter()
+        .map(|v| non_empty(v.pre.as_str()))
+        .collect::<StringArray>();
+    let build = versions
+        .iter()
+        .map(|v| non_empty(v.build.as_str()))
+        .collect::<StringArray>();
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(major),
+        Arc::new(minor),
+        Arc::new(patch),
+        Arc::new(pre),
+        Arc::new(build),
+    ];
+    StructArray::new(arrow_fields(), columns, None)
+}
+
+/// Decode a struct column in the layout of [`arrow_fields`] back into
+/// versions, validating the pre-release and build metadata.
+///
+/// Columns are looked up by name, so the struct may have other fields, in
+/// any order. Fails if a column is missing or has the wrong type, or if a row
+/// of the struct or of `major`, `minor` or `patch` is null.
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub fn versions_from_arrow(array: &StructArray) -> Result<Vec<Version>, Error> {
+    let major = column::<UInt64Array>(array, "major")?;
+    let minor = column::<UInt64Array>(array, "minor")?;
+    let patch = column::<UInt64Array>(array, "patch")?;
+    let pre = column::<StringArray>(array, "pre")?;
+    let build = column::<StringArray>(array, "build")?;
+
+    let mut versions = Vec::with_capacity(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) || major.is_null(i) || minor.is_null(i) || patch.is_null(i) {
+            return Err(Error::new(ErrorKind::InvalidArrowArray));
+        }
+        let mut version = Version::new(major.value(i), minor.value(i), patch.value(i));
+        if pre.is_valid(i) {
+            version.pre = Prerelease::new(pre.value(i))?;
+        }
+        if build.is_valid(i) {
+            version.build = BuildMetadata::new(build.value(i))?;
+        }
+        versions.push(version);
+    }
+    Ok(versions)
+}
+
+fn non_empty(string: &str) -> Option<&str> {
+    if string.is_empty() {
+        None
+    } else {
+        Some(string)
+    }
+}
+
+fn column<'a, T: 'static>(array: &'a StructArray, name: &str) -> Result<&'a T, Error> {
+    array
+        .column_by_name(name)
+        .and_then(|column| column.as_any().downcast_ref::<T>())
+        .ok_or_else(|| Error::new(ErrorKind::InvalidArrowArray))
+}
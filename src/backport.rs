@@ -1,23 +1,76 @@
 #[cfg(no_str_strip_prefix)] // rustc <1.45
 pub(crate) trait StripPrefixExt {
-    fn strip_prefix(&self, ch: char) -> Option<&str>;
+    fn strip_prefix<P: StripPattern>(&self, pattern: P) -> Option<&str>;
+    fn strip_suffix<P: StripPattern>(&self, pattern: P) -> Option<&str>;
 }
 
 #[cfg(no_str_strip_prefix)]
-impl StripPrefixExt for str {
-    fn strip_prefix(&self, ch: char) -> Option<&str> {
-        if self.starts_with(ch) {
-            Some(&self[ch.len_utf8()..])
+pub(crate) trait StripPattern {
+    fn prefix_len(&self, string: &str) -> Option<usize>;
+    fn suffix_len(&self, string: &str) -> Option<usize>;
+}
+
+#[cfg(no_str_strip_prefix)]
+impl StripPattern for char {
+    fn prefix_len(&self, string: &str) -> Option<usize> {
+        if string.starts_with(*self) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+
+    fn suffix_len(&self, string: &str) -> Option<usize> {
+        if string.ends_with(*self) {
+            Some(self.len_utf8())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(no_str_strip_prefix)]
+impl<'a> StripPattern for &'a str {
+    fn prefix_len(&self, string: &str) -> Option<usize> {
+        if string.starts_with(*self) {
+            Some(self.len())
+        } else {
+            None
+        }
+    }
+
+    fn suffix_len(&self, string: &str) -> Option<usize> {
+        if string.ends_with(*self) {
+            Some(self.len())
         } else {
             None
         }
     }
 }
 
+#[cfg(no_str_strip_prefix)]
+impl StripPrefixExt for str {
+    fn strip_prefix<P: StripPattern>(&self, pattern: P) -> Option<&str> {
+        let len = pattern.prefix_len(self)?;
+        Some(&self[len..])
+    }
+
+    fn strip_suffix<P: StripPattern>(&self, pattern: P) -> Option<&str> {
+        let len = pattern.suffix_len(self)?;
+        Some(&self[..self.len() - len])
+    }
+}
+
+pub(crate) use crate::alloc::format;
+pub(crate) use crate::alloc::string::String;
 pub(crate) use crate::alloc::vec::Vec;
 
 #[cfg(no_alloc_crate)] // rustc <1.36
 pub(crate) mod alloc {
     pub use std::alloc;
+    pub use std::collections;
+    pub use std::format;
+    pub use std::string;
+    pub use std::sync;
     pub use std::vec;
 }
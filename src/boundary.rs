@@ -0,0 +1,51 @@
+use crate::backport::*;
+use crate::{Version, VersionReq};
+use core::ops::Bound;
+
+/// A version at which a requirement stops or starts matching, returned by
+/// [`VersionReq::boundaries`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BoundaryEvent {
+    /// The version at the boundary, with build metadata removed.
+    pub version: Version,
+    /// Whether `version` itself is on the matching side of the boundary.
+    pub inclusive: bool,
+    /// Whether matching versions lie above or below the boundary.
+    pub side: BoundarySide,
+    /// Index into [`VersionReq::comparators`] of the comparator that imposes
+    /// the boundary.
+    pub comparator: usize,
+}
+
+/// Which side of a [`BoundaryEvent`] the matching versions are on.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BoundarySide {
+    /// The boundary is a lower bound: versions above it match.
+    Lower,
+    /// The boundary is an upper bound: versions below it match.
+    Upper,
+}
+
+pub(crate) fn boundaries(req: &VersionReq) -> Vec<BoundaryEvent> {
+    let mut events = Vec::new();
+    for (i, cmp) in req.comparators.iter().enumerate() {
+        let mut push = |bound, side| {
+            let (version, inclusive) = match bound {
+                Bound::Included(version) => (version, true),
+                Bound::Excluded(version) => (version, false),
+                Bound::Unbounded => return,
+            };
+            events.push(BoundaryEvent {
+                version,
+                inclusive,
+                side,
+                comparator: i,
+            });
+        };
+        push(cmp.lower_bound(), BoundarySide::Lower);
+        push(cmp.upper_bound(), BoundarySide::Upper);
+    }
+    // Stable, so events at the same version stay in comparator order.
+    events.sort_by(|a, b| a.version.cmp_precedence(&b.version));
+    events
+}
@@ -0,0 +1,90 @@
+use crate::backport::*;
+use crate::error::ErrorKind;
+use crate::parse::Error;
+use crate::precedence;
+use crate::{BuildMetadata, Prerelease, Version};
+use bson::{Bson, Document};
+use core::convert::TryFrom;
+use core::fmt::Write;
+
+/// Stores the version as a BSON string, such as `"1.4.2-rc.1"`.
+impl From<Version> for Bson {
+    fn from(version: Version) -> Self {
+        Bson::String(format!("{}", version))
+    }
+}
+
+/// Reads a version stored either as a BSON string, or as a document with
+/// integer fields `major`, `minor` and `patch` and optional string fields
+/// `pre` and `build`.
+impl TryFrom<Bson> for Version {
+    type Error = Error;
+
+    fn try_from(bson: Bson) -> Result<Self, Self::Error> {
+        Version::try_from(&bson)
+    }
+}
+
+impl<'a> TryFrom<&'a Bson> for Version {
+    type Error = Error;
+
+    fn try_from(bson: &'a Bson) -> Result<Self, Self::Error> {
+        match bson {
+            Bson::String(string) => Version::parse(string),
+            Bson::Document(document) => from_document(document),
+            _ => Err(Error::new(ErrorKind::InvalidBson)),
+        }
+    }
+}
+
+fn from_document(document: &Document) -> Result<Version, Error> {
+    let mut version = Version::new(
+        number(document, "major")?,
+        number(document, "minor")?,
+        number(document, "patch")?,
+    );
+    match document.get("pre") {
+        None | Some(Bson::Null) => {}
+        Some(Bson::String(pre)) => version.pre = Prerelease::new(pre)?,
+        Some(_) => return Err(Error::new(ErrorKind::InvalidBson)),
+    }
+    match document.get("build") {
+        None | Some(Bson::Null) => {}
+        Some(Bson::String(build)) => version.build = BuildMetadata::new(build)?,
+        Some(_) => return Err(Error::new(ErrorKind::InvalidBson)),
+    }
+    Ok(version)
+}
+
+fn number(document: &Document, key: &str) -> Result<u64, Error> {
+    let value = match document.get(key) {
+        Some(Bson::Int32(value)) => i64::from(*value),
+        Some(Bson::Int64(value)) => *value,
+        _ => return Err(Error::new(ErrorKind::InvalidBson)),
+    };
+    if value < 0 {
+        return Err(Error::new(ErrorKind::InvalidBson));
+    }
+    Ok(value as u64)
+}
+
+// Layout of the key, chosen so that byte order is precedence order:
+//
+//   - major, minor and patch as 20 zero-padded digits each, separated by '.';
+//   - then '~' for a release, or '-' followed by the pre-release sort key for
+//     a pre-release, which sorts first.
+pub(crate) fn sort_key(version: &Version) -> String {
+    let mut key = String::new();
+    let _ = write!(
+        key,
+        "{:020}.{:020}.{:020}",
+        version.major, version.minor, version.patch,
+    );
+    if version.pre.is_empty() {
+        key.push('~');
+    } else {
+        key.push('-');
+        key.push_str(&precedence::prerelease_sort_key(&version.pre));
+    }
+    key
+}
@@ -0,0 +1,90 @@
+use crate::backport::*;
+use crate::error::ErrorKind;
+use crate::{BuildMetadata, Error, Prerelease, Version};
+use core::cmp::Ordering;
+use core::u64;
+
+pub(crate) fn major(version: &Version) -> Option<Version> {
+    Some(Version::new(version.major.checked_add(1)?, 0, 0))
+}
+
+pub(crate) fn minor(version: &Version) -> Option<Version> {
+    Some(Version::new(
+        version.major,
+        version.minor.checked_add(1)?,
+        0,
+    ))
+}
+
+pub(crate) fn patch(version: &Version) -> Option<Version> {
+    Some(Version::new(
+        version.major,
+        version.minor,
+        version.patch.checked_add(1)?,
+    ))
+}
+
+// On overflow the numbers stay as they are, which is still the release of
+// `version` and so never lower in precedence.
+pub(crate) fn saturate(version: &Version, bumped: Option<Version>) -> Version {
+    bumped.unwrap_or_else(|| Version::new(version.major, version.minor, version.patch))
+}
+
+// The release of lowest precedence that is greater than `version`.
+pub(crate) fn next_release(version: &Version) -> Option<Version> {
+    if version.pre.is_empty() {
+        patch(version)
+            .or_else(|| minor(version))
+            .or_else(|| major(version))
+    } else {
+        Some(Version::new(version.major, version.minor, version.patch))
+    }
+}
+
+// No pre-release is lower than `0`, and of the pre-releases that extend the
+// identifiers of `version.pre`, none is lower than appending `.0`.
+pub(crate) fn successor(version: &Version) -> Option<Version> {
+    let mut next = next_release(version)?;
+    next.pre = if version.pre.is_empty() {
+        Prerelease::new("0").unwrap()
+    } else {
+        Prerelease::new(&format!("{}.0", version.pre)).unwrap()
+    };
+    Some(next)
+}
+
+// The reverse of `successor`. Versions not produced by it have no immediate
+// predecessor: below a release or a pre-release ending in anything but `.0`
+// there are always longer pre-releases that are greater than any given one.
+pub(crate) fn predecessor(version: &Version) -> Option<Version> {
+    let pre = version.pre.as_str();
+    if pre == "0" {
+        let (major, minor, patch) = match (version.major, version.minor, version.patch) {
+            (0, 0, 0) => return None,
+            (major, 0, 0) => (major - 1, u64::MAX, u64::MAX),
+            (major, minor, 0) => (major, minor - 1, u64::MAX),
+            (major, minor, patch) => (major, minor, patch - 1),
+        };
+        Some(Version::new(major, minor, patch))
+    } else if pre.ends_with(".0") {
+        let mut prev = Version::new(version.major, version.minor, version.patch);
+        prev.pre = Prerelease::new(&pre[..pre.len() - 2]).unwrap();
+        Some(prev)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn next_channel(version: &Version, channel: &str) -> Result<Version, Error> {
+    let promoted = Version {
+        major: version.major,
+        minor: version.minor,
+        patch: version.patch,
+        pre: Prerelease::new(&format!("{}.1", channel))?,
+        build: BuildMetadata::EMPTY,
+    };
+    if promoted.cmp_precedence(version) != Ordering::Greater {
+        return Err(Error::new(ErrorKind::ChannelNotAfter));
+    }
+    Ok(promoted)
+}
@@ -0,0 +1,109 @@
+use crate::{Version, VersionReq};
+use std::collections::{HashMap, VecDeque};
+
+/// A bounded memo of [`VersionReq::matches`] results.
+///
+/// Resolvers tend to evaluate the same requirement against the same
+/// candidate version many times while exploring assignments. The cache holds
+/// up to `capacity` results and evicts the oldest entry to make room for a
+/// new one. Looking up a pair that is already cached does not allocate.
+///
+/// # Example
+///
+/// ```
+/// use semver::{MatchCache, Version, VersionReq};
+///
+/// let mut cache = MatchCache::new(1024);
+/// let req = VersionReq::parse("^1.2").unwrap();
+/// let version = Version::parse("1.4.0").unwrap();
+///
+/// assert!(cache.matches(&req, &version));
+/// assert!(cache.matches(&req, &version));
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+#[derive(Clone, Debug)]
+pub struct MatchCache {
+    results: HashMap<VersionReq, HashMap<Version, bool>>,
+    // Insertion order, for eviction.
+    order: VecDeque<(VersionReq, Version)>,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl MatchCache {
+    /// Create a cache holding at most `capacity` results. A capacity of zero
+    /// caches nothing.
+    pub fn new(capacity: usize) -> Self {
+        MatchCache {
+            results: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Evaluate whether `version` satisfies `req`, using the cached result if
+    /// there is one.
+    pub fn matches(&mut self, req: &VersionReq, version: &Version) -> bool {
+        if let Some(&result) = self.results.get(req).and_then(|map| map.get(version)) {
+            self.hits += 1;
+            return result;
+        }
+
+        self.misses += 1;
+        let result = req.matches(version);
+        if self.capacity == 0 {
+            return result;
+        }
+
+        if self.order.len() == self.capacity {
+            if let Some((old_req, old_version)) = self.order.pop_front() {
+                if let Some(map) = self.results.get_mut(&old_req) {
+                    map.remove(&old_version);
+                    if map.is_empty() {
+                        self.results.remove(&old_req);
+                    }
+                }
+            }
+        }
+        self.results
+            .entry(req.clone())
+            .or_default()
+            .insert(version.clone(), result);
+        self.order.push_back((req.clone(), version.clone()));
+        result
+    }
+
+    /// Number of calls to [`matches`][Self::matches] answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of calls to [`matches`][Self::matches] that had to evaluate the
+    /// requirement.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Number of results currently cached.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Forget every cached result. The hit and miss counts are kept.
+    pub fn clear(&mut self) {
+        self.results.clear();
+        self.order.clear();
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{PrecedenceKey, Version};
+#[cfg(not(no_once_cell))]
+use core::cell::OnceCell;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// A [`Version`] that remembers its [`PrecedenceKey`] once computed, so that
+/// comparing the same value over and over does not split and classify its
+/// pre-release identifiers every time.
+///
+/// This pays off for values that are compared many times over their
+/// lifetime, such as keys of a `BTreeMap` or entries of a `BinaryHeap`. The
+/// key is computed on the first comparison. `CachedVersion` has the same
+/// ordering, equality and hash as the wrapped `Version`, and dereferences to
+/// it. There is no mutable access to the wrapped version, which would make
+/// the cached key stale; use [`into_inner`][Self::into_inner] and wrap again.
+///
+/// Clippy's `mutable_key_type` lint fires on maps keyed by `CachedVersion`
+/// because the cache is interior mutability. Ordering and hashing never
+/// depend on whether the key has been computed yet, so the lint can safely be
+/// allowed.
+///
+/// # Example
+///
+/// ```
+/// use semver::{CachedVersion, Version};
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap = ["1.0.0-beta.2", "1.0.0-beta.11", "0.9.0"]
+///     .iter()
+///     .map(|v| CachedVersion::new(v.parse::<Version>().unwrap()))
+///     .collect::<BinaryHeap<_>>();
+///
+/// assert_eq!(heap.pop().unwrap().to_string(), "1.0.0-beta.11");
+/// assert_eq!(heap.pop().unwrap().pre.as_str(), "beta.2");
+/// ```
+#[derive(Clone)]
+pub struct CachedVersion {
+    version: Version,
+    #[cfg(not(no_once_cell))]
+    key: OnceCell<PrecedenceKey>,
+    // Without OnceCell the key is computed up front.
+    #[cfg(no_once_cell)] // rustc <1.70
+    key: PrecedenceKey,
+}
+
+impl CachedVersion {
+    pub fn new(version: Version) -> Self {
+        CachedVersion {
+            #[cfg(not(no_once_cell))]
+            key: OnceCell::new(),
+            #[cfg(no_once_cell)]
+            key: PrecedenceKey::new(&version),
+            version,
+        }
+    }
+
+    /// The precedence key of the wrapped version, computing it if this is
+    /// the first time it is needed.
+    pub fn precedence_key(&self) -> &PrecedenceKey {
+        #[cfg(not(no_once_cell))]
+        return self.key.get_or_init(|| PrecedenceKey::new(&self.version));
+        #[cfg(no_once_cell)]
+        return &self.key;
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    pub fn into_inner(self) -> Version {
+        self.version
+    }
+}
+
+impl From<Version> for CachedVersion {
+    fn from(version: Version) -> Self {
+        CachedVersion::new(version)
+    }
+}
+
+impl Deref for CachedVersion {
+    type Target = Version;
+
+    fn deref(&self) -> &Self::Target {
+        &self.version
+    }
+}
+
+impl PartialEq for CachedVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+    }
+}
+
+impl Eq for CachedVersion {}
+
+impl PartialOrd for CachedVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CachedVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Same as Version's Ord: precedence, then build metadata.
+        self.precedence_key()
+            .cmp(other.precedence_key())
+            .then_with(|| self.version.build.cmp(&other.version.build))
+    }
+}
+
+impl Hash for CachedVersion {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.version.hash(hasher);
+    }
+}
+
+impl Display for CachedVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.version, formatter)
+    }
+}
+
+impl Debug for CachedVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.version, formatter)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::backport::*;
+use crate::{desugar_caret, desugar_tilde, desugar_wildcard};
+use crate::{Comparator, Op, Prerelease, VersionReq};
+use core::fmt::Write;
+
+pub(crate) fn canonical_string(req: &VersionReq) -> String {
+    let mut primitive = Vec::new();
+    for cmp in &req.comparators {
+        push_primitive(cmp, &mut primitive);
+    }
+
+    primitive.retain(|cmp| !is_unbounded(cmp));
+    primitive.sort_by(|a, b| {
+        let a_key = (a.major, a.minor, a.patch, &a.pre, op_rank(a.op));
+        let b_key = (b.major, b.minor, b.patch, &b.pre, op_rank(b.op));
+        a_key.cmp(&b_key)
+    });
+    primitive.dedup();
+
+    if primitive.is_empty() {
+        return String::from("*");
+    }
+    let mut out = String::new();
+    for (i, cmp) in primitive.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        let _ = write!(out, "{}", cmp);
+    }
+    out
+}
+
+// Rewrite cmp as comparators whose op is one of `=`, `>`, `>=`, `<`, `<=` and
+// whose major, minor and patch are all present, and which together match the
+// same versions.
+pub(crate) fn push_primitive(cmp: &Comparator, out: &mut Vec<Comparator>) {
+    let full = cmp.minor.is_some() && cmp.patch.is_some();
+    let mut push_pair = |(lower, upper): (Comparator, Option<Comparator>)| {
+        out.push(lower);
+        out.extend(upper);
+    };
+    match cmp.op {
+        Op::Exact | Op::Greater | Op::LessEq if full => out.push(cmp.clone()),
+        Op::Exact | Op::Wildcard => push_pair(desugar_wildcard(cmp)),
+        Op::Tilde => push_pair(desugar_tilde(cmp)),
+        Op::Caret => push_pair(desugar_caret(cmp)),
+        Op::Greater => match desugar_wildcard(cmp).1 {
+            // `>I.J` is the same as `>=I.(J+1).0`.
+            Some(upper) => out.push(Comparator {
+                op: Op::GreaterEq,
+                pre: Prerelease::EMPTY,
+                ..upper
+            }),
+            // Nothing is greater than the last possible major.minor line.
+            None => out.push(cmp.clone()),
+        },
+        Op::LessEq => out.extend(desugar_wildcard(cmp).1),
+        Op::GreaterEq | Op::Less => out.push(Comparator {
+            op: cmp.op,
+            major: cmp.major,
+            minor: Some(cmp.minor.unwrap_or(0)),
+            patch: Some(cmp.patch.unwrap_or(0)),
+            pre: cmp.pre.clone(),
+        }),
+        #[cfg(no_non_exhaustive)]
+        Op::__NonExhaustive => unreachable!(),
+    }
+}
+
+// `>=0.0.0`, which every version without a pre-release satisfies.
+fn is_unbounded(cmp: &Comparator) -> bool {
+    cmp.op == Op::GreaterEq
+        && cmp.major == 0
+        && cmp.minor == Some(0)
+        && cmp.patch == Some(0)
+        && cmp.pre.is_empty()
+}
+
+fn op_rank(op: Op) -> u8 {
+    match op {
+        Op::Exact => 0,
+        Op::Greater => 1,
+        Op::GreaterEq => 2,
+        Op::Less => 3,
+        Op::LessEq => 4,
+        _ => 5,
+    }
+}
@@ -0,0 +1,125 @@
+use crate::backport::*;
+use crate::{select, Prerelease, SelectionContext, Version};
+
+/// Rule for selecting the version a release channel points at.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ChannelRule {
+    /// The highest version that has no pre-release identifier.
+    Stable,
+    /// The highest pre-release version whose first pre-release identifier is
+    /// equal to the given tag. For example `Prerelease("beta")` selects among
+    /// `1.0.0-beta`, `1.0.0-beta.2`, `1.1.0-beta.1.nightly`, but not
+    /// `1.0.0-rc.1` or `1.0.0-betamax`.
+    Prerelease(String),
+    /// The highest version, whether or not it is a pre-release.
+    Any,
+}
+
+/// Mapping from named release channels (dist-tags), such as `"latest"` or
+/// `"beta"`, to the rule that selects the version each channel points at.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Channels, Version};
+///
+/// let versions = ["1.0.0", "1.1.0-beta.1", "1.1.0-beta.2", "1.1.0-nightly.20240601"]
+///     .iter()
+///     .map(|v| v.parse::<Version>().unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let channels = Channels::standard();
+/// assert_eq!(channels.resolve("latest", &versions).unwrap().to_string(), "1.0.0");
+/// assert_eq!(channels.resolve("beta", &versions).unwrap().to_string(), "1.1.0-beta.2");
+/// assert!(channels.resolve("canary", &versions).is_none());
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Channels {
+    rules: Vec<(String, ChannelRule)>,
+}
+
+impl ChannelRule {
+    /// Select the version this rule points at among `versions`, or `None` if
+    /// no version qualifies.
+    ///
+    /// Versions are compared by [precedence][Version::cmp_precedence]. Among
+    /// versions that differ only in build metadata, the last one wins.
+    pub fn select<'a>(&self, versions: &'a [Version]) -> Option<&'a Version> {
+        select(versions, self, &SelectionContext::new())
+    }
+
+    pub(crate) fn accepts(&self, version: &Version) -> bool {
+        match self {
+            ChannelRule::Stable => version.pre.is_empty(),
+            ChannelRule::Prerelease(tag) => channel_of(&version.pre) == Some(tag.as_str()),
+            ChannelRule::Any => true,
+        }
+    }
+}
+
+impl Channels {
+    /// Create an empty set of channels.
+    pub fn new() -> Self {
+        Channels { rules: Vec::new() }
+    }
+
+    /// The channels most registries define: `"latest"` selecting the highest
+    /// stable version, and `"beta"` and `"nightly"` selecting the highest
+    /// pre-release tagged with that channel name.
+    pub fn standard() -> Self {
+        let mut channels = Channels::new();
+        channels.insert("latest", ChannelRule::Stable);
+        channels.insert("beta", ChannelRule::Prerelease(String::from("beta")));
+        channels.insert("nightly", ChannelRule::Prerelease(String::from("nightly")));
+        channels
+    }
+
+    /// Define the channel `name`, returning the rule it previously had if it
+    /// was already defined.
+    pub fn insert(&mut self, name: &str, rule: ChannelRule) -> Option<ChannelRule> {
+        for entry in &mut self.rules {
+            if entry.0 == name {
+                return Some(core::mem::replace(&mut entry.1, rule));
+            }
+        }
+        self.rules.push((String::from(name), rule));
+        None
+    }
+
+    /// Remove the channel `name`, returning its rule if it was defined.
+    pub fn remove(&mut self, name: &str) -> Option<ChannelRule> {
+        let index = self.rules.iter().position(|entry| entry.0 == name)?;
+        Some(self.rules.remove(index).1)
+    }
+
+    /// Look up the rule for channel `name`.
+    pub fn rule(&self, name: &str) -> Option<&ChannelRule> {
+        self.rules
+            .iter()
+            .find(|entry| entry.0 == name)
+            .map(|entry| &entry.1)
+    }
+
+    /// Iterate over the defined channel names, in insertion order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|entry| entry.0.as_str())
+    }
+
+    /// Select the version that channel `name` points at among `versions`.
+    ///
+    /// Returns `None` if the channel is not defined or if no version qualifies
+    /// for it.
+    pub fn resolve<'a>(&self, name: &str, versions: &'a [Version]) -> Option<&'a Version> {
+        self.rule(name)?.select(versions)
+    }
+}
+
+/// The first dot-separated identifier of a pre-release, which by convention
+/// names the release channel (`beta` in `1.0.0-beta.2`).
+pub(crate) fn channel_of(pre: &Prerelease) -> Option<&str> {
+    if pre.is_empty() {
+        None
+    } else {
+        pre.as_str().split('.').next()
+    }
+}
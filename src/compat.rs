@@ -0,0 +1,162 @@
+use crate::error::ErrorKind;
+use crate::{BuildMetadata, Comparator, Error, Op, Prerelease, Version, VersionReq};
+use core::convert::TryFrom;
+
+// Both crates accept the same pre-release and build metadata syntax, so text
+// valid in one is valid in the other.
+
+impl From<upstream::Version> for Version {
+    fn from(version: upstream::Version) -> Self {
+        Version {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            pre: Prerelease::from(version.pre),
+            build: BuildMetadata::from(version.build),
+        }
+    }
+}
+
+impl From<Version> for upstream::Version {
+    fn from(version: Version) -> Self {
+        upstream::Version {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            pre: upstream::Prerelease::from(version.pre),
+            build: upstream::BuildMetadata::from(version.build),
+        }
+    }
+}
+
+/// Fails if a comparator has an operator added to upstream after this crate
+/// was written.
+impl TryFrom<upstream::VersionReq> for VersionReq {
+    type Error = Error;
+
+    fn try_from(req: upstream::VersionReq) -> Result<Self, Self::Error> {
+        let comparators = req
+            .comparators
+            .into_iter()
+            .map(Comparator::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(VersionReq { comparators })
+    }
+}
+
+impl From<VersionReq> for upstream::VersionReq {
+    fn from(req: VersionReq) -> Self {
+        upstream::VersionReq {
+            comparators: req
+                .comparators
+                .into_iter()
+                .map(upstream::Comparator::from)
+                .collect(),
+        }
+    }
+}
+
+/// Fails if the operator was added to upstream after this crate was written.
+impl TryFrom<upstream::Comparator> for Comparator {
+    type Error = Error;
+
+    fn try_from(cmp: upstream::Comparator) -> Result<Self, Self::Error> {
+        Ok(Comparator {
+            op: Op::try_from(cmp.op)?,
+            major: cmp.major,
+            minor: cmp.minor,
+            patch: cmp.patch,
+            pre: Prerelease::from(cmp.pre),
+        })
+    }
+}
+
+/// Build metadata, which upstream's comparators cannot hold and which does
+/// not affect matching, is dropped.
+impl From<Comparator> for upstream::Comparator {
+    fn from(cmp: Comparator) -> Self {
+        upstream::Comparator {
+            op: upstream::Op::from(cmp.op),
+            major: cmp.major,
+            minor: cmp.minor,
+            patch: cmp.patch,
+            pre: upstream::Prerelease::from(cmp.pre),
+        }
+    }
+}
+
+/// Fails on an operator added to upstream after this crate was written.
+impl TryFrom<upstream::Op> for Op {
+    type Error = Error;
+
+    fn try_from(op: upstream::Op) -> Result<Self, Self::Error> {
+        Ok(match op {
+            upstream::Op::Exact => Op::Exact,
+            upstream::Op::Greater => Op::Greater,
+            upstream::Op::GreaterEq => Op::GreaterEq,
+            upstream::Op::Less => Op::Less,
+            upstream::Op::LessEq => Op::LessEq,
+            upstream::Op::Tilde => Op::Tilde,
+            upstream::Op::Caret => Op::Caret,
+            upstream::Op::Wildcard => Op::Wildcard,
+            _ => return Err(Error::new(ErrorKind::UnsupportedUpstreamOp)),
+        })
+    }
+}
+
+impl From<Op> for upstream::Op {
+    fn from(op: Op) -> Self {
+        match op {
+            Op::Exact => upstream::Op::Exact,
+            Op::Greater => upstream::Op::Greater,
+            Op::GreaterEq => upstream::Op::GreaterEq,
+            Op::Less => upstream::Op::Less,
+            Op::LessEq => upstream::Op::LessEq,
+            Op::Tilde => upstream::Op::Tilde,
+            Op::Caret => upstream::Op::Caret,
+            Op::Wildcard => upstream::Op::Wildcard,
+            #[cfg(no_non_exhaustive)]
+            Op::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+impl From<upstream::Prerelease> for Prerelease {
+    fn from(pre: upstream::Prerelease) -> Self {
+        if pre.is_empty() {
+            Prerelease::EMPTY
+        } else {
+            Prerelease::new(pre.as_str()).unwrap()
+        }
+    }
+}
+
+impl From<Prerelease> for upstream::Prerelease {
+    fn from(pre: Prerelease) -> Self {
+        if pre.is_empty() {
+            upstream::Prerelease::EMPTY
+        } else {
+            upstream::Prerelease::new(pre.as_str()).unwrap()
+        }
+    }
+}
+
+impl From<upstream::BuildMetadata> for BuildMetadata {
+    fn from(build: upstream::BuildMetadata) -> Self {
+        if build.is_empty() {
+            BuildMetadata::EMPTY
+        } else {
+            BuildMetadata::new(build.as_str()).unwrap()
+        }
+    }
+}
+
+impl From<BuildMetadata> for upstream::BuildMetadata {
+    fn from(build: BuildMetadata) -> Self {
+        if build.is_empty() {
+            upstream::BuildMetadata::EMPTY
+        } else {
+            upstream::BuildMetadata::new(build.as_str()).unwrap()
+        }
+    }
+}
@@ -0,0 +1,410 @@
+use crate::backport::*;
+use crate::error::ErrorKind;
+use crate::{Comparator, Error, Op, Prerelease, Version, VersionCore, VersionReq};
+use core::u64;
+
+#[cfg(not(feature = "stats"))]
+use crate::eval;
+#[cfg(feature = "stats")]
+use crate::stats::{Counters, MatchStats};
+
+// First byte of every blob produced by CompiledReq::to_bytes. Bump this when
+// the layout changes so that old blobs are rejected instead of misread.
+const FORMAT: u8 = 1;
+
+const MIN: VersionCore = VersionCore::new(0, 0, 0);
+const MAX: VersionCore = VersionCore::new(u64::MAX, u64::MAX, u64::MAX);
+
+/// A [`VersionReq`] precompiled for fast matching.
+///
+/// The release versions satisfying any requirement form a single contiguous
+/// range, so matching a version without pre-release is two comparisons of
+/// major.minor.patch. Pre-release versions can only ever match requirements
+/// that have a comparator with a pre-release of its own; for the rest they
+/// are rejected immediately, and otherwise they are evaluated against the
+/// original comparators.
+///
+/// A compiled requirement can be serialized to a compact binary blob with
+/// [`to_bytes`][Self::to_bytes] and loaded back with
+/// [`from_bytes`][Self::from_bytes] without parsing the requirement again.
+///
+/// Matching gives the same result as [`VersionReq::matches`] for every
+/// requirement produced by the parser.
+///
+/// # Example
+///
+/// ```
+/// use semver::{CompiledReq, Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=1.2.3, <1.8.0").unwrap();
+/// let bytes = req.compile().to_bytes();
+///
+/// let compiled = CompiledReq::from_bytes(&bytes).unwrap();
+/// assert!(compiled.matches(&Version::parse("1.4.0").unwrap()));
+/// assert!(!compiled.matches(&Version::parse("1.8.0").unwrap()));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CompiledReq {
+    // Inclusive range of release versions that match, or None if none do.
+    releases: Option<(VersionCore, VersionCore)>,
+    // The original comparators, kept only if a pre-release can match.
+    prerelease: Option<VersionReq>,
+    #[cfg(feature = "stats")]
+    stats: Counters,
+}
+
+impl CompiledReq {
+    pub fn new(req: &VersionReq) -> Self {
+        let mut releases = Some((MIN, MAX));
+        for cmp in &req.comparators {
+            releases = match (releases, release_range(cmp)) {
+                (Some((lo, hi)), Some((cmp_lo, cmp_hi))) => {
+                    let lo = lo.max(cmp_lo);
+                    let hi = hi.min(cmp_hi);
+                    if lo <= hi {
+                        Some((lo, hi))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+        }
+
+        let has_pre = req
+            .comparators
+            .iter()
+            .any(|cmp| cmp.patch.is_some() && !cmp.pre.is_empty());
+        let prerelease = if has_pre { Some(req.clone()) } else { None };
+
+        CompiledReq {
+            releases,
+            prerelease,
+            #[cfg(feature = "stats")]
+            stats: Counters::default(),
+        }
+    }
+
+    /// Evaluate whether the given `Version` satisfies the requirement this
+    /// was compiled from.
+    pub fn matches(&self, version: &Version) -> bool {
+        #[cfg(feature = "stats")]
+        self.stats.call();
+        if version.pre.is_empty() {
+            let core = VersionCore::from(version);
+            match self.releases {
+                Some((lo, hi)) => lo <= core && core <= hi,
+                None => false,
+            }
+        } else {
+            match &self.prerelease {
+                #[cfg(not(feature = "stats"))]
+                Some(req) => eval::matches_req(req, version),
+                #[cfg(feature = "stats")]
+                Some(req) => self.stats.matches_prerelease(req, version),
+                None => {
+                    #[cfg(feature = "stats")]
+                    self.stats.prerelease_gate();
+                    false
+                }
+            }
+        }
+    }
+
+    /// Counts of the work done by [`matches`][Self::matches] since this
+    /// requirement was compiled or the counts were last reset.
+    ///
+    /// A clone starts out with the counts of the original and counts
+    /// separately from then on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionReq};
+    ///
+    /// let compiled = VersionReq::parse(">=1.2.0-beta, <2").unwrap().compile();
+    /// compiled.matches(&Version::parse("1.4.0").unwrap());
+    /// compiled.matches(&Version::parse("1.2.0-rc.1").unwrap());
+    /// compiled.matches(&Version::parse("1.3.0-rc.1").unwrap());
+    ///
+    /// let stats = compiled.stats();
+    /// assert_eq!(stats.calls, 3);
+    /// assert_eq!(stats.comparator_evaluations, 4);
+    /// assert_eq!(stats.prerelease_gate, 1);
+    /// ```
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn stats(&self) -> MatchStats {
+        self.stats.get()
+    }
+
+    /// Set the counts returned by [`stats`][Self::stats] back to zero.
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// Serialize to a compact binary blob, which [`from_bytes`][Self::from_bytes]
+    /// loads back.
+    ///
+    /// The first byte identifies the layout. Blobs are portable across
+    /// platforms but not necessarily across versions of this crate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.push(FORMAT);
+        match self.releases {
+            None => out.push(0),
+            Some((lo, hi)) => {
+                out.push(1);
+                for &n in &[lo.major, lo.minor, lo.patch, hi.major, hi.minor, hi.patch] {
+                    write_varint(&mut out, n);
+                }
+            }
+        }
+        let comparators = match &self.prerelease {
+            Some(req) => req.comparators.as_slice(),
+            None => &[],
+        };
+        write_varint(&mut out, comparators.len() as u64);
+        for cmp in comparators {
+            out.push(op_to_byte(cmp.op));
+            write_varint(&mut out, cmp.major);
+            out.push(cmp.minor.is_some() as u8 | (cmp.patch.is_some() as u8) << 1);
+            for n in cmp.minor.iter().chain(&cmp.patch) {
+                write_varint(&mut out, *n);
+            }
+            write_varint(&mut out, cmp.pre.len() as u64);
+            out.extend_from_slice(cmp.pre.as_bytes());
+        }
+        out
+    }
+
+    /// Load a compiled requirement serialized by [`to_bytes`][Self::to_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Fails if `bytes` was not produced by [`to_bytes`][Self::to_bytes] of
+    /// a compatible version of this crate.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        decode(&mut Reader { bytes }).ok_or_else(|| Error::new(ErrorKind::InvalidCompiledReq))
+    }
+}
+
+fn decode(input: &mut Reader) -> Option<CompiledReq> {
+    if input.byte()? != FORMAT {
+        return None;
+    }
+
+    let releases = match input.byte()? {
+        0 => None,
+        1 => {
+            let lo = VersionCore::new(input.varint()?, input.varint()?, input.varint()?);
+            let hi = VersionCore::new(input.varint()?, input.varint()?, input.varint()?);
+            Some((lo, hi))
+        }
+        _ => return None,
+    };
+
+    let len = input.varint()?;
+    let mut comparators = Vec::new();
+    for _ in 0..len {
+        let op = op_from_byte(input.byte()?)?;
+        let major = input.varint()?;
+        let present = input.byte()?;
+        if present > 0b11 {
+            return None;
+        }
+        let minor = if present & 0b01 != 0 {
+            Some(input.varint()?)
+        } else {
+            None
+        };
+        let patch = if present & 0b10 != 0 {
+            Some(input.varint()?)
+        } else {
+            None
+        };
+        let pre_len = input.varint()?;
+        if pre_len > input.bytes.len() as u64 {
+            return None;
+        }
+        let (pre, rest) = input.bytes.split_at(pre_len as usize);
+        input.bytes = rest;
+        let pre = Prerelease::new(core::str::from_utf8(pre).ok()?).ok()?;
+        comparators.push(Comparator {
+            op,
+            major,
+            minor,
+            patch,
+            pre,
+        });
+    }
+
+    if !input.bytes.is_empty() {
+        return None;
+    }
+
+    let prerelease = if comparators.is_empty() {
+        None
+    } else {
+        Some(VersionReq { comparators })
+    };
+
+    Some(CompiledReq {
+        releases,
+        prerelease,
+        #[cfg(feature = "stats")]
+        stats: Counters::default(),
+    })
+}
+
+// Inclusive range of release versions matching cmp, or None if none do. Only
+// the shapes of comparator the parser produces are handled precisely.
+fn release_range(cmp: &Comparator) -> Option<(VersionCore, VersionCore)> {
+    let first = VersionCore::new(cmp.major, cmp.minor.unwrap_or(0), cmp.patch.unwrap_or(0));
+    let last = VersionCore::new(
+        cmp.major,
+        cmp.minor.unwrap_or(u64::MAX),
+        cmp.patch.unwrap_or(u64::MAX),
+    );
+    let end_of_major = VersionCore::new(cmp.major, u64::MAX, u64::MAX);
+    let end_of_minor = VersionCore::new(cmp.major, cmp.minor.unwrap_or(u64::MAX), u64::MAX);
+
+    // A release never equals a comparator that has a pre-release, and it is
+    // greater than every pre-release of its own major.minor.patch.
+    let pre = cmp.patch.is_some() && !cmp.pre.is_empty();
+
+    match cmp.op {
+        Op::Exact | Op::Wildcard => {
+            if pre {
+                None
+            } else {
+                Some((first, last))
+            }
+        }
+        Op::Greater => {
+            if pre {
+                Some((first, MAX))
+            } else {
+                Some((successor(last)?, MAX))
+            }
+        }
+        Op::GreaterEq => Some((first, MAX)),
+        Op::Less => Some((MIN, predecessor(first)?)),
+        Op::LessEq => {
+            if pre {
+                Some((MIN, predecessor(first)?))
+            } else {
+                Some((MIN, last))
+            }
+        }
+        Op::Tilde => Some((first, end_of_minor)),
+        Op::Caret => match (cmp.minor, cmp.patch) {
+            (None, _) => Some((first, end_of_major)),
+            _ if cmp.major > 0 => Some((first, end_of_major)),
+            (Some(_), None) => Some((first, end_of_minor)),
+            (Some(minor), Some(_)) => {
+                if minor > 0 {
+                    Some((first, end_of_minor))
+                } else {
+                    Some((first, first))
+                }
+            }
+        },
+        #[cfg(no_non_exhaustive)]
+        Op::__NonExhaustive => unreachable!(),
+    }
+}
+
+fn successor(core: VersionCore) -> Option<VersionCore> {
+    if let Some(patch) = core.patch.checked_add(1) {
+        Some(VersionCore::new(core.major, core.minor, patch))
+    } else if let Some(minor) = core.minor.checked_add(1) {
+        Some(VersionCore::new(core.major, minor, 0))
+    } else {
+        Some(VersionCore::new(core.major.checked_add(1)?, 0, 0))
+    }
+}
+
+fn predecessor(core: VersionCore) -> Option<VersionCore> {
+    if let Some(patch) = core.patch.checked_sub(1) {
+        Some(VersionCore::new(core.major, core.minor, patch))
+    } else if let Some(minor) = core.minor.checked_sub(1) {
+        Some(VersionCore::new(core.major, minor, u64::MAX))
+    } else {
+        Some(VersionCore::new(
+            core.major.checked_sub(1)?,
+            u64::MAX,
+            u64::MAX,
+        ))
+    }
+}
+
+fn op_to_byte(op: Op) -> u8 {
+    match op {
+        Op::Exact => 0,
+        Op::Greater => 1,
+        Op::GreaterEq => 2,
+        Op::Less => 3,
+        Op::LessEq => 4,
+        Op::Tilde => 5,
+        Op::Caret => 6,
+        Op::Wildcard => 7,
+        #[cfg(no_non_exhaustive)]
+        Op::__NonExhaustive => unreachable!(),
+    }
+}
+
+fn op_from_byte(byte: u8) -> Option<Op> {
+    Some(match byte {
+        0 => Op::Exact,
+        1 => Op::Greater,
+        2 => Op::GreaterEq,
+        3 => Op::Less,
+        4 => Op::LessEq,
+        5 => Op::Tilde,
+        6 => Op::Caret,
+        7 => Op::Wildcard,
+        _ => return None,
+    })
+}
+
+// Unsigned LEB128: seven bits per byte, least significant group first, with
+// the high bit set on every byte except the last.
+pub(crate) fn write_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+pub(crate) struct Reader<'a> {
+    pub(crate) bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    pub(crate) fn byte(&mut self) -> Option<u8> {
+        let (&first, rest) = self.bytes.split_first()?;
+        self.bytes = rest;
+        Some(first)
+    }
+
+    pub(crate) fn varint(&mut self) -> Option<u64> {
+        let mut n = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift == 63 && bits > 1 || shift > 63 {
+                return None;
+            }
+            n |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Some(n);
+            }
+            shift += 7;
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::backport::*;
+use crate::Version;
+
+// In the order they are proposed when nothing has been typed yet.
+const OPERATORS: [&str; 7] = ["^", "~", "=", ">=", ">", "<=", "<"];
+
+/// Propose completions of a requirement that is being typed.
+///
+/// Only the last comma-separated comparator of `partial` is completed, and
+/// each proposal is the whole of `partial` with that comparator completed,
+/// so that it can replace the input as is.
+///
+/// - With nothing typed after the last comma, the operators are proposed,
+///   followed by the known versions.
+///
+/// - With only an operator typed, the known versions are proposed after it,
+///   and `>` and `<` are also completed to `>=` and `<=`.
+///
+/// - With part of a version typed, the known versions that start with it are
+///   proposed, first cut off after the minor version, like `1.4`, then in
+///   full.
+///
+/// Known versions are proposed newest first without duplicates, and without
+/// build metadata. Pre-releases are left out unless the typed version has a
+/// `-`.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let known = ["1.2.3", "1.4.0", "1.4.1", "1.5.0-rc.1", "2.0.0"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     semver::complete(">=1.0, <1.", &known),
+///     [">=1.0, <1.4", ">=1.0, <1.2", ">=1.0, <1.4.1", ">=1.0, <1.4.0", ">=1.0, <1.2.3"],
+/// );
+/// assert_eq!(semver::complete("^2", &known), ["^2.0", "^2.0.0"]);
+/// ```
+pub fn complete(partial: &str, known_versions: &[Version]) -> Vec<String> {
+    let start = match partial.rfind(',') {
+        Some(comma) => partial.len() - partial[comma + 1..].trim_start_matches(' ').len(),
+        None => partial.len() - partial.trim_start_matches(' ').len(),
+    };
+    let (prefix, piece) = partial.split_at(start);
+
+    let op_len = OPERATORS
+        .iter()
+        .filter(|op| piece.starts_with(*op))
+        .map(|op| op.len())
+        .max()
+        .unwrap_or(0);
+    let text = piece[op_len..].trim_start_matches(' ');
+    let head = &partial[..partial.len() - text.len()];
+
+    let mut known: Vec<&Version> = known_versions
+        .iter()
+        .filter(|version| version.pre.is_empty() || text.contains('-'))
+        .collect();
+    known.sort_by(|a, b| b.cmp_precedence(a));
+
+    let mut completions = Vec::new();
+    let mut push = |completion: String| {
+        if !completions.contains(&completion) {
+            completions.push(completion);
+        }
+    };
+
+    if text.is_empty() {
+        if op_len == 0 {
+            for op in &OPERATORS {
+                push(format!("{}{}", prefix, op));
+            }
+        } else if piece == ">" || piece == "<" {
+            push(format!("{}=", partial));
+        }
+        for version in &known {
+            push(format!("{}{}", head, full(version)));
+        }
+        return completions;
+    }
+
+    for version in &known {
+        let minor = format!("{}.{}", version.major, version.minor);
+        if minor.len() > text.len() && minor.starts_with(text) {
+            push(format!("{}{}", head, minor));
+        }
+    }
+    for version in &known {
+        let full = full(version);
+        if full.len() > text.len() && full.starts_with(text) {
+            push(format!("{}{}", head, full));
+        }
+    }
+    completions
+}
+
+fn full(version: &Version) -> String {
+    if version.pre.is_empty() {
+        format!("{}.{}.{}", version.major, version.minor, version.patch)
+    } else {
+        format!(
+            "{}.{}.{}-{}",
+            version.major, version.minor, version.patch, version.pre,
+        )
+    }
+}
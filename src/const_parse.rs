@@ -0,0 +1,223 @@
+// Compile-time parsing backing the crate_version! and lazy_req! macros.
+// Everything here is a const fn mirroring the grammar accepted by
+// Version::from_str and VersionReq::from_str in parse.rs; invalid input is a
+// panic, which in a const context is a compile error.
+
+use crate::identifier::Identifier;
+use crate::{BuildMetadata, Prerelease, Version};
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct ConstVersion {
+    text: &'static str,
+    major: u64,
+    minor: u64,
+    patch: u64,
+    // Byte ranges of the pre-release and build metadata within `text`.
+    pre: (usize, usize),
+    build: (usize, usize),
+}
+
+impl ConstVersion {
+    #[allow(clippy::missing_panics_doc)]
+    pub const fn parse(text: &'static str) -> Self {
+        let bytes = text.as_bytes();
+        let (major, i) = numeric(bytes, 0);
+        let i = dot(bytes, i);
+        let (minor, i) = numeric(bytes, i);
+        let i = dot(bytes, i);
+        let (patch, mut i) = numeric(bytes, i);
+
+        let mut pre = (i, i);
+        if i < bytes.len() && bytes[i] == b'-' {
+            let end = identifier(bytes, i + 1, bytes.len(), true);
+            pre = (i + 1, end);
+            i = end;
+        }
+
+        let mut build = (i, i);
+        if i < bytes.len() && bytes[i] == b'+' {
+            let end = identifier(bytes, i + 1, bytes.len(), false);
+            build = (i + 1, end);
+            i = end;
+        }
+
+        assert!(i == bytes.len(), "unexpected character in version");
+
+        ConstVersion {
+            text,
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        }
+    }
+
+    pub fn to_version(&self) -> Version {
+        Version {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease {
+                identifier: self.identifier(self.pre),
+            },
+            build: BuildMetadata {
+                identifier: self.identifier(self.build),
+            },
+        }
+    }
+
+    fn identifier(&self, range: (usize, usize)) -> Identifier {
+        // SAFETY: parse only accepts ASCII alphanumerics, '-' and '.' in the
+        // pre-release and build metadata ranges.
+        unsafe { Identifier::new_unchecked(&self.text[range.0..range.1]) }
+    }
+}
+
+const fn numeric(bytes: &[u8], start: usize) -> (u64, usize) {
+    let mut i = start;
+    let mut value = 0u64;
+    while i < bytes.len() && bytes[i] >= b'0' && bytes[i] <= b'9' {
+        assert!(value != 0 || i == start, "leading zero in version number");
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[i] - b'0') as u64) {
+                Some(value) => value,
+                None => panic!("version number exceeds u64::MAX"),
+            },
+            None => panic!("version number exceeds u64::MAX"),
+        };
+        i += 1;
+    }
+    assert!(i > start, "expected a version number");
+    (value, i)
+}
+
+const fn dot(bytes: &[u8], i: usize) -> usize {
+    if i < bytes.len() && bytes[i] == b'.' {
+        i + 1
+    } else {
+        panic!("expected '.' in version");
+    }
+}
+
+// Returns the end of the dot-separated identifier starting at `start`, which
+// stops at `end` at the latest.
+const fn identifier(bytes: &[u8], start: usize, end: usize, is_pre: bool) -> usize {
+    let mut i = start;
+    let mut segment_start = start;
+    let mut segment_has_nondigit = false;
+    loop {
+        let boundary = i == end || bytes[i] == b'.' || bytes[i] == b'+';
+        if boundary {
+            assert!(i > segment_start, "empty identifier segment in version");
+            if is_pre
+                && !segment_has_nondigit
+                && i - segment_start > 1
+                && bytes[segment_start] == b'0'
+            {
+                panic!("leading zero in pre-release identifier");
+            }
+            if i < end && bytes[i] == b'.' {
+                i += 1;
+                segment_start = i;
+                segment_has_nondigit = false;
+                continue;
+            }
+            return i;
+        }
+        match bytes[i] {
+            b'0'..=b'9' => {}
+            b'A'..=b'Z' | b'a'..=b'z' | b'-' => segment_has_nondigit = true,
+            _ => panic!("illegal character in version identifier"),
+        }
+        i += 1;
+    }
+}
+
+// Panics unless `text` is a requirement that VersionReq::parse accepts.
+pub const fn check_req(text: &str) {
+    let bytes = text.as_bytes();
+    let mut i = spaces(bytes, 0);
+    if i < bytes.len() && is_wildcard(bytes[i]) {
+        assert!(
+            spaces(bytes, i + 1) == bytes.len(),
+            "wildcard must be the only comparator in a version requirement",
+        );
+        return;
+    }
+
+    let mut count = 0;
+    loop {
+        count += 1;
+        assert!(count <= 32, "excessive number of version comparators");
+        i = spaces(bytes, comparator(bytes, i));
+        if i == bytes.len() {
+            return;
+        }
+        assert!(bytes[i] == b',', "expected comma after version comparator");
+        i = spaces(bytes, i + 1);
+    }
+}
+
+// Returns the end of the comparator starting at `start`.
+const fn comparator(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    if i < bytes.len() {
+        match bytes[i] {
+            b'=' | b'~' | b'^' => i += 1,
+            b'>' | b'<' => {
+                i += 1;
+                if i < bytes.len() && bytes[i] == b'=' {
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    i = spaces(bytes, i);
+
+    let mut end = i;
+    while end < bytes.len() && bytes[end] != b' ' && bytes[end] != b',' {
+        end += 1;
+    }
+
+    i = numeric(bytes, i).1;
+    let mut has_patch = false;
+    if i < end && bytes[i] == b'.' {
+        let minor_wildcard = i + 1 < end && is_wildcard(bytes[i + 1]);
+        i = if minor_wildcard {
+            i + 2
+        } else {
+            numeric(bytes, i + 1).1
+        };
+        if i < end && bytes[i] == b'.' {
+            if i + 1 < end && is_wildcard(bytes[i + 1]) {
+                i += 2;
+            } else {
+                assert!(!minor_wildcard, "unexpected character after wildcard");
+                i = numeric(bytes, i + 1).1;
+                has_patch = true;
+            }
+        }
+    }
+    if has_patch && i < end && bytes[i] == b'-' {
+        i = identifier(bytes, i + 1, end, true);
+    }
+    if has_patch && i < end && bytes[i] == b'+' {
+        i = identifier(bytes, i + 1, end, false);
+    }
+    assert!(i == end, "unexpected character in version requirement");
+    end
+}
+
+const fn spaces(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    i
+}
+
+const fn is_wildcard(byte: u8) -> bool {
+    byte == b'*' || byte == b'x' || byte == b'X'
+}
@@ -0,0 +1,56 @@
+use crate::Version;
+
+/// Component-wise difference between two versions, returned by
+/// [`Version::delta`].
+///
+/// Each field is the other version's number minus this version's, so a
+/// positive field means the other version is ahead in that component.
+/// Pre-release and build metadata are not part of the difference.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct VersionDelta {
+    pub major: i128,
+    pub minor: i128,
+    pub patch: i128,
+}
+
+impl VersionDelta {
+    pub(crate) fn new(from: &Version, to: &Version) -> Self {
+        VersionDelta {
+            major: to.major as i128 - from.major as i128,
+            minor: to.minor as i128 - from.minor as i128,
+            patch: to.patch as i128 - from.patch as i128,
+        }
+    }
+
+    /// A single number summarizing how far apart the two versions are: the
+    /// sum of the absolute differences of major, minor and patch.
+    ///
+    /// This is a heuristic for dashboards and the like, such as flagging
+    /// dependencies that fall far behind their latest release. It is zero
+    /// exactly when the versions have the same `major.minor.patch`, but it
+    /// does not weigh a major difference more than a minor one, so it says
+    /// nothing about compatibility.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let current = Version::parse("1.4.7").unwrap();
+    /// let latest = Version::parse("1.6.0").unwrap();
+    /// assert_eq!(current.delta(&latest).distance(), 9);
+    /// ```
+    pub fn distance(&self) -> u128 {
+        abs(self.major) + abs(self.minor) + abs(self.patch)
+    }
+}
+
+// Every field is the difference of two u64, so the magnitude fits u64 and
+// the sum of three fits u128.
+fn abs(n: i128) -> u128 {
+    if n < 0 {
+        -n as u128
+    } else {
+        n as u128
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Comparator, Op, Prerelease};
+
+/// Rewrite a caret comparator as a pair of primitive `>=` and `<` comparators.
+///
+/// A requirement consisting of just `cmp` matches exactly the same versions as
+/// a requirement consisting of the two returned comparators. The upper bound
+/// is written with a `-0` pre-release, the lowest possible pre-release, so
+/// that pre-releases of the excluded version are excluded too even when
+/// pre-release matching is relaxed &mdash; `^1.2.3` becomes `>=1.2.3,
+/// <2.0.0-0`.
+///
+/// The upper bound is `None` if it would not fit in u64, as in `^18446744073709551615`,
+/// which has no upper bound.
+///
+/// Only the version fields of `cmp` are looked at; its `op` is assumed to be
+/// `^` whatever it actually is.
+///
+/// # Example
+///
+/// ```
+/// use semver::Comparator;
+///
+/// let cmp = Comparator::parse("^0.2.3").unwrap();
+/// let (lower, upper) = semver::desugar_caret(&cmp);
+/// assert_eq!(lower.to_string(), ">=0.2.3");
+/// assert_eq!(upper.unwrap().to_string(), "<0.3.0-0");
+/// ```
+pub fn desugar_caret(cmp: &Comparator) -> (Comparator, Option<Comparator>) {
+    let upper = match (cmp.minor, cmp.patch) {
+        (Some(minor), Some(patch)) if cmp.major == 0 && minor == 0 => {
+            next(cmp.major, Some(minor), Some(patch))
+        }
+        (Some(minor), _) if cmp.major == 0 => next(cmp.major, Some(minor), None),
+        _ => next(cmp.major, None, None),
+    };
+    (lower(cmp), upper)
+}
+
+/// Rewrite a tilde comparator as a pair of primitive `>=` and `<` comparators.
+///
+/// See [`desugar_caret`] for the guarantees about the returned pair. For
+/// example `~1.2.3` becomes `>=1.2.3, <1.3.0-0`, and `~1` becomes `>=1.0.0,
+/// <2.0.0-0`.
+///
+/// Only the version fields of `cmp` are looked at; its `op` is assumed to be
+/// `~` whatever it actually is.
+pub fn desugar_tilde(cmp: &Comparator) -> (Comparator, Option<Comparator>) {
+    let upper = match cmp.minor {
+        Some(minor) => next(cmp.major, Some(minor), None),
+        None => next(cmp.major, None, None),
+    };
+    (lower(cmp), upper)
+}
+
+/// Rewrite a wildcard comparator as a pair of primitive comparators.
+///
+/// See [`desugar_caret`] for the guarantees about the returned pair. For
+/// example `1.2.*` becomes `>=1.2.0, <1.3.0-0`, and `1.*` becomes `>=1.0.0,
+/// <2.0.0-0`. A comparator with all of major, minor and patch present, which
+/// the parser never produces for `Op::Wildcard`, matches exactly the one
+/// version like `=I.J.K` does and becomes `>=I.J.K, <=I.J.K`.
+///
+/// Only the version fields of `cmp` are looked at; its `op` is assumed to be
+/// `*` whatever it actually is.
+pub fn desugar_wildcard(cmp: &Comparator) -> (Comparator, Option<Comparator>) {
+    let upper = match (cmp.minor, cmp.patch) {
+        (Some(_), Some(_)) => Some(Comparator {
+            op: Op::LessEq,
+            ..lower(cmp)
+        }),
+        (Some(minor), None) => next(cmp.major, Some(minor), None),
+        (None, _) => next(cmp.major, None, None),
+    };
+    (lower(cmp), upper)
+}
+
+fn lower(cmp: &Comparator) -> Comparator {
+    let pre = if cmp.minor.is_some() && cmp.patch.is_some() {
+        cmp.pre.clone()
+    } else {
+        Prerelease::EMPTY
+    };
+    Comparator {
+        op: Op::GreaterEq,
+        major: cmp.major,
+        minor: Some(cmp.minor.unwrap_or(0)),
+        patch: Some(cmp.patch.unwrap_or(0)),
+        pre,
+    }
+}
+
+// The comparator `<M.N.P-0` where M.N.P is the first version after the line
+// identified by major, minor, and patch. The least significant component
+// present is incremented, carrying into more significant components on
+// overflow.
+fn next(major: u64, minor: Option<u64>, patch: Option<u64>) -> Option<Comparator> {
+    let (major, minor, patch) = match (minor, patch) {
+        (Some(minor), Some(patch)) => bump_patch(major, minor, patch)?,
+        (Some(minor), None) => bump_minor(major, minor)?,
+        (None, _) => bump_major(major)?,
+    };
+    Some(Comparator {
+        op: Op::Less,
+        major,
+        minor: Some(minor),
+        patch: Some(patch),
+        pre: Prerelease::new("0").unwrap(),
+    })
+}
+
+fn bump_major(major: u64) -> Option<(u64, u64, u64)> {
+    Some((major.checked_add(1)?, 0, 0))
+}
+
+fn bump_minor(major: u64, minor: u64) -> Option<(u64, u64, u64)> {
+    match minor.checked_add(1) {
+        Some(minor) => Some((major, minor, 0)),
+        None => bump_major(major),
+    }
+}
+
+fn bump_patch(major: u64, minor: u64, patch: u64) -> Option<(u64, u64, u64)> {
+    match patch.checked_add(1) {
+        Some(patch) => Some((major, minor, patch)),
+        None => bump_minor(major, minor),
+    }
+}
@@ -0,0 +1,141 @@
+use crate::backport::*;
+use crate::{BuildMetadata, Comparator, Op, Version, VersionReq};
+use core::cmp::Ordering;
+use core::fmt::{self, Display};
+
+/// What changed between two requirements, returned by
+/// [`VersionReq::diff_display`].
+///
+/// Its `Display` impl describes the changes in one line, like ``raised `^1.2`
+/// to `^1.4`, added `<1.8` ``, for the description of a dependency update.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ReqDiff {
+    /// The changes, in the order of the new requirement's comparators, then
+    /// the removed comparators in their old order. Comparators present in
+    /// both requirements are not listed.
+    pub changes: Vec<ReqChange>,
+}
+
+/// One change to a comparator, as part of a [`ReqDiff`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReqChange {
+    /// A comparator only in the new requirement.
+    Added(Comparator),
+    /// A comparator only in the old requirement.
+    Removed(Comparator),
+    /// A comparator whose operator changed, like `~1.2` to `^1.2`, with or
+    /// without a change of version.
+    OperatorChanged { old: Comparator, new: Comparator },
+    /// A comparator with the same operator and a different version, like
+    /// `^1.2` to `^1.4`.
+    BoundChanged { old: Comparator, new: Comparator },
+}
+
+impl ReqDiff {
+    /// Whether the requirements have the same comparators, in any order.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl Display for ReqDiff {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.changes.is_empty() {
+            return formatter.write_str("unchanged");
+        }
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(", ")?;
+            }
+            write!(formatter, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ReqChange {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReqChange::Added(new) => write!(formatter, "added `{}`", new),
+            ReqChange::Removed(old) => write!(formatter, "removed `{}`", old),
+            ReqChange::OperatorChanged { old, new } => {
+                write!(formatter, "changed `{}` to `{}`", old, new)
+            }
+            ReqChange::BoundChanged { old, new } => {
+                let verb = match bound(new).cmp_precedence(&bound(old)) {
+                    Ordering::Greater => "raised",
+                    Ordering::Less => "lowered",
+                    Ordering::Equal => "changed",
+                };
+                write!(formatter, "{} `{}` to `{}`", verb, old, new)
+            }
+        }
+    }
+}
+
+pub(crate) fn diff(old: &VersionReq, new: &VersionReq) -> ReqDiff {
+    let mut old_used: Vec<bool> = old.comparators.iter().map(|_| false).collect();
+    let mut paired: Vec<Option<usize>> = new.comparators.iter().map(|_| None).collect();
+
+    // Unchanged comparators first, so that they are not paired up with
+    // something else, then the same operator, then the same kind of bound.
+    for pass in 0..3 {
+        for (j, new_cmp) in new.comparators.iter().enumerate() {
+            if paired[j].is_some() {
+                continue;
+            }
+            paired[j] = (0..old.comparators.len()).find(|&i| {
+                let old_cmp = &old.comparators[i];
+                !old_used[i]
+                    && match pass {
+                        0 => old_cmp == new_cmp,
+                        1 => old_cmp.op == new_cmp.op,
+                        _ => side(old_cmp.op) == side(new_cmp.op),
+                    }
+            });
+            if let Some(i) = paired[j] {
+                old_used[i] = true;
+            }
+        }
+    }
+
+    let mut changes = Vec::new();
+    for (new_cmp, i) in new.comparators.iter().zip(paired) {
+        let new = new_cmp.clone();
+        match i.map(|i| old.comparators[i].clone()) {
+            None => changes.push(ReqChange::Added(new)),
+            Some(old) => {
+                if old.op != new.op {
+                    changes.push(ReqChange::OperatorChanged { old, new });
+                } else if old != new {
+                    changes.push(ReqChange::BoundChanged { old, new });
+                }
+            }
+        }
+    }
+    for (old_cmp, used) in old.comparators.iter().zip(old_used) {
+        if !used {
+            changes.push(ReqChange::Removed(old_cmp.clone()));
+        }
+    }
+    ReqDiff { changes }
+}
+
+// Operators that limit versions from below, from above, or from both sides.
+fn side(op: Op) -> u8 {
+    match op {
+        Op::Greater | Op::GreaterEq => 0,
+        Op::Less | Op::LessEq => 1,
+        _ => 2,
+    }
+}
+
+fn bound(cmp: &Comparator) -> Version {
+    Version {
+        major: cmp.major,
+        minor: cmp.minor.unwrap_or(0),
+        patch: cmp.patch.unwrap_or(0),
+        pre: cmp.pre.clone(),
+        build: BuildMetadata::EMPTY,
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use crate::{
+    ArenaVersion, BuildMetadata, Comparator, Op, PartialVersion, Prerelease, Version, VersionCore,
+    VersionReq,
+};
 use core::fmt::{self, Alignment, Debug, Display, Write};
 
 impl Display for Version {
@@ -30,6 +33,49 @@ impl Display for Version {
     }
 }
 
+impl Display for ArenaVersion<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.to_version(), formatter)
+    }
+}
+
+impl Display for VersionCore {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let do_display = |formatter: &mut fmt::Formatter| -> fmt::Result {
+            write!(formatter, "{}.{}.{}", self.major, self.minor, self.patch)
+        };
+
+        let do_len =
+            || -> usize { digits(self.major) + 1 + digits(self.minor) + 1 + digits(self.patch) };
+
+        pad(formatter, do_display, do_len)
+    }
+}
+
+impl Display for PartialVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let do_display = |formatter: &mut fmt::Formatter| -> fmt::Result {
+            write!(formatter, "{}", self.major)?;
+            if let Some(minor) = self.minor {
+                write!(formatter, ".{}", minor)?;
+                if let Some(patch) = self.patch {
+                    write!(formatter, ".{}", patch)?;
+                }
+            }
+            Ok(())
+        };
+
+        let do_len = || -> usize {
+            digits(self.major)
+                + self.minor.map_or(0, |minor| {
+                    1 + digits(minor) + self.patch.map_or(0, |patch| 1 + digits(patch))
+                })
+        };
+
+        pad(formatter, do_display, do_len)
+    }
+}
+
 impl Display for VersionReq {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         if self.comparators.is_empty() {
@@ -47,31 +93,41 @@ impl Display for VersionReq {
 
 impl Display for Comparator {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        let op = match self.op {
-            Op::Exact => "=",
-            Op::Greater => ">",
-            Op::GreaterEq => ">=",
-            Op::Less => "<",
-            Op::LessEq => "<=",
-            Op::Tilde => "~",
-            Op::Caret => "^",
-            Op::Wildcard => "",
-            #[cfg(no_non_exhaustive)]
-            Op::__NonExhaustive => unreachable!(),
-        };
-        formatter.write_str(op)?;
-        write!(formatter, "{}", self.major)?;
-        if let Some(minor) = &self.minor {
+        fmt_comparator(self, false, formatter)
+    }
+}
+
+pub(crate) fn fmt_comparator(
+    cmp: &Comparator,
+    space_after_op: bool,
+    formatter: &mut fmt::Formatter,
+) -> fmt::Result {
+    let op = cmp.op.as_str();
+    formatter.write_str(op)?;
+    if space_after_op && !op.is_empty() {
+        formatter.write_str(" ")?;
+    }
+    Display::fmt(&ComparatorVersion(cmp), formatter)
+}
+
+// The part of a comparator after its operator.
+pub(crate) struct ComparatorVersion<'a>(pub &'a Comparator);
+
+impl Display for ComparatorVersion<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let cmp = self.0;
+        write!(formatter, "{}", cmp.major)?;
+        if let Some(minor) = &cmp.minor {
             write!(formatter, ".{}", minor)?;
-            if let Some(patch) = &self.patch {
+            if let Some(patch) = &cmp.patch {
                 write!(formatter, ".{}", patch)?;
-                if !self.pre.is_empty() {
-                    write!(formatter, "-{}", self.pre)?;
+                if !cmp.pre.is_empty() {
+                    write!(formatter, "-{}", cmp.pre)?;
                 }
-            } else if self.op == Op::Wildcard {
+            } else if cmp.op == Op::Wildcard {
                 formatter.write_str(".*")?;
             }
-        } else if self.op == Op::Wildcard {
+        } else if cmp.op == Op::Wildcard {
             formatter.write_str(".*")?;
         }
         Ok(())
@@ -90,20 +146,25 @@ impl Display for BuildMetadata {
     }
 }
 
+// The Debug representations are the Display string wrapped in the type name,
+// on one line even in alternate `{:#?}` mode. They are meant to stay the same
+// across releases so that snapshot tests of structures containing versions do
+// not churn.
 impl Debug for Version {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        let mut debug = formatter.debug_struct("Version");
-        debug
-            .field("major", &self.major)
-            .field("minor", &self.minor)
-            .field("patch", &self.patch);
-        if !self.pre.is_empty() {
-            debug.field("pre", &self.pre);
-        }
-        if !self.build.is_empty() {
-            debug.field("build", &self.build);
-        }
-        debug.finish()
+        write!(formatter, "Version(\"{}\")", self)
+    }
+}
+
+impl Debug for VersionReq {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "VersionReq(\"{}\")", self)
+    }
+}
+
+impl Debug for Comparator {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Comparator(\"{}\")", self)
     }
 }
 
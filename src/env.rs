@@ -0,0 +1,146 @@
+use crate::parse::Error;
+use crate::{Version, VersionReq};
+use std::env::{self, VarError};
+use std::ffi::OsString;
+use std::fmt::{self, Display};
+
+/// Error returned by [`from_env`] and [`req_from_env`].
+///
+/// The Display impl names the variable, and for a value that does not parse,
+/// includes the raw value and the parse error, so it can be shown as is in a
+/// startup failure.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct EnvError {
+    var: String,
+    kind: EnvErrorKind,
+}
+
+#[derive(Debug)]
+enum EnvErrorKind {
+    Missing,
+    NotUnicode(OsString),
+    Invalid {
+        expected: &'static str,
+        value: String,
+        error: Error,
+    },
+}
+
+/// Read a version from the environment variable `var`.
+///
+/// # Example
+///
+/// ```
+/// # std::env::set_var("MIN_CLIENT_VERSION", "1.4.0");
+/// let min = semver::from_env("MIN_CLIENT_VERSION").unwrap();
+/// assert_eq!(min.to_string(), "1.4.0");
+///
+/// # std::env::set_var("MIN_CLIENT_VERSION", "1.4");
+/// let err = semver::from_env("MIN_CLIENT_VERSION").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "environment variable MIN_CLIENT_VERSION is not a valid version: \"1.4\": \
+///      unexpected end of input while parsing minor version number",
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn from_env(var: &str) -> Result<Version, EnvError> {
+    let value = read(var)?;
+    Version::parse(&value).map_err(|error| EnvError::invalid(var, "version", value, error))
+}
+
+/// Read a version requirement from the environment variable `var`.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn req_from_env(var: &str) -> Result<VersionReq, EnvError> {
+    let value = read(var)?;
+    VersionReq::parse(&value)
+        .map_err(|error| EnvError::invalid(var, "version requirement", value, error))
+}
+
+fn read(var: &str) -> Result<String, EnvError> {
+    env::var(var).map_err(|err| EnvError {
+        var: String::from(var),
+        kind: match err {
+            VarError::NotPresent => EnvErrorKind::Missing,
+            VarError::NotUnicode(value) => EnvErrorKind::NotUnicode(value),
+        },
+    })
+}
+
+impl EnvError {
+    fn invalid(var: &str, expected: &'static str, value: String, error: Error) -> Self {
+        EnvError {
+            var: String::from(var),
+            kind: EnvErrorKind::Invalid {
+                expected,
+                value,
+                error,
+            },
+        }
+    }
+
+    /// The name of the environment variable.
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
+    /// Whether the variable is not set, as opposed to set to a value that is
+    /// not valid.
+    pub fn is_missing(&self) -> bool {
+        match self.kind {
+            EnvErrorKind::Missing => true,
+            _ => false,
+        }
+    }
+
+    /// The raw value of the variable, if it is set and is valid unicode.
+    pub fn value(&self) -> Option<&str> {
+        match &self.kind {
+            EnvErrorKind::Invalid { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The error from parsing the value, if it is set and is valid unicode.
+    pub fn parse_error(&self) -> Option<&Error> {
+        match &self.kind {
+            EnvErrorKind::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for EnvError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            EnvErrorKind::Missing => {
+                write!(formatter, "environment variable {} is not set", self.var)
+            }
+            EnvErrorKind::NotUnicode(value) => write!(
+                formatter,
+                "environment variable {} is not valid unicode: {:?}",
+                self.var, value,
+            ),
+            EnvErrorKind::Invalid {
+                expected,
+                value,
+                error,
+            } => write!(
+                formatter,
+                "environment variable {} is not a valid {}: {:?}: {}",
+                self.var, expected, value, error,
+            ),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for EnvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            EnvErrorKind::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
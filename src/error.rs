@@ -14,6 +14,20 @@ pub(crate) enum ErrorKind {
     WildcardNotTheOnlyComparator(char),
     UnexpectedAfterWildcard,
     ExcessiveComparators,
+    UnknownRustChannel,
+    InvalidCompiledReq,
+    InvalidStableEncoding,
+    ChannelNotAfter,
+    ArenaMismatch,
+    CommitsPastMaxVersion,
+    #[cfg(feature = "arrow")]
+    InvalidArrowArray,
+    #[cfg(feature = "bson")]
+    InvalidBson,
+    #[cfg(feature = "compat")]
+    UnsupportedUpstreamOp,
+    #[cfg(feature = "zerocopy")]
+    InvalidRawVersion,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -23,6 +37,7 @@ pub(crate) enum Position {
     Patch,
     Pre,
     Build,
+    Numeric,
 }
 
 #[cfg(feature = "std")]
@@ -85,6 +100,38 @@ impl Display for Error {
             ErrorKind::ExcessiveComparators => {
                 formatter.write_str("excessive number of version comparators")
             }
+            ErrorKind::UnknownRustChannel => {
+                formatter.write_str("unrecognized Rust release channel in rustc version")
+            }
+            ErrorKind::InvalidCompiledReq => {
+                formatter.write_str("invalid or incompatible compiled version requirement")
+            }
+            ErrorKind::InvalidStableEncoding => {
+                formatter.write_str("invalid or unsupported stable version encoding")
+            }
+            ErrorKind::ChannelNotAfter => formatter
+                .write_str("pre-release channel does not come after the version's current one"),
+            ErrorKind::ArenaMismatch => {
+                formatter.write_str("arena returned a different string than it was given")
+            }
+            ErrorKind::CommitsPastMaxVersion => formatter
+                .write_str("no release after the highest possible version to number commits with"),
+            #[cfg(feature = "arrow")]
+            ErrorKind::InvalidArrowArray => {
+                formatter.write_str("Arrow array does not have the layout of a version column")
+            }
+            #[cfg(feature = "bson")]
+            ErrorKind::InvalidBson => formatter.write_str(
+                "expected a version string, or a document with integer major, minor and patch",
+            ),
+            #[cfg(feature = "compat")]
+            ErrorKind::UnsupportedUpstreamOp => {
+                formatter.write_str("operator from the semver crate has no equivalent")
+            }
+            #[cfg(feature = "zerocopy")]
+            ErrorKind::InvalidRawVersion => {
+                formatter.write_str("string table offset out of bounds or not UTF-8")
+            }
         }
     }
 }
@@ -97,6 +144,7 @@ impl Display for Position {
             Position::Patch => "patch version number",
             Position::Pre => "pre-release identifier",
             Position::Build => "build metadata",
+            Position::Numeric => "numeric identifier",
         })
     }
 }
@@ -1,47 +1,273 @@
+//! Evaluation of versions against comparators, for callers that keep
+//! comparators in storage of their own.
+//!
+//! [`VersionReq::matches`] and [`VersionReq::matches_with`] need the
+//! comparators in a `VersionReq`. A resolver that stores comparators
+//! elsewhere, such as in an arena or in columns of a table, can evaluate a
+//! borrowed slice of them with [`matches_all`] instead of building a
+//! `VersionReq` for each evaluation.
+
+use crate::channel::channel_of;
+use crate::impls;
 use crate::{Comparator, Op, Version, VersionReq};
+use core::cmp::Ordering;
+use core::slice;
+
+/// Options controlling how a version is evaluated against a requirement by
+/// [`VersionReq::matches_with`] and [`Comparator::matches_with`].
+///
+/// The default options evaluate exactly like [`VersionReq::matches`] and
+/// [`Comparator::matches`].
+///
+/// # Example
+///
+/// ```
+/// use semver::{MatchOptions, Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=2.0.0-beta.1, <3").unwrap();
+/// let beta = MatchOptions::new().channel(Some("beta"));
+///
+/// assert!(req.matches_with(&Version::parse("2.0.0-beta.2").unwrap(), &beta));
+/// assert!(!req.matches_with(&Version::parse("2.0.0-rc.1").unwrap(), &beta));
+/// assert!(req.matches_with(&Version::parse("2.1.0").unwrap(), &beta));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct MatchOptions<'a> {
+    include_prerelease: bool,
+    compare_build: bool,
+    channel: Option<&'a str>,
+    policy: PreReleasePolicy,
+    ignore_prerelease_case: bool,
+    plain_caret: bool,
+}
 
-pub(crate) fn matches_req(req: &VersionReq, ver: &Version) -> bool {
-    for cmp in &req.comparators {
-        if !matches_impl(cmp, ver) {
-            return false;
+impl<'a> MatchOptions<'a> {
+    /// Options that evaluate exactly like [`VersionReq::matches`].
+    pub const fn new() -> Self {
+        MatchOptions {
+            include_prerelease: false,
+            compare_build: false,
+            channel: None,
+            policy: PreReleasePolicy::SameTriple,
+            ignore_prerelease_case: false,
+            plain_caret: false,
         }
     }
 
-    if ver.pre.is_empty() {
-        return true;
+    /// Let pre-release versions match whenever their precedence falls within
+    /// the requirement, overriding the [`policy`][Self::policy] with
+    /// [`PreReleasePolicy::Allowed`].
+    #[must_use]
+    pub fn include_prerelease(mut self, include_prerelease: bool) -> Self {
+        self.include_prerelease = include_prerelease;
+        self
     }
 
-    // If a version has a prerelease tag (for example, 1.2.3-alpha.3) then it
-    // will only be allowed to satisfy req if at least one comparator with the
-    // same major.minor.patch also has a prerelease tag.
-    for cmp in &req.comparators {
-        if pre_is_compatible(cmp, ver) {
-            return true;
-        }
+    /// Treat build metadata as significant, ordering versions that differ
+    /// only in build metadata by [`Version`]'s `Ord` impl. Build metadata
+    /// written in a requirement is not part of its comparators and is ignored,
+    /// so with this enabled `=1.2.3+g8e6aa2a` matches `1.2.3` but not
+    /// `1.2.3+g8e6aa2a`, while `>1.2.3` matches the latter.
+    #[must_use]
+    pub fn compare_build(mut self, compare_build: bool) -> Self {
+        self.compare_build = compare_build;
+        self
+    }
+
+    /// Additionally require pre-release versions to belong to the given
+    /// channel, meaning their first pre-release identifier is equal to
+    /// `channel`. Versions without a pre-release are part of every channel.
+    ///
+    /// `None`, the default, places no restriction on the channel.
+    #[must_use]
+    pub fn channel(mut self, channel: Option<&'a str>) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Rule deciding whether a pre-release version can match. The default is
+    /// [`PreReleasePolicy::SameTriple`].
+    #[must_use]
+    pub fn policy(mut self, policy: PreReleasePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Compare pre-release identifiers, and the [`channel`][Self::channel],
+    /// without regard to ASCII case, so that `=1.2.3-rc.1` matches
+    /// `1.2.3-RC.1`. Pre-releases are ordered as by
+    /// [`Prerelease::cmp_ignore_ascii_case`][crate::Prerelease::cmp_ignore_ascii_case].
+    #[must_use]
+    pub fn ignore_prerelease_case(mut self, ignore_prerelease_case: bool) -> Self {
+        self.ignore_prerelease_case = ignore_prerelease_case;
+        self
+    }
+
+    /// Evaluate caret comparators without Cargo's special case for a major
+    /// version of 0, for ecosystems where `^0.2.3` means `>=0.2.3, <1.0.0`.
+    ///
+    /// By default `^0.2.3` is `>=0.2.3, <0.3.0`, `^0.0.3` is `=0.0.3`, and
+    /// `^0.0` is `=0.0`, because Cargo treats the leftmost nonzero component
+    /// as the major version. With plain caret semantics every caret
+    /// comparator allows any version up to the next major version: `^0.0.3`
+    /// is `>=0.0.3, <1.0.0` and `^0.0` is `>=0.0.0, <1.0.0`. `^0` is the same
+    /// either way.
+    ///
+    /// ```
+    /// use semver::{MatchOptions, Version, VersionReq};
+    ///
+    /// let req = VersionReq::parse("^0.2.3").unwrap();
+    /// let version = Version::parse("0.4.0").unwrap();
+    /// let plain = MatchOptions::new().plain_caret(true);
+    ///
+    /// assert!(!req.matches(&version));
+    /// assert!(req.matches_with(&version, &plain));
+    /// ```
+    #[must_use]
+    pub fn plain_caret(mut self, plain_caret: bool) -> Self {
+        self.plain_caret = plain_caret;
+        self
     }
+}
 
-    false
+/// Rule deciding whether a pre-release version can match, for
+/// [`MatchOptions::policy`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PreReleasePolicy {
+    /// Pre-release versions never match.
+    Excluded,
+    /// A pre-release version matches only if some comparator has a
+    /// pre-release of its own on the same major.minor.patch, such as
+    /// `>=1.2.3-beta` matching `1.2.3-rc.1` but not `1.2.4-rc.1`. This is the
+    /// rule used by [`VersionReq::matches`] and [`Comparator::matches`], and
+    /// the default.
+    SameTriple,
+    /// Pre-release versions match whenever their precedence falls within the
+    /// requirement, such as `^1.2.3` matching `1.3.0-beta`.
+    Allowed,
+}
+
+impl Default for PreReleasePolicy {
+    fn default() -> Self {
+        PreReleasePolicy::SameTriple
+    }
+}
+
+pub(crate) fn matches_req(req: &VersionReq, ver: &Version) -> bool {
+    let result = matches_req_with(req, ver, &MatchOptions::new());
+    #[cfg(feature = "verify")]
+    crate::verify::check(req, ver, result);
+    result
+}
+
+pub(crate) fn matches_req_with(req: &VersionReq, ver: &Version, opts: &MatchOptions) -> bool {
+    matches_all(&req.comparators, ver, opts)
+}
+
+pub(crate) fn matches_stable(req: &VersionReq, ver: &Version) -> bool {
+    let opts = MatchOptions::new();
+    req.comparators
+        .iter()
+        .all(|cmp| matches_impl(cmp, ver, &opts))
 }
 
 pub(crate) fn matches_comparator(cmp: &Comparator, ver: &Version) -> bool {
-    matches_impl(cmp, ver) && (ver.pre.is_empty() || pre_is_compatible(cmp, ver))
+    matches_comparator_with(cmp, ver, &MatchOptions::new())
 }
 
-fn matches_impl(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_comparator_with(
+    cmp: &Comparator,
+    ver: &Version,
+    opts: &MatchOptions,
+) -> bool {
+    matches_all(slice::from_ref(cmp), ver, opts)
+}
+
+/// Evaluate whether `ver` satisfies every comparator in `comparators`, as
+/// [`VersionReq::matches_with`] does for a requirement with those
+/// comparators.
+///
+/// The pre-release rule applies to the slice as a whole: by default a
+/// pre-release version matches only if some comparator in the slice names a
+/// pre-release of the same major.minor.patch. An empty slice is the same as
+/// [`VersionReq::STAR`].
+///
+/// # Example
+///
+/// ```
+/// use semver::eval::matches_all;
+/// use semver::{Comparator, MatchOptions, Version};
+///
+/// // Comparators of many requirements, stored back to back.
+/// let storage = [">=1.2.0", "<2.0.0", "^0.4", ">=1.0.0-rc.1"]
+///     .iter()
+///     .map(|cmp| Comparator::parse(cmp).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let opts = MatchOptions::new();
+/// let version = Version::parse("1.4.0").unwrap();
+/// assert!(matches_all(&storage[0..2], &version, &opts));
+/// assert!(!matches_all(&storage[2..3], &version, &opts));
+///
+/// let rc = Version::parse("1.0.0-rc.2").unwrap();
+/// assert!(matches_all(&storage[3..], &rc, &opts));
+/// ```
+pub fn matches_all(comparators: &[Comparator], ver: &Version, opts: &MatchOptions) -> bool {
+    if let Some(channel) = opts.channel {
+        if !ver.pre.is_empty() {
+            let in_channel = match channel_of(&ver.pre) {
+                Some(ver_channel) if opts.ignore_prerelease_case => {
+                    ver_channel.eq_ignore_ascii_case(channel)
+                }
+                ver_channel => ver_channel == Some(channel),
+            };
+            if !in_channel {
+                return false;
+            }
+        }
+    }
+
+    for cmp in comparators {
+        if !matches_impl(cmp, ver, opts) {
+            return false;
+        }
+    }
+
+    if ver.pre.is_empty() {
+        return true;
+    }
+
+    let policy = if opts.include_prerelease {
+        PreReleasePolicy::Allowed
+    } else {
+        opts.policy
+    };
+
+    match policy {
+        PreReleasePolicy::Excluded => false,
+        // If a version has a prerelease tag (for example, 1.2.3-alpha.3) then
+        // it will only be allowed to satisfy req if at least one comparator
+        // with the same major.minor.patch also has a prerelease tag.
+        PreReleasePolicy::SameTriple => comparators.iter().any(|cmp| pre_is_compatible(cmp, ver)),
+        PreReleasePolicy::Allowed => true,
+    }
+}
+
+pub(crate) fn matches_impl(cmp: &Comparator, ver: &Version, opts: &MatchOptions) -> bool {
     match cmp.op {
-        Op::Exact | Op::Wildcard => matches_exact(cmp, ver),
-        Op::Greater => matches_greater(cmp, ver),
-        Op::GreaterEq => matches_exact(cmp, ver) || matches_greater(cmp, ver),
-        Op::Less => matches_less(cmp, ver),
-        Op::LessEq => matches_exact(cmp, ver) || matches_less(cmp, ver),
-        Op::Tilde => matches_tilde(cmp, ver),
-        Op::Caret => matches_caret(cmp, ver),
+        Op::Exact | Op::Wildcard => matches_exact(cmp, ver, opts),
+        Op::Greater => matches_greater(cmp, ver, opts),
+        Op::GreaterEq => matches_exact(cmp, ver, opts) || matches_greater(cmp, ver, opts),
+        Op::Less => matches_less(cmp, ver, opts),
+        Op::LessEq => matches_exact(cmp, ver, opts) || matches_less(cmp, ver, opts),
+        Op::Tilde => matches_tilde(cmp, ver, opts),
+        Op::Caret => matches_caret(cmp, ver, opts),
         #[cfg(no_non_exhaustive)]
         Op::__NonExhaustive => unreachable!(),
     }
 }
 
-fn matches_exact(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_exact(cmp: &Comparator, ver: &Version, opts: &MatchOptions) -> bool {
     if ver.major != cmp.major {
         return false;
     }
@@ -56,12 +282,15 @@ fn matches_exact(cmp: &Comparator, ver: &Version) -> bool {
         if ver.patch != patch {
             return false;
         }
+        if opts.compare_build && !ver.build.is_empty() {
+            return false;
+        }
     }
 
-    ver.pre == cmp.pre
+    cmp_pre(ver, cmp, opts) == Ordering::Equal
 }
 
-fn matches_greater(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_greater(cmp: &Comparator, ver: &Version, opts: &MatchOptions) -> bool {
     if ver.major != cmp.major {
         return ver.major > cmp.major;
     }
@@ -84,10 +313,15 @@ fn matches_greater(cmp: &Comparator, ver: &Version) -> bool {
         }
     }
 
-    ver.pre > cmp.pre
+    let pre = cmp_pre(ver, cmp, opts);
+    if pre != Ordering::Equal {
+        return pre == Ordering::Greater;
+    }
+
+    opts.compare_build && !ver.build.is_empty()
 }
 
-fn matches_less(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_less(cmp: &Comparator, ver: &Version, opts: &MatchOptions) -> bool {
     if ver.major != cmp.major {
         return ver.major < cmp.major;
     }
@@ -110,10 +344,10 @@ fn matches_less(cmp: &Comparator, ver: &Version) -> bool {
         }
     }
 
-    ver.pre < cmp.pre
+    cmp_pre(ver, cmp, opts) == Ordering::Less
 }
 
-fn matches_tilde(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_tilde(cmp: &Comparator, ver: &Version, opts: &MatchOptions) -> bool {
     if ver.major != cmp.major {
         return false;
     }
@@ -130,10 +364,10 @@ fn matches_tilde(cmp: &Comparator, ver: &Version) -> bool {
         }
     }
 
-    ver.pre >= cmp.pre
+    cmp_pre(ver, cmp, opts) != Ordering::Less
 }
 
-fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_caret(cmp: &Comparator, ver: &Version, opts: &MatchOptions) -> bool {
     if ver.major != cmp.major {
         return false;
     }
@@ -143,9 +377,12 @@ fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
         Some(minor) => minor,
     };
 
+    // Under plain caret semantics, 0 is a major version like any other.
+    let major_is_nonzero = cmp.major > 0 || opts.plain_caret;
+
     let patch = match cmp.patch {
         None => {
-            if cmp.major > 0 {
+            if major_is_nonzero {
                 return ver.minor >= minor;
             } else {
                 return ver.minor == minor;
@@ -154,7 +391,7 @@ fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
         Some(patch) => patch,
     };
 
-    if cmp.major > 0 {
+    if major_is_nonzero {
         if ver.minor != minor {
             return ver.minor > minor;
         } else if ver.patch != patch {
@@ -170,10 +407,14 @@ fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
         return false;
     }
 
-    ver.pre >= cmp.pre
+    cmp_pre(ver, cmp, opts) != Ordering::Less
+}
+
+fn cmp_pre(ver: &Version, cmp: &Comparator, opts: &MatchOptions) -> Ordering {
+    impls::cmp_prerelease(&ver.pre, &cmp.pre, opts.ignore_prerelease_case)
 }
 
-fn pre_is_compatible(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn pre_is_compatible(cmp: &Comparator, ver: &Version) -> bool {
     cmp.major == ver.major
         && cmp.minor == Some(ver.minor)
         && cmp.patch == Some(ver.patch)
@@ -204,9 +445,9 @@ mod test_matches {
             pre: Prerelease::EMPTY,
         };
         let ver = Version::new(major_1, minor_1, patch_1);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_2, minor_2, patch_2);
-        assert!(! matches_caret(& cmp, & ver));
+        assert!(! matches_caret(& cmp, & ver, &MatchOptions::new()));
 
     }
     #[test]
@@ -234,11 +475,11 @@ mod test_matches {
             pre: Prerelease::EMPTY,
         };
         let ver = Version::new(major_1, minor_1, patch_1);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_2, minor_2, patch_2);
-        assert!(! matches_caret(& cmp, & ver));
+        assert!(! matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_3, minor_3, patch_3);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
     }
     #[test]
     fn test_matches_caret_patch() {
@@ -265,11 +506,11 @@ mod test_matches {
             pre: Prerelease::EMPTY,
         };
         let ver = Version::new(major_1, minor_1, patch_1);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_2, minor_2, patch_2);
-        assert!(! matches_caret(& cmp, & ver));
+        assert!(! matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_3, minor_3, patch_3);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
     }
     #[test]
     fn test_matches_caret_build() {
@@ -299,7 +540,7 @@ mod test_matches {
             pre: Prerelease::EMPTY,
             build: BuildMetadata::new(build_text_0).unwrap(),
         };
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version {
             major: major_2,
             minor: minor_2,
@@ -307,7 +548,7 @@ mod test_matches {
             pre: Prerelease::EMPTY,
             build: BuildMetadata::new(build_text_1).unwrap(),
         };
-        assert!(! matches_caret(& cmp, & ver));
+        assert!(! matches_caret(& cmp, & ver, &MatchOptions::new()));
     }
     #[test]
     fn test_matches_caret_zero_major() {
@@ -332,10 +573,10 @@ mod test_matches {
             pre: Prerelease::EMPTY,
         };
         let ver = Version::new(major_1, minor_1, patch_1);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_2, minor_2, patch_2);
-        assert!(! matches_caret(& cmp, & ver));
+        assert!(! matches_caret(& cmp, & ver, &MatchOptions::new()));
         let ver = Version::new(major_3, minor_3, patch_3);
-        assert!(matches_caret(& cmp, & ver));
+        assert!(matches_caret(& cmp, & ver, &MatchOptions::new()));
     }
 }
\ No newline at end of file
@@ -0,0 +1,112 @@
+use crate::backport::*;
+use crate::{Version, VersionReq};
+use core::cmp::Ordering;
+use core::ops::Bound;
+use core::str;
+
+/// Check that a version survives printing and parsing again, for use as the
+/// body of a fuzz target.
+///
+/// `data` is taken as UTF-8 text and ignored if it is not valid UTF-8 or not
+/// a valid version. Otherwise the version is printed and parsed again, which
+/// must succeed and give back an equal version printed the same way.
+///
+/// # Panics
+///
+/// Panics if the round trip does not hold, which is a bug in this crate.
+///
+/// # Example
+///
+/// ```ignore
+/// #![no_main]
+///
+/// libfuzzer_sys::fuzz_target!(|data: &[u8]| semver::fuzz_roundtrip_version(data));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub fn fuzz_roundtrip_version(data: &[u8]) {
+    let text = match str::from_utf8(data) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    let version = match Version::parse(text) {
+        Ok(version) => version,
+        Err(_) => return,
+    };
+    let printed = format!("{}", version);
+    let reparsed = match Version::parse(&printed) {
+        Ok(reparsed) => reparsed,
+        Err(err) => panic!(
+            "{:?} printed as {:?}, which fails to parse: {}",
+            text, printed, err
+        ),
+    };
+    assert_eq!(version, reparsed, "{:?} printed as {:?}", text, printed);
+    assert_eq!(printed, format!("{}", reparsed));
+}
+
+/// Check that the ways of evaluating a requirement agree with each other,
+/// for use as the body of a fuzz target.
+///
+/// `data` is taken as UTF-8 text of a requirement and a version, separated
+/// by the first newline, and ignored if either fails to parse. Then:
+///
+/// - [`VersionReq::matches`] and its [compiled][crate::CompiledReq] form
+///   agree;
+///
+/// - a matching version lies within the [`lower_bound`] and [`upper_bound`]
+///   of every comparator;
+///
+/// - a release that lies within the bounds of every comparator matches.
+///
+/// # Panics
+///
+/// Panics if any of these does not hold, which is a bug in this crate.
+///
+/// [`lower_bound`]: crate::Comparator::lower_bound
+/// [`upper_bound`]: crate::Comparator::upper_bound
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub fn fuzz_eval_consistency(data: &[u8]) {
+    let text = match str::from_utf8(data) {
+        Ok(text) => text,
+        Err(_) => return,
+    };
+    let newline = match text.find('\n') {
+        Some(newline) => newline,
+        None => return,
+    };
+    let (req, version) = match (
+        VersionReq::parse(&text[..newline]),
+        Version::parse(&text[newline + 1..]),
+    ) {
+        (Ok(req), Ok(version)) => (req, version),
+        _ => return,
+    };
+
+    let matches = req.matches(&version);
+    assert_eq!(
+        matches,
+        req.compile().matches(&version),
+        "compiled {} disagrees on {}",
+        req,
+        version,
+    );
+
+    let within = req.comparators.iter().all(|cmp| {
+        let above = match cmp.lower_bound() {
+            Bound::Included(bound) => version.cmp_precedence(&bound) != Ordering::Less,
+            Bound::Excluded(bound) => version.cmp_precedence(&bound) == Ordering::Greater,
+            Bound::Unbounded => true,
+        };
+        let below = match cmp.upper_bound() {
+            Bound::Included(bound) => version.cmp_precedence(&bound) != Ordering::Greater,
+            Bound::Excluded(bound) => version.cmp_precedence(&bound) == Ordering::Less,
+            Bound::Unbounded => true,
+        };
+        above && below
+    });
+    if matches {
+        assert!(within, "{} matches {} outside its bounds", req, version);
+    } else if version.pre.is_empty() {
+        assert!(!within, "{} rejects {} within its bounds", req, version);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::backport::*;
+use crate::bump;
+use crate::error::ErrorKind;
+use crate::{BuildMetadata, Error, Prerelease, Version};
+use core::str::FromStr;
+
+/// The output of `git describe --tags`, such as `v1.2.3-4-gabcdef1-dirty`.
+///
+/// # Example
+///
+/// ```
+/// use semver::{GitDescribe, Version};
+///
+/// let describe = GitDescribe::parse("v1.2.3-4-gabcdef1-dirty").unwrap();
+/// assert_eq!(describe.base, Version::new(1, 2, 3));
+/// assert_eq!(describe.commits_since, 4);
+/// assert_eq!(describe.hash.as_ref().map(String::as_str), Some("abcdef1"));
+/// assert!(describe.dirty);
+///
+/// let version = describe.to_prerelease_version();
+/// assert_eq!(version.to_string(), "1.2.4-dev.4+gabcdef1.dirty");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GitDescribe {
+    /// The version in the most recent tag, with any leading `v` removed.
+    pub base: Version,
+    /// The number of commits on top of the tag.
+    pub commits_since: u64,
+    /// The abbreviated hash of the described commit, without the `g` prefix
+    /// git puts before it. This is `None` if the commit is the tagged one and
+    /// `--long` was not used, in which case git does not print it.
+    pub hash: Option<String>,
+    /// Whether the working tree had local modifications (`--dirty`).
+    pub dirty: bool,
+}
+
+impl GitDescribe {
+    /// Parse the output of `git describe --tags`, optionally with `--long`
+    /// and `--dirty`.
+    ///
+    /// The tag must be a SemVer version, optionally preceded by `v`. Since a
+    /// version with a pre-release can look just like describe output,
+    /// `1.2.3-4-gabcdef1` is always taken to be 4 commits past `1.2.3`.
+    ///
+    /// Commits past `18446744073709551615.18446744073709551615.18446744073709551615`
+    /// are an error, as no later release exists for
+    /// [`to_prerelease_version`][Self::to_prerelease_version] to number them
+    /// with.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        GitDescribe::from_str(text)
+    }
+
+    /// A version identifying the described commit, ordered after the base
+    /// version and before the commits of the next release.
+    ///
+    /// - The tagged commit itself, without local modifications, is the base
+    ///   version unchanged.
+    ///
+    /// - Otherwise if the base is a release, the patch number is incremented
+    ///   and the pre-release is `dev.N`, where N is the number of commits
+    ///   since the tag: `v1.2.3-4-gabcdef1` becomes `1.2.4-dev.4+gabcdef1`.
+    ///   A patch number of `u64::MAX` carries into the minor number, and
+    ///   likewise the minor into the major.
+    ///
+    /// - If the base is a pre-release, `.dev.N` is appended to its
+    ///   pre-release instead: `v1.2.3-rc.1-4-gabcdef1` becomes
+    ///   `1.2.3-rc.1.dev.4+gabcdef1`.
+    ///
+    /// The build metadata is the hash with git's `g` prefix, followed by
+    /// `.dirty` for a modified working tree. The base version's own build
+    /// metadata is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hash` was set to a string that is not valid in build
+    /// metadata, or if `base` is the highest possible release and the commit
+    /// is not the tagged one. `parse` never produces either.
+    pub fn to_prerelease_version(&self) -> Version {
+        if self.commits_since == 0 && !self.dirty {
+            return self.base.clone();
+        }
+
+        let mut version = Version::new(self.base.major, self.base.minor, self.base.patch);
+        let pre = if self.base.pre.is_empty() {
+            version = bump::next_release(&self.base).expect("no release after base version");
+            format!("dev.{}", self.commits_since)
+        } else {
+            format!("{}.dev.{}", self.base.pre, self.commits_since)
+        };
+        version.pre = Prerelease::new(&pre).unwrap();
+
+        let mut build = String::new();
+        if let Some(hash) = &self.hash {
+            build.push('g');
+            build.push_str(hash);
+        }
+        if self.dirty {
+            if !build.is_empty() {
+                build.push('.');
+            }
+            build.push_str("dirty");
+        }
+        version.build = BuildMetadata::new(&build).unwrap();
+        version
+    }
+}
+
+impl FromStr for GitDescribe {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (text, dirty) = match text.strip_suffix("-dirty") {
+            Some(text) => (text, true),
+            None => (text, false),
+        };
+
+        let (tag, commits_since, hash) = match split_suffix(text) {
+            Some((tag, commits_since, hash)) => (tag, commits_since, Some(String::from(hash))),
+            None => (text, 0, None),
+        };
+
+        let tag = tag.strip_prefix('v').unwrap_or(tag);
+        let base = Version::parse(tag)?;
+        if (commits_since > 0 || dirty) && bump::next_release(&base).is_none() {
+            return Err(Error::new(ErrorKind::CommitsPastMaxVersion));
+        }
+        Ok(GitDescribe {
+            base,
+            commits_since,
+            hash,
+            dirty,
+        })
+    }
+}
+
+// Split `TAG-N-gHASH` into its parts.
+fn split_suffix(text: &str) -> Option<(&str, u64, &str)> {
+    let g = text.rfind("-g")?;
+    let hash = &text[g + 2..];
+    if hash.len() < 4 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let text = &text[..g];
+    let dash = text.rfind('-')?;
+    let commits_since = &text[dash + 1..];
+    if commits_since.is_empty() || !commits_since.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((&text[..dash], commits_since.parse().ok()?, hash))
+}
@@ -0,0 +1,49 @@
+use crate::backport::*;
+use crate::{Version, VersionReq};
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+/// A version such as `1.4.2-rc.1`, as a GraphQL string scalar named
+/// `Version`.
+#[Scalar(name = "Version")]
+impl ScalarType for Version {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(string) => Version::parse(string).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(string) => Version::parse(string).is_ok(),
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format!("{}", self))
+    }
+}
+
+/// A version requirement such as `>=1.2.3, <2`, as a GraphQL string scalar
+/// named `VersionReq`.
+#[Scalar(name = "VersionReq")]
+impl ScalarType for VersionReq {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(string) => VersionReq::parse(string).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        match value {
+            Value::String(string) => VersionReq::parse(string).is_ok(),
+            _ => false,
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(format!("{}", self))
+    }
+}
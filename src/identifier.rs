@@ -34,7 +34,7 @@
 // longer than 8 bytes.
 //
 // To differentiate the inline representation from the heap allocated long
-// representation, we'll allocate heap pointers with 2-byte alignment so that
+// representation, we'll allocate heap pointers with 4-byte alignment so that
 // they are guaranteed to have an unset least significant bit. Then in the repr
 // we store for pointers, we rotate a 1 into the most significant bit of the
 // most significant byte, which is never set for an ASCII byte.
@@ -45,9 +45,15 @@
 //
 // Heap allocated repr:
 //
-//     1ppppppp pppppppp pppppppp pppppppp pppppppp pppppppp pppppppp pppppppp 0
+//     1ppppppp pppppppp pppppppp pppppppp pppppppp pppppppp pppppppp ppppppp0 0
 //     ^ most significant bit   least significant bit of orig ptr, rotated out ^
 //
+// The second least significant bit of the original pointer, which is also
+// always unset, becomes the least significant bit of the repr. It is set for
+// interned identifiers (the "intern" feature): those point to a shared
+// allocation that lives for the rest of the program, so cloning one copies
+// the repr and dropping one does nothing.
+//
 // Since the most significant bit doubles as a sign bit for the similarly sized
 // signed integer type, the CPU has an efficient instruction for inspecting it,
 // meaning we can differentiate between an inline repr and a heap allocated repr
@@ -120,7 +126,7 @@ impl Identifier {
             9..=0xff_ffff_ffff_ffff => {
                 // SAFETY: len is in a range that does not contain 0.
                 let size = bytes_for_varint(unsafe { NonZeroUsize::new_unchecked(len) }) + len;
-                let align = 2;
+                let align = 4;
                 // On 32-bit and 16-bit architecture, check for size overflowing
                 // isize::MAX. Making an allocation request bigger than this to
                 // the allocator is considered UB. All allocations (including
@@ -189,6 +195,47 @@ impl Identifier {
         self.is_empty() || self.is_inline()
     }
 
+    // Only meaningful for the heap allocated representation.
+    #[cfg(feature = "intern")]
+    fn is_interned(&self) -> bool {
+        self.head.as_ptr() as usize & 1 != 0
+    }
+
+    // Identifier with the same contents as this one, whose heap allocation if
+    // any is shared with every other interned identifier of the same contents.
+    #[cfg(feature = "intern")]
+    pub(crate) fn intern(&self) -> Self {
+        if self.is_empty_or_inline() || self.is_interned() {
+            return self.clone();
+        }
+        // SAFETY: repr is in the heap allocated representation.
+        let string = unsafe { ptr_as_str(&self.head) };
+        let shared = crate::intern::intern(string, || {
+            let leaked = self.clone();
+            // SAFETY: repr is in the heap allocated representation. The
+            // allocation is never freed because leaked is forgotten.
+            let shared = unsafe { &*(ptr_as_str(&leaked.head) as *const str) };
+            mem::forget(leaked);
+            shared
+        });
+        let shared = match shared {
+            Some(shared) => shared,
+            None => return self.clone(),
+        };
+        // SAFETY: heap allocated strings are longer than 8 bytes.
+        let len = unsafe { NonZeroUsize::new_unchecked(shared.len()) };
+        // SAFETY: the string contents of a heap allocation are preceded by
+        // the varint header.
+        let ptr = unsafe { shared.as_ptr().sub(bytes_for_varint(len)) };
+        let repr = ptr_to_repr(ptr as *mut u8);
+        Identifier {
+            // SAFETY: the most significant bit of repr is set, so the value is
+            // still not zero with the least significant bit set too.
+            head: unsafe { NonNull::new_unchecked(repr.as_ptr().wrapping_add(1)) },
+            tail: [0; TAIL_BYTES],
+        }
+    }
+
     pub(crate) fn as_str(&self) -> &str {
         if self.is_empty() {
             ""
@@ -204,7 +251,11 @@ impl Identifier {
 
 impl Clone for Identifier {
     fn clone(&self) -> Self {
-        if self.is_empty_or_inline() {
+        #[cfg(feature = "intern")]
+        let is_shared = self.is_empty_or_inline() || self.is_interned();
+        #[cfg(not(feature = "intern"))]
+        let is_shared = self.is_empty_or_inline();
+        if is_shared {
             Identifier {
                 head: self.head,
                 tail: self.tail,
@@ -214,7 +265,7 @@ impl Clone for Identifier {
             // SAFETY: ptr is one of our own heap allocations.
             let len = unsafe { decode_len(ptr) };
             let size = bytes_for_varint(len) + len.get();
-            let align = 2;
+            let align = 4;
             // SAFETY: align is not zero, align is a power of two, and rounding
             // size up to align does not overflow isize::MAX. This is just
             // duplicating a previous allocation where all of these guarantees
@@ -242,11 +293,17 @@ impl Drop for Identifier {
         if self.is_empty_or_inline() {
             return;
         }
+        #[cfg(feature = "intern")]
+        {
+            if self.is_interned() {
+                return;
+            }
+        }
         let ptr = repr_to_ptr_mut(self.head);
         // SAFETY: ptr is one of our own heap allocations.
         let len = unsafe { decode_len(ptr) };
         let size = bytes_for_varint(len) + len.get();
-        let align = 2;
+        let align = 4;
         // SAFETY: align is not zero, align is a power of two, and rounding
         // size up to align does not overflow isize::MAX. These guarantees were
         // made when originally allocating this memory.
@@ -274,7 +331,7 @@ impl PartialEq for Identifier {
 unsafe impl Send for Identifier {}
 unsafe impl Sync for Identifier {}
 
-// We use heap pointers that are 2-byte aligned, meaning they have an
+// We use heap pointers that are 4-byte aligned, meaning they have an
 // insignificant 0 in the least significant bit. We take advantage of that
 // unneeded bit to rotate a 1 into the most significant bit to make the repr
 // distinguishable from ASCII bytes.
@@ -293,12 +350,13 @@ fn ptr_to_repr(original: *mut u8) -> NonNull<u8> {
 }
 
 // Shift out the 1 previously placed into the most significant bit of the least
-// significant byte. Shift in a low 0 bit to reconstruct the original 2-byte
-// aligned pointer.
+// significant byte. Shift in a low 0 bit and clear the interned bit to
+// reconstruct the original 4-byte aligned pointer.
 fn repr_to_ptr(modified: NonNull<u8>) -> *const u8 {
     // `lea rax, [rdi + rdi]`
+    // `and rax, -3`
     let modified = modified.as_ptr();
-    let original = (modified as usize) << 1;
+    let original = (modified as usize) << 1 & !2;
 
     // `modified + (original - modified)`, but being mindful of provenance.
     let diff = original.wrapping_sub(modified as usize);
@@ -0,0 +1,112 @@
+#[allow(unused_imports)]
+use crate::backport::*;
+use crate::{Prerelease, Version};
+
+/// Extract the version from a container image reference, such as
+/// `redis:7.2.4-alpine` or `docker.io/library/redis:7.2.4-alpine@sha256:…`,
+/// returning it along with the tag's variant suffix.
+///
+/// The tag is the part after the last `:` of the final path component, with
+/// any `@digest` removed, and is split by [`split_image_tag`]. Returns `None`
+/// if the reference has no tag or its tag does not start with a version.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let (version, variant) = semver::from_image_tag("redis:7.2.4-alpine").unwrap();
+/// assert_eq!(version, Version::new(7, 2, 4));
+/// assert_eq!(variant, "alpine");
+/// ```
+pub fn from_image_tag(reference: &str) -> Option<(Version, &str)> {
+    let reference = match reference.find('@') {
+        Some(at) => &reference[..at],
+        None => reference,
+    };
+    let name = match reference.rfind('/') {
+        Some(slash) => &reference[slash + 1..],
+        None => reference,
+    };
+    let colon = name.rfind(':')?;
+    split_image_tag(&name[colon + 1..])
+}
+
+/// Split an image tag such as `3.12.2-slim-bookworm` into its version and its
+/// variant suffix, `slim-bookworm`.
+///
+/// The tag must start with a version number of one to three components,
+/// optionally preceded by `v`. Missing components are zero, so `7.2-alpine`
+/// gives 7.2.0. Everything after the following `-` is the variant, except a
+/// leading `alpha`, `beta` or `rc` identifier, optionally numbered as in
+/// `rc1` or `rc.1`, which becomes the version's pre-release. The variant is
+/// empty if the tag is only a version.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let (version, variant) = semver::split_image_tag("1.0.0-rc.2-alpine").unwrap();
+/// assert_eq!(version, Version::parse("1.0.0-rc.2").unwrap());
+/// assert_eq!(variant, "alpine");
+///
+/// assert_eq!(semver::split_image_tag("latest"), None);
+/// ```
+pub fn split_image_tag(tag: &str) -> Option<(Version, &str)> {
+    let tag = tag.strip_prefix('v').unwrap_or(tag);
+
+    let mut numbers = [0u64; 3];
+    let mut rest = tag;
+    for (i, number) in numbers.iter_mut().enumerate() {
+        if i > 0 {
+            match rest.strip_prefix('.') {
+                Some(after_dot) => rest = after_dot,
+                None => break,
+            }
+        }
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return None;
+        }
+        *number = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+    }
+
+    let mut version = Version::new(numbers[0], numbers[1], numbers[2]);
+    if rest.is_empty() {
+        return Some((version, ""));
+    }
+    let rest = rest.strip_prefix('-')?;
+
+    let pre_len = prerelease_len(rest);
+    if pre_len == 0 {
+        return Some((version, rest));
+    }
+    version.pre = Prerelease::new(&rest[..pre_len]).ok()?;
+    let variant = &rest[pre_len..];
+    let variant = variant.strip_prefix('-').unwrap_or(variant);
+    Some((version, variant))
+}
+
+// Length of a leading `alpha`, `beta` or `rc` identifier with an optional
+// number, if it is followed by the end of the string or a `-`.
+fn prerelease_len(rest: &str) -> usize {
+    let word = ["alpha", "beta", "rc"]
+        .iter()
+        .find(|word| rest.starts_with(*word))
+        .map_or(0, |word| word.len());
+    if word == 0 {
+        return 0;
+    }
+    let mut len = word;
+    if rest[len..].starts_with('.') && rest[len + 1..].starts_with(|ch: char| ch.is_ascii_digit()) {
+        len += 1;
+    }
+    len += rest[len..].bytes().take_while(u8::is_ascii_digit).count();
+    if rest[len..].is_empty() || rest[len..].starts_with('-') {
+        len
+    } else {
+        0
+    }
+}
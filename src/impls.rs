@@ -1,3 +1,4 @@
+use crate::alloc::vec;
 use crate::backport::*;
 use crate::identifier::Identifier;
 use crate::{BuildMetadata, Comparator, Prerelease, VersionReq};
@@ -5,6 +6,7 @@ use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 use core::iter::FromIterator;
 use core::ops::Deref;
+use core::slice;
 
 impl Default for Identifier {
     fn default() -> Self {
@@ -50,56 +52,100 @@ impl PartialOrd for BuildMetadata {
 
 impl Ord for Prerelease {
     fn cmp(&self, rhs: &Self) -> Ordering {
-        match self.is_empty() {
-            true if rhs.is_empty() => return Ordering::Equal,
-            // A real release compares greater than prerelease.
-            true => return Ordering::Greater,
-            // Prerelease compares less than the real release.
-            false if rhs.is_empty() => return Ordering::Less,
-            false => {}
-        }
+        cmp_prerelease(self, rhs, false)
+    }
+}
 
-        let lhs = self.as_str().split('.');
-        let mut rhs = rhs.as_str().split('.');
+pub(crate) fn cmp_prerelease(lhs: &Prerelease, rhs: &Prerelease, ignore_case: bool) -> Ordering {
+    match lhs.is_empty() {
+        true if rhs.is_empty() => Ordering::Equal,
+        // A real release compares greater than prerelease.
+        true => Ordering::Greater,
+        // Prerelease compares less than the real release.
+        false if rhs.is_empty() => Ordering::Less,
+        false => cmp_identifiers(lhs.as_str(), rhs.as_str(), ignore_case),
+    }
+}
 
-        for lhs in lhs {
-            let rhs = match rhs.next() {
-                // Spec: "A larger set of pre-release fields has a higher
-                // precedence than a smaller set, if all of the preceding
-                // identifiers are equal."
-                None => return Ordering::Greater,
-                Some(rhs) => rhs,
-            };
+/// Compare two dot-separated lists of pre-release identifiers by SemVer
+/// precedence, the rule in item 11.4 of the specification.
+///
+/// Numeric identifiers compare numerically and below alphanumeric ones,
+/// alphanumeric identifiers compare in ASCII order, and a list that is a
+/// prefix of another is lower. The empty string is the empty list, lower
+/// than any other. Unlike [`Prerelease`]'s `Ord`, this knows nothing of
+/// releases, which the specification orders after their pre-releases, and
+/// the input is not validated, so other version schemes can reuse it for
+/// their own dotted tags.
+///
+/// # Example
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(semver::cmp_prerelease_str("beta.2", "beta.11"), Ordering::Less);
+/// assert_eq!(semver::cmp_prerelease_str("rc.1", "beta"), Ordering::Greater);
+/// assert_eq!(semver::cmp_prerelease_str("", "alpha"), Ordering::Less);
+/// ```
+pub fn cmp_prerelease_str(lhs: &str, rhs: &str) -> Ordering {
+    cmp_identifiers(lhs, rhs, false)
+}
 
-            let string_cmp = || Ord::cmp(lhs, rhs);
-            let is_ascii_digit = |b: u8| b.is_ascii_digit();
-            let ordering = match (
-                lhs.bytes().all(is_ascii_digit),
-                rhs.bytes().all(is_ascii_digit),
-            ) {
-                // Respect numeric ordering, for example 99 < 100. Spec says:
-                // "Identifiers consisting of only digits are compared
-                // numerically."
-                (true, true) => Ord::cmp(&lhs.len(), &rhs.len()).then_with(string_cmp),
-                // Spec: "Numeric identifiers always have lower precedence than
-                // non-numeric identifiers."
-                (true, false) => return Ordering::Less,
-                (false, true) => return Ordering::Greater,
-                // Spec: "Identifiers with letters or hyphens are compared
-                // lexically in ASCII sort order."
-                (false, false) => string_cmp(),
-            };
+pub(crate) fn cmp_identifiers(lhs: &str, rhs: &str, ignore_case: bool) -> Ordering {
+    match (lhs.is_empty(), rhs.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => {}
+    }
 
-            if ordering != Ordering::Equal {
-                return ordering;
+    let lhs = lhs.split('.');
+    let mut rhs = rhs.split('.');
+
+    for lhs in lhs {
+        let rhs = match rhs.next() {
+            // Spec: "A larger set of pre-release fields has a higher
+            // precedence than a smaller set, if all of the preceding
+            // identifiers are equal."
+            None => return Ordering::Greater,
+            Some(rhs) => rhs,
+        };
+
+        let string_cmp = || {
+            if ignore_case {
+                let lowercase = |b: u8| b.to_ascii_lowercase();
+                Iterator::cmp(lhs.bytes().map(lowercase), rhs.bytes().map(lowercase))
+            } else {
+                Ord::cmp(lhs, rhs)
             }
+        };
+        let is_ascii_digit = |b: u8| b.is_ascii_digit();
+        let ordering = match (
+            lhs.bytes().all(is_ascii_digit),
+            rhs.bytes().all(is_ascii_digit),
+        ) {
+            // Respect numeric ordering, for example 99 < 100. Spec says:
+            // "Identifiers consisting of only digits are compared
+            // numerically."
+            (true, true) => Ord::cmp(&lhs.len(), &rhs.len()).then_with(string_cmp),
+            // Spec: "Numeric identifiers always have lower precedence than
+            // non-numeric identifiers."
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            // Spec: "Identifiers with letters or hyphens are compared
+            // lexically in ASCII sort order."
+            (false, false) => string_cmp(),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
         }
+    }
 
-        if rhs.next().is_none() {
-            Ordering::Equal
-        } else {
-            Ordering::Less
-        }
+    if rhs.next().is_none() {
+        Ordering::Equal
+    } else {
+        Ordering::Less
     }
 }
 
@@ -154,3 +200,21 @@ impl FromIterator<Comparator> for VersionReq {
         VersionReq { comparators }
     }
 }
+
+impl IntoIterator for VersionReq {
+    type Item = Comparator;
+    type IntoIter = vec::IntoIter<Comparator>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.comparators.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a VersionReq {
+    type Item = &'a Comparator;
+    type IntoIter = slice::Iter<'a, Comparator>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.comparators.iter()
+    }
+}
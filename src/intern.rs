@@ -0,0 +1,28 @@
+#[cfg(not(no_once_cell))]
+use std::collections::HashSet;
+#[cfg(not(no_once_cell))]
+use std::sync::{Mutex, OnceLock};
+
+// Every interned identifier too long to be stored inline, leaked so that it
+// can be shared by any number of identifiers for the rest of the program.
+#[cfg(not(no_once_cell))]
+static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+// The interned copy of string, calling leak to make one if there is none yet.
+#[cfg(not(no_once_cell))]
+pub(crate) fn intern(string: &str, leak: impl FnOnce() -> &'static str) -> Option<&'static str> {
+    let interned = INTERNED.get_or_init(|| Mutex::new(HashSet::new()));
+    let mut interned = interned.lock().unwrap_or_else(|poison| poison.into_inner());
+    if let Some(shared) = interned.get(string) {
+        return Some(shared);
+    }
+    let shared = leak();
+    interned.insert(shared);
+    Some(shared)
+}
+
+// Without OnceLock there is no interner, and identifiers are not shared.
+#[cfg(no_once_cell)] // rustc <1.70
+pub(crate) fn intern(_string: &str, _leak: impl FnOnce() -> &'static str) -> Option<&'static str> {
+    None
+}
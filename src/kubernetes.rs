@@ -0,0 +1,43 @@
+use crate::backport::*;
+use crate::{BuildMetadata, Error, Prerelease, Version};
+
+/// Parse a Kubernetes-style version such as `v1.29.1`, `v1.29.1+k3s1`, or
+/// `v1.27.3-gke.1200`, as reported by `kubectl version` and distributions.
+///
+/// The leading `v` is optional. Upstream pre-releases, whose pre-release
+/// starts with `alpha`, `beta` or `rc`, stay pre-releases. Any other
+/// pre-release is a distribution's suffix on a release, not a pre-release of
+/// it, so it is moved into the build metadata ahead of any build metadata
+/// already present: `v1.28.3-eks-4f4795d` becomes `1.28.3+eks-4f4795d`, which
+/// has the same precedence as 1.28.3.
+///
+/// # Example
+///
+/// ```
+/// let version = semver::parse_kubernetes("v1.27.3-gke.1200").unwrap();
+/// assert_eq!(version.to_string(), "1.27.3+gke.1200");
+///
+/// let version = semver::parse_kubernetes("v1.30.0-rc.1+k3s1").unwrap();
+/// assert_eq!(version.to_string(), "1.30.0-rc.1+k3s1");
+/// ```
+pub fn parse_kubernetes(text: &str) -> Result<Version, Error> {
+    let text = text.strip_prefix('v').unwrap_or(text);
+    let mut version = Version::parse(text)?;
+
+    if !version.pre.is_empty() && !is_upstream_prerelease(&version.pre) {
+        let build = if version.build.is_empty() {
+            String::from(version.pre.as_str())
+        } else {
+            format!("{}.{}", version.pre, version.build)
+        };
+        version.build = BuildMetadata::new(&build)?;
+        version.pre = Prerelease::EMPTY;
+    }
+
+    Ok(version)
+}
+
+fn is_upstream_prerelease(pre: &Prerelease) -> bool {
+    let first = pre.as_str().split('.').next().unwrap_or("");
+    first == "alpha" || first == "beta" || first == "rc"
+}
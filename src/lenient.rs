@@ -0,0 +1,175 @@
+use crate::backport::*;
+use crate::{Error, Version};
+
+/// A version parsed by [`Version::parse_lenient_with_report`], together with
+/// the rewrites that were needed to make the input valid SemVer.
+#[cfg(feature = "report")]
+#[cfg_attr(docsrs, doc(cfg(feature = "report")))]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParseReport {
+    version: Version,
+    normalizations: Vec<Normalization>,
+}
+
+/// One rewrite applied by lenient parsing, as listed in a [`ParseReport`].
+///
+/// Offsets are byte offsets into the original input.
+#[cfg(feature = "report")]
+#[cfg_attr(docsrs, doc(cfg(feature = "report")))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Normalization {
+    /// Whitespace around the version was removed.
+    TrimmedWhitespace,
+    /// A `v`, `V` or `=` in front of the version was removed.
+    StrippedPrefix(char),
+    /// The minor version was missing and filled in as `0`.
+    FilledMinor,
+    /// The patch version was missing and filled in as `0`.
+    FilledPatch,
+    /// Leading zeros were removed from the version number or numeric
+    /// pre-release identifier starting at `offset`.
+    StrippedLeadingZeros { offset: usize },
+}
+
+#[cfg(feature = "report")]
+impl ParseReport {
+    /// The parsed version.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// The parsed version, dropping the report.
+    pub fn into_version(self) -> Version {
+        self.version
+    }
+
+    /// The rewrites applied to the input, in the order they occur in it.
+    /// Empty if the input was already a valid version.
+    pub fn normalizations(&self) -> &[Normalization] {
+        &self.normalizations
+    }
+}
+
+// Records the normalizations if the "report" feature is enabled, and is
+// empty otherwise.
+#[derive(Default)]
+pub(crate) struct Log {
+    #[cfg(feature = "report")]
+    normalizations: Vec<Normalization>,
+}
+
+#[cfg_attr(not(feature = "report"), allow(unused_variables))]
+impl Log {
+    fn trimmed(&mut self) {
+        #[cfg(feature = "report")]
+        self.normalizations.push(Normalization::TrimmedWhitespace);
+    }
+
+    fn stripped_prefix(&mut self, prefix: char) {
+        #[cfg(feature = "report")]
+        self.normalizations
+            .push(Normalization::StrippedPrefix(prefix));
+    }
+
+    fn filled(&mut self, component: usize) {
+        #[cfg(feature = "report")]
+        self.normalizations.push(if component == 1 {
+            Normalization::FilledMinor
+        } else {
+            Normalization::FilledPatch
+        });
+    }
+
+    fn stripped_zeros(&mut self, offset: usize) {
+        #[cfg(feature = "report")]
+        self.normalizations
+            .push(Normalization::StrippedLeadingZeros { offset });
+    }
+}
+
+#[cfg(feature = "report")]
+pub(crate) fn parse_with_report(text: &str) -> Result<ParseReport, Error> {
+    let mut log = Log::default();
+    let version = parse(text, &mut log)?;
+    Ok(ParseReport {
+        version,
+        normalizations: log.normalizations,
+    })
+}
+
+pub(crate) fn parse(text: &str, log: &mut Log) -> Result<Version, Error> {
+    let trimmed = text.trim();
+    if trimmed.len() != text.len() {
+        log.trimmed();
+    }
+    let mut offset = text.len() - text.trim_start().len();
+    let mut rest = trimmed;
+
+    if let Some(prefix) = rest.chars().next().filter(|ch| "vV=".contains(*ch)) {
+        log.stripped_prefix(prefix);
+        rest = &rest[1..];
+        offset += 1;
+    }
+
+    let mut normalized = String::new();
+    for component in 0..3 {
+        if component > 0 {
+            match rest.strip_prefix('.') {
+                Some(after_dot) if after_dot.starts_with(|ch: char| ch.is_ascii_digit()) => {
+                    rest = after_dot;
+                    offset += 1;
+                }
+                _ if rest.is_empty() || rest.starts_with(|ch| ch == '-' || ch == '+') => {
+                    log.filled(component);
+                    normalized.push_str(".0");
+                    continue;
+                }
+                // Let Version::parse report what is wrong.
+                _ => break,
+            }
+            normalized.push('.');
+        }
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            break;
+        }
+        normalized.push_str(strip_zeros(&rest[..digits], offset, log));
+        rest = &rest[digits..];
+        offset += digits;
+    }
+
+    if let Some(after_dash) = rest.strip_prefix('-') {
+        let end = after_dash.find('+').unwrap_or(after_dash.len());
+        normalized.push('-');
+        offset += 1;
+        for (i, identifier) in after_dash[..end].split('.').enumerate() {
+            if i > 0 {
+                normalized.push('.');
+                offset += 1;
+            }
+            if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                normalized.push_str(strip_zeros(identifier, offset, log));
+            } else {
+                normalized.push_str(identifier);
+            }
+            offset += identifier.len();
+        }
+        rest = &after_dash[end..];
+    }
+    normalized.push_str(rest);
+
+    Version::parse(&normalized)
+}
+
+fn strip_zeros<'a>(digits: &'a str, offset: usize, log: &mut Log) -> &'a str {
+    let stripped = digits.trim_start_matches('0');
+    if stripped.len() == digits.len() || digits.len() < 2 {
+        return digits;
+    }
+    log.stripped_zeros(offset);
+    if stripped.is_empty() {
+        "0"
+    } else {
+        stripped
+    }
+}
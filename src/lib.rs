@@ -89,25 +89,204 @@
 #[cfg(not(no_alloc_crate))]
 extern crate alloc;
 
+// The code generated by async-graphql's macros refers to std, which a no_std
+// build of this crate does not otherwise link.
+#[cfg(all(feature = "async-graphql", not(feature = "std")))]
+extern crate std;
+
+#[macro_use]
+mod macros;
+
+mod arena;
 mod backport;
+mod boundary;
+mod bump;
+#[cfg(feature = "cache")]
+mod cache;
+mod cached;
+mod canonical;
+mod channel;
+mod compiled;
+mod complete;
+#[cfg(not(no_const_panic))]
+mod const_parse;
+mod delta;
+mod desugar;
+mod diff;
 mod display;
+#[cfg(feature = "std")]
+mod env;
 mod error;
-mod eval;
+#[cfg(feature = "testing")]
+mod fuzz;
+mod git_describe;
 mod identifier;
+mod image_tag;
 mod impls;
+#[cfg(feature = "intern")]
+mod intern;
+mod kubernetes;
+mod lenient;
+mod lines;
+mod loose;
+mod matching;
+mod minimum;
+#[cfg(feature = "testing")]
+mod mutations;
+mod order;
+mod osv;
 mod parse;
+mod partial;
+mod partition;
+#[cfg(feature = "std")]
+mod pool;
+mod precedence;
+mod preview;
+mod range;
+mod range_set;
+#[cfg(feature = "zerocopy")]
+mod raw_version;
+mod require;
+mod select;
+mod sequence;
+mod set_diff;
+mod shared;
+mod simplify;
+mod sort;
+mod sql;
+mod stable;
+#[cfg(feature = "stats")]
+mod stats;
+mod steps;
+mod style;
+mod tokenize;
+mod tool_output;
+mod toolchain;
+#[cfg(feature = "verify")]
+mod verify;
+mod version_core;
 
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "bson")]
+mod bson;
+#[cfg(feature = "compat")]
+mod compat;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "redis")]
+mod redis;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "utoipa")]
+mod utoipa;
+
+pub mod eval;
+pub mod parser;
+pub mod raw;
+
+#[cfg(feature = "testvectors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testvectors")))]
+pub mod testvectors;
+
+// Not public API. Used by the crate_version!, pkg_version_req! and lazy_req!
+// macros.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Comparator, Op, Version, VersionReq};
+    use core::iter;
+
+    #[cfg(not(no_const_panic))]
+    pub use crate::const_parse::{check_req, ConstVersion};
+
+    pub fn caret_req(version: Version) -> VersionReq {
+        iter::once(Comparator {
+            op: Op::Caret,
+            major: version.major,
+            minor: Some(version.minor),
+            patch: Some(version.patch),
+            pre: version.pre,
+        })
+        .collect()
+    }
+}
 
+use crate::error::{ErrorKind, Position};
 use crate::identifier::Identifier;
 use core::cmp::Ordering;
+use core::iter;
+use core::ops::{Bound, RangeBounds};
+use core::slice;
 use core::str::FromStr;
 
 #[allow(unused_imports)]
 use crate::backport::*;
 
-pub use crate::parse::Error;
+pub use crate::arena::ArenaVersion;
+#[cfg(feature = "arrow")]
+pub use crate::arrow::{arrow_fields, versions_from_arrow, versions_to_arrow};
+pub use crate::boundary::{BoundaryEvent, BoundarySide};
+#[cfg(feature = "cache")]
+pub use crate::cache::MatchCache;
+pub use crate::cached::CachedVersion;
+pub use crate::channel::{ChannelRule, Channels};
+pub use crate::compiled::CompiledReq;
+pub use crate::complete::complete;
+pub use crate::delta::VersionDelta;
+pub use crate::desugar::{desugar_caret, desugar_tilde, desugar_wildcard};
+pub use crate::diff::{ReqChange, ReqDiff};
+#[cfg(feature = "std")]
+pub use crate::env::{from_env, req_from_env, EnvError};
+pub use crate::eval::{MatchOptions, PreReleasePolicy};
+#[cfg(feature = "testing")]
+pub use crate::fuzz::{fuzz_eval_consistency, fuzz_roundtrip_version};
+pub use crate::git_describe::GitDescribe;
+pub use crate::image_tag::{from_image_tag, split_image_tag};
+pub use crate::impls::cmp_prerelease_str;
+pub use crate::kubernetes::parse_kubernetes;
+#[cfg(feature = "report")]
+pub use crate::lenient::{Normalization, ParseReport};
+pub use crate::lines::{parse_lines, parse_req_lines};
+pub use crate::loose::cmp_loose;
+pub use crate::matching::Matching;
+pub use crate::minimum::minimum_supported;
+#[cfg(feature = "testing")]
+pub use crate::mutations::Mutations;
+pub use crate::order::{ByPrecedence, NewestFirst, OldestFirst};
+pub use crate::osv::{osv_reqs, OsvEvent};
+pub use crate::parse::{Error, ParseConfig, Separator};
+pub use crate::partial::{PartialVersion, Precision};
+pub use crate::partition::{partition_between, Partitioned};
+#[cfg(feature = "std")]
+pub use crate::pool::{ReqId, ReqPool};
+pub use crate::precedence::PrecedenceKey;
+pub use crate::preview::Preview;
+pub use crate::range::VersionRange;
+pub use crate::range_set::{CoverageReport, VersionRangeSet};
+#[cfg(feature = "zerocopy")]
+pub use crate::raw_version::RawVersion;
+pub use crate::require::{require_compatible, IncompatibleError};
+pub use crate::select::{
+    select, ClosestToCurrent, FewestBreakingChanges, HighestAny, HighestStable, SelectionContext,
+    SelectionPolicy,
+};
+pub use crate::sequence::{validate_sequence, SequenceIssue};
+#[cfg(feature = "serde")]
+pub use crate::serde::req_map;
+pub use crate::set_diff::{diff_version_sets, MetadataChange, SetDiff};
+pub use crate::shared::SharedVersion;
+pub use crate::simplify::simplify_union;
+pub use crate::sort::{sort, sort_by_precedence, sort_strs};
+pub use crate::sql::{SqlColumns, SqlDialect};
+pub use crate::stable::StableEncoding;
+#[cfg(feature = "stats")]
+pub use crate::stats::MatchStats;
+pub use crate::steps::Steps;
+pub use crate::style::{DisplayStyle, StyledReq};
+pub use crate::tokenize::{tokenize, SpannedToken, TokenKind, Tokens};
+pub use crate::tool_output::from_tool_output;
+pub use crate::toolchain::{rust_toolchain, RustChannel, RustToolchain};
+pub use crate::version_core::VersionCore;
 
 /// **SemVer version** as defined by <https://semver.org>.
 ///
@@ -179,12 +358,13 @@ pub struct Version {
 ///
 /// - Build metadata is syntactically permitted on the partial versions, but is
 ///   completely ignored, as it's never relevant to whether any comparator
-///   matches a particular version.
+///   matches a particular version. It can be retrieved using
+///   [`VersionReq::parse_with_build`].
 ///
 /// - Whitespace is permitted around commas and around operators. Whitespace is
 ///   not permitted within a partial version, i.e. anywhere between the major
 ///   version number and its minor, patch, pre-release, or build metadata.
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(no_const_vec_new, derive(Default))]
 pub struct VersionReq {
     pub comparators: Vec<Comparator>,
@@ -192,7 +372,7 @@ pub struct VersionReq {
 
 /// A pair of comparison operator and partial version, such as `>=1.2`. Forms
 /// one piece of a VersionReq.
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct Comparator {
     pub op: Op,
     pub major: u64,
@@ -265,6 +445,48 @@ pub enum Op {
     __NonExhaustive,
 }
 
+/// Which component of a wildcard comparator the `*` stands in for.
+///
+/// Returned by [`Comparator::wildcard_position`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(not(no_non_exhaustive), non_exhaustive)]
+pub enum WildcardPosition {
+    /// **`I.*`**&ensp;or&ensp;**`I.*.*`**
+    Minor,
+    /// **`I.J.*`**
+    Patch,
+
+    #[cfg(no_non_exhaustive)] // rustc <1.40
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+/// The structure of a comparator, returned by [`Comparator::classify`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(not(no_non_exhaustive), non_exhaustive)]
+pub enum ComparatorKind {
+    /// **`=I.J.K`**&ensp;or&ensp;**`=I.J.K-pre`**&emsp;&mdash;&emsp;exactly
+    /// one version, up to build metadata
+    ExactFull,
+    /// **`=I.J`**&ensp;or&ensp;**`=I`**&emsp;&mdash;&emsp;every version
+    /// with the given leading components
+    ExactPartial,
+    /// **`>`**&ensp;or&ensp;**`>=`**&emsp;&mdash;&emsp;bounded below only
+    RangeLower,
+    /// **`<`**&ensp;or&ensp;**`<=`**&emsp;&mdash;&emsp;bounded above only
+    RangeUpper,
+    /// **`~I.J.K`**, **`~I.J`**&ensp;or&ensp;**`~I`**
+    Tilde,
+    /// **`^I.J.K`**, **`^I.J`**&ensp;or&ensp;**`^I`**
+    Caret,
+    /// **`I.J.*`**&ensp;or&ensp;**`I.*`**
+    Wildcard,
+
+    #[cfg(no_non_exhaustive)] // rustc <1.40
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
 /// Optional pre-release identifier on a version string. This comes after `-` in
 /// a SemVer version, like `1.0.0-alpha.1`
 ///
@@ -428,10 +650,146 @@ impl Version {
     ///   and `.` (dot).
     ///
     /// - `23456789999999999999.0.0` &mdash; overflow of a u64.
+    ///
+    /// Apart from the u64 limit, this accepts exactly the syntax of the
+    /// [SemVer 2.0.0 specification][spec]: no leading `v`, no surrounding
+    /// whitespace, and no missing components. Use
+    /// [`parse_lenient`][Self::parse_lenient] for input that departs from it.
+    ///
+    /// [spec]: https://semver.org/spec/v2.0.0.html
     pub fn parse(text: &str) -> Result<Self, Error> {
         Version::from_str(text)
     }
 
+    /// Parse a version, first repairing common departures from SemVer found
+    /// in tags and hand-written data.
+    ///
+    /// - Whitespace around the version is ignored.
+    /// - A leading `v`, `V` or `=` is stripped, as in `v1.2.3`.
+    /// - A missing minor or patch version is filled in with zero, so `1.2`
+    ///   becomes `1.2.0` and `1.2-rc.1` becomes `1.2.0-rc.1`.
+    /// - Leading zeros are removed from the version numbers and from numeric
+    ///   pre-release identifiers, so `1.02.3-rc.01` becomes `1.2.3-rc.1`.
+    ///
+    /// Whatever remains must be a valid version. With the "report" feature,
+    /// [`parse_lenient_with_report`][Self::parse_lenient_with_report] also
+    /// tells which of these repairs were made.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse_lenient(" v1.02-rc.01 ").unwrap();
+    /// assert_eq!(version.to_string(), "1.2.0-rc.1");
+    ///
+    /// assert!(Version::parse_lenient("1.2.3.4").is_err());
+    /// ```
+    pub fn parse_lenient(text: &str) -> Result<Self, Error> {
+        lenient::parse(text, &mut lenient::Log::default())
+    }
+
+    /// Like [`parse_lenient`][Self::parse_lenient], additionally listing the
+    /// repairs that were made to the input, so that ingestion pipelines can
+    /// audit how much cleanup their data needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Normalization, Version};
+    ///
+    /// let report = Version::parse_lenient_with_report("v1.02").unwrap();
+    /// assert_eq!(report.version().to_string(), "1.2.0");
+    /// assert_eq!(
+    ///     report.normalizations(),
+    ///     [
+    ///         Normalization::StrippedPrefix('v'),
+    ///         Normalization::StrippedLeadingZeros { offset: 3 },
+    ///         Normalization::FilledPatch,
+    ///     ],
+    /// );
+    /// ```
+    #[cfg(feature = "report")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "report")))]
+    pub fn parse_lenient_with_report(text: &str) -> Result<ParseReport, Error> {
+        lenient::parse_with_report(text)
+    }
+
+    /// Replace the pre-release with `text`, or leave the version unchanged
+    /// and return an error if `text` is not a valid pre-release.
+    ///
+    /// An empty string removes the pre-release.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let mut version = Version::parse("1.4.0").unwrap();
+    /// version.set_pre_str("rc.1").unwrap();
+    /// assert_eq!(version.to_string(), "1.4.0-rc.1");
+    ///
+    /// assert!(version.set_pre_str("rc.01").is_err());
+    /// assert_eq!(version.to_string(), "1.4.0-rc.1");
+    /// ```
+    pub fn set_pre_str(&mut self, text: &str) -> Result<(), Error> {
+        self.pre = Prerelease::new(text)?;
+        Ok(())
+    }
+
+    /// Replace the build metadata with `text`, or leave the version unchanged
+    /// and return an error if `text` is not valid build metadata.
+    ///
+    /// An empty string removes the build metadata.
+    pub fn set_build_str(&mut self, text: &str) -> Result<(), Error> {
+        self.build = BuildMetadata::new(text)?;
+        Ok(())
+    }
+
+    /// Check that the pre-release and build metadata consist of valid SemVer
+    /// identifiers, returning the error that parsing them would produce if
+    /// not.
+    ///
+    /// [`Prerelease`] and [`BuildMetadata`] reject invalid identifiers when
+    /// they are created, so every version assembled through this crate's API
+    /// passes. This is a single call for code that mutates versions in many
+    /// places to assert that at a boundary, such as before publishing.
+    pub fn validate(&self) -> Result<(), Error> {
+        Prerelease::new(self.pre.as_str())?;
+        BuildMetadata::new(self.build.as_str())?;
+        Ok(())
+    }
+
+    /// Whether this version satisfies `req`. The same as
+    /// [`req.matches(self)`][VersionReq::matches], with the version first, for
+    /// filtering iterators of versions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionReq};
+    ///
+    /// let req = VersionReq::parse("^1.2").unwrap();
+    /// let compatible = ["1.1.0", "1.2.5", "1.9.0", "2.0.0"]
+    ///     .iter()
+    ///     .map(|v| Version::parse(v).unwrap())
+    ///     .filter(|v| v.satisfies(&req))
+    ///     .count();
+    /// assert_eq!(compatible, 2);
+    /// ```
+    pub fn satisfies(&self, req: &VersionReq) -> bool {
+        req.matches(self)
+    }
+
+    /// Parse `req` and check whether this version satisfies it.
+    ///
+    /// For checking many versions against one requirement, parse it once with
+    /// [`VersionReq::parse`] and use [`satisfies`][Self::satisfies] instead.
+    pub fn satisfies_str(&self, req: &str) -> Result<bool, Error> {
+        let req = VersionReq::parse(req)?;
+        Ok(req.matches(self))
+    }
+
     /// Compare the major, minor, patch, and pre-release value of two versions,
     /// disregarding build metadata. Versions that differ only in build metadata
     /// are considered equal. This comparison is what the SemVer spec refers to
@@ -478,103 +836,1507 @@ impl Version {
             &(other.major, other.minor, other.patch, &other.pre),
         )
     }
-}
 
-impl VersionReq {
-    /// A `VersionReq` with no constraint on the version numbers it matches.
-    /// Equivalent to `VersionReq::parse("*").unwrap()`.
+    /// An owned key ordering versions by precedence, for use with
+    /// `sort_by_key` and similar methods. See [`PrecedenceKey`].
+    pub fn precedence_key(&self) -> PrecedenceKey {
+        PrecedenceKey::new(self)
+    }
+
+    /// A string whose byte order is the precedence order of versions, for
+    /// storing next to the version in a MongoDB document.
     ///
-    /// In terms of comparators this is equivalent to `>=0.0.0`.
+    /// Strings compare in BSON by their bytes, so an index on this field
+    /// sorts versions correctly, `1.0.0-beta.2` before `1.0.0-beta.11`
+    /// before `1.0.0`, and range queries with `$gte` and `$lt` on keys of
+    /// two versions select exactly the versions between them. Like
+    /// precedence, the key disregards build metadata.
     ///
-    /// Counterintuitively a `*` VersionReq does not match every possible
-    /// version number. In particular, in order for *any* `VersionReq` to match
-    /// a pre-release version, the `VersionReq` must contain at least one
-    /// `Comparator` that has an explicit major, minor, and patch version
-    /// identical to the pre-release being matched, and that has a nonempty
-    /// pre-release component. Since `*` is not written with an explicit major,
-    /// minor, and patch version, and does not contain a nonempty pre-release
-    /// component, it does not match any pre-release versions.
-    #[cfg(not(no_const_vec_new))] // rustc <1.39
-    pub const STAR: Self = VersionReq {
-        comparators: Vec::new(),
-    };
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let beta2 = Version::parse("1.0.0-beta.2").unwrap();
+    /// let beta11 = Version::parse("1.0.0-beta.11").unwrap();
+    /// let release = Version::parse("1.0.0").unwrap();
+    /// assert!(beta2.bson_sort_key() < beta11.bson_sort_key());
+    /// assert!(beta11.bson_sort_key() < release.bson_sort_key());
+    /// ```
+    #[cfg(feature = "bson")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bson")))]
+    pub fn bson_sort_key(&self) -> String {
+        crate::bson::sort_key(self)
+    }
 
-    /// Create `VersionReq` by parsing from string representation.
+    /// A score for this version in a Redis sorted set, such that sorting by
+    /// score sorts by precedence.
     ///
-    /// # Errors
+    /// The score packs major into 16 bits, minor into 16 bits, patch into 20
+    /// bits, and one more bit that is set for a release and clear for a
+    /// pre-release, which is 53 bits and so exact in the double that Redis
+    /// keeps as the score. This returns `None` if major or minor is 65536 or
+    /// more, or patch is 1048576 or more.
     ///
-    /// Possible reasons for the parse to fail include:
+    /// All pre-releases of the same `major.minor.patch` share a score, below
+    /// the score of the release. Redis orders members with equal scores by
+    /// their bytes, which for pre-releases is not always their precedence:
+    /// `beta.11` sorts before `beta.2`. Build metadata is disregarded.
     ///
-    /// - `>a.b` &mdash; unexpected characters in the partial version.
+    /// # Example
     ///
-    /// - `@1.0.0` &mdash; unrecognized comparison operator.
+    /// ```
+    /// use semver::Version;
     ///
-    /// - `^1.0.0, ` &mdash; unexpected end of input.
+    /// let beta = Version::parse("1.0.0-beta.2").unwrap();
+    /// let release = Version::parse("1.0.0").unwrap();
+    /// let patch = Version::parse("1.0.1").unwrap();
+    /// assert!(beta.redis_score().unwrap() < release.redis_score().unwrap());
+    /// assert!(release.redis_score().unwrap() < patch.redis_score().unwrap());
     ///
-    /// - `>=1.0 <2.0` &mdash; missing comma between comparators.
+    /// let date = Version::parse("0.0.20240601").unwrap();
+    /// assert_eq!(date.redis_score(), None);
+    /// ```
+    #[cfg(feature = "redis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+    pub fn redis_score(&self) -> Option<f64> {
+        crate::redis::score(self)
+    }
+
+    /// Whether the two versions have the same major and minor numbers, and
+    /// so belong to the same `major.minor` maintenance branch. Patch,
+    /// pre-release and build metadata are disregarded.
     ///
-    /// - `*.*` &mdash; unsupported wildcard syntax.
-    pub fn parse(text: &str) -> Result<Self, Error> {
-        VersionReq::from_str(text)
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let v = Version::parse("1.4.2").unwrap();
+    /// assert!(v.same_minor_line(&Version::parse("1.4.0-rc.1").unwrap()));
+    /// assert!(!v.same_minor_line(&Version::parse("1.5.0").unwrap()));
+    /// ```
+    pub fn same_minor_line(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor
     }
 
-    /// Evaluate whether the given `Version` satisfies the version requirement
-    /// described by `self`.
-    pub fn matches(&self, version: &Version) -> bool {
-        eval::matches_req(self, version)
+    /// Whether the two versions have the same major number. Everything else
+    /// is disregarded, including for `0.y.z` versions, so `0.1.0` and `0.2.0`
+    /// are on the same major line even though they are not compatible in
+    /// the sense of a caret requirement.
+    pub fn same_major_line(&self, other: &Self) -> bool {
+        self.major == other.major
     }
-}
 
-/// The default VersionReq is the same as [`VersionReq::STAR`].
-#[cfg(not(no_const_vec_new))]
-impl Default for VersionReq {
-    fn default() -> Self {
-        VersionReq::STAR
+    /// Compare the `major.minor` lines the two versions belong to, by major
+    /// number then minor number. Versions on the same minor line compare
+    /// equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = Version::parse("1.4.2").unwrap();
+    /// let b = Version::parse("1.10.0-beta").unwrap();
+    /// assert_eq!(a.cmp_minor_line(&b), Ordering::Less);
+    /// assert_eq!(a.cmp_minor_line(&Version::parse("1.4.0").unwrap()), Ordering::Equal);
+    /// ```
+    pub fn cmp_minor_line(&self, other: &Self) -> Ordering {
+        Ord::cmp(&(self.major, self.minor), &(other.major, other.minor))
     }
-}
 
-impl Comparator {
-    pub fn parse(text: &str) -> Result<Self, Error> {
-        Comparator::from_str(text)
+    /// The release with the leading components of this version up to
+    /// `precision` and zeros after them, without pre-release or build
+    /// metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Precision, Version};
+    ///
+    /// let version = Version::parse("1.2.9-beta+x").unwrap();
+    /// assert_eq!(version.truncated(Precision::Major), Version::new(1, 0, 0));
+    /// assert_eq!(version.truncated(Precision::Minor), Version::new(1, 2, 0));
+    /// assert_eq!(version.truncated(Precision::Patch), Version::new(1, 2, 9));
+    /// ```
+    pub fn truncated(&self, precision: Precision) -> Version {
+        partial::truncated(self, precision)
     }
 
-    pub fn matches(&self, version: &Version) -> bool {
-        eval::matches_comparator(self, version)
+    /// Whether this version starts with the components of `prefix`, like
+    /// `1.2.9-beta` starts with `1` and `1.2` but not `1.20`. Pre-release
+    /// and build metadata are disregarded.
+    pub fn matches_prefix(&self, prefix: &PartialVersion) -> bool {
+        partial::matches_prefix(self, prefix)
     }
-}
 
-impl Prerelease {
-    pub const EMPTY: Self = Prerelease {
-        identifier: Identifier::empty(),
-    };
+    /// Difference between the major, minor and patch numbers of `other` and
+    /// those of this version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let current = Version::parse("1.4.7").unwrap();
+    /// let latest = Version::parse("2.0.1").unwrap();
+    /// let delta = current.delta(&latest);
+    /// assert_eq!((delta.major, delta.minor, delta.patch), (1, -4, -6));
+    /// ```
+    pub fn delta(&self, other: &Self) -> VersionDelta {
+        VersionDelta::new(self, other)
+    }
 
-    pub fn new(text: &str) -> Result<Self, Error> {
-        Prerelease::from_str(text)
+    /// The next major version, `(major+1).0.0`, or `None` if the major
+    /// number is already `u64::MAX`.
+    ///
+    /// Bumping any component resets the ones after it to zero and drops the
+    /// pre-release and build metadata, so `1.4.2-rc.1+g1a2b` bumps to
+    /// `2.0.0`, `1.5.0` and `1.4.3`. The checked bumps never panic or wrap
+    /// around, which matters for tooling that handles untrusted versions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse("1.4.2-rc.1").unwrap();
+    /// assert_eq!(version.checked_bump_major(), Some(Version::new(2, 0, 0)));
+    /// assert_eq!(version.checked_bump_minor(), Some(Version::new(1, 5, 0)));
+    /// assert_eq!(version.checked_bump_patch(), Some(Version::new(1, 4, 3)));
+    ///
+    /// let version = Version::new(std::u64::MAX, 0, 0);
+    /// assert_eq!(version.checked_bump_major(), None);
+    /// assert_eq!(version.saturating_bump_major(), version);
+    /// ```
+    pub fn checked_bump_major(&self) -> Option<Version> {
+        bump::major(self)
     }
 
-    pub fn as_str(&self) -> &str {
-        self.identifier.as_str()
+    /// The next minor version, `major.(minor+1).0`, or `None` if the minor
+    /// number is already `u64::MAX`.
+    pub fn checked_bump_minor(&self) -> Option<Version> {
+        bump::minor(self)
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.identifier.is_empty()
+    /// The next patch version, `major.minor.(patch+1)`, or `None` if the
+    /// patch number is already `u64::MAX`.
+    pub fn checked_bump_patch(&self) -> Option<Version> {
+        bump::patch(self)
     }
-}
 
-impl BuildMetadata {
-    pub const EMPTY: Self = BuildMetadata {
-        identifier: Identifier::empty(),
-    };
+    /// Like [`checked_bump_major`][Self::checked_bump_major], but if the
+    /// major number is already `u64::MAX`, it and the other numbers are left
+    /// as they are, without pre-release or build metadata. The result never
+    /// has lower precedence than this version.
+    pub fn saturating_bump_major(&self) -> Version {
+        bump::saturate(self, bump::major(self))
+    }
 
-    pub fn new(text: &str) -> Result<Self, Error> {
-        BuildMetadata::from_str(text)
+    /// Like [`checked_bump_minor`][Self::checked_bump_minor], but if the
+    /// minor number is already `u64::MAX`, it and the other numbers are left
+    /// as they are, without pre-release or build metadata.
+    pub fn saturating_bump_minor(&self) -> Version {
+        bump::saturate(self, bump::minor(self))
     }
 
-    pub fn as_str(&self) -> &str {
-        self.identifier.as_str()
+    /// Like [`checked_bump_patch`][Self::checked_bump_patch], but if the
+    /// patch number is already `u64::MAX`, it is left as it is, without
+    /// pre-release or build metadata.
+    pub fn saturating_bump_patch(&self) -> Version {
+        bump::saturate(self, bump::patch(self))
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.identifier.is_empty()
+    /// The release this pre-release leads up to, `1.4.0-rc.3` being promoted
+    /// to `1.4.0`. A release is promoted to itself. Build metadata describes
+    /// the pre-release build, so the result has none.
+    pub fn promote(&self) -> Version {
+        Version::new(self.major, self.minor, self.patch)
+    }
+
+    /// The first pre-release of the same version on the given channel, like
+    /// `1.4.0-beta.2` moving on to `1.4.0-rc.1` with `channel` equal to
+    /// `"rc"`. The build metadata is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `channel` is not a valid pre-release identifier,
+    /// or if the result would not have greater precedence than this version,
+    /// which includes moving a release or moving `1.4.0-rc.1` to `"beta"`.
+    /// Channel names are compared like any pre-release identifiers, so the
+    /// usual progression from `alpha` to `beta` to `rc` works.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse("1.4.0-beta.2").unwrap();
+    /// let rc = version.to_next_channel("rc").unwrap();
+    /// assert_eq!(rc.to_string(), "1.4.0-rc.1");
+    /// assert_eq!(rc.promote().to_string(), "1.4.0");
+    ///
+    /// assert!(rc.to_next_channel("beta").is_err());
+    /// ```
+    pub fn to_next_channel(&self, channel: &str) -> Result<Version, Error> {
+        bump::next_channel(self, channel)
+    }
+
+    /// The version of lowest precedence that is greater than this one, or
+    /// `None` for `18446744073709551615.18446744073709551615.18446744073709551615`.
+    ///
+    /// For a release this is the lowest pre-release of the next patch
+    /// version, `1.4.2` being followed by `1.4.3-0`, and for a pre-release it
+    /// is the pre-release with `.0` appended, `1.4.2-rc.1` being followed by
+    /// `1.4.2-rc.1.0`. Nothing lies in between, so `>= successor` is exactly
+    /// `> self`, which exclusive bounds and complements of ranges rely on.
+    /// Build metadata is not part of precedence and the result has none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse("1.4.2").unwrap();
+    /// assert_eq!(version.immediate_successor().unwrap().to_string(), "1.4.3-0");
+    ///
+    /// let version = Version::parse("1.4.2-rc.1+g1a2b").unwrap();
+    /// assert_eq!(version.immediate_successor().unwrap().to_string(), "1.4.2-rc.1.0");
+    /// ```
+    pub fn immediate_successor(&self) -> Option<Version> {
+        bump::successor(self)
+    }
+
+    /// The version of greatest precedence that is less than this one, if
+    /// there is one.
+    ///
+    /// This is the inverse of
+    /// [`immediate_successor`][Self::immediate_successor]: `1.4.3-0` is
+    /// preceded by `1.4.2`, and `1.4.2-rc.1.0` by `1.4.2-rc.1`. Other versions
+    /// have no immediate predecessor, because pre-release identifiers can be
+    /// arbitrarily long: below `1.4.2` are `1.4.2-rc`, `1.4.2-rc.1`,
+    /// `1.4.2-rc.1.1` and so on without a greatest one. For those, and for
+    /// `0.0.0-0` below which there is nothing, the result is `None`, so an
+    /// exclusive bound is converted to an inclusive one only when that is
+    /// exact. Build metadata is not part of precedence and the result has
+    /// none.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse("1.5.0-0").unwrap();
+    /// assert_eq!(version.immediate_predecessor(), Some(Version::new(1, 4, 18446744073709551615)));
+    ///
+    /// let version = Version::parse("1.4.2-rc.1.0").unwrap();
+    /// assert_eq!(version.immediate_predecessor().unwrap().to_string(), "1.4.2-rc.1");
+    ///
+    /// let version = Version::parse("1.4.2").unwrap();
+    /// assert_eq!(version.immediate_predecessor(), None);
+    /// ```
+    pub fn immediate_predecessor(&self) -> Option<Version> {
+        bump::predecessor(self)
+    }
+
+    /// The versions from this one through `end` on the same `major.minor`
+    /// line, bumping the patch number by one each time.
+    ///
+    /// Only major, minor and patch numbers are involved: every version
+    /// produced is a release without build metadata, starting at this
+    /// version's `major.minor.patch` and ending at `end`'s patch number. If
+    /// `end` is on a different minor line or has a lower patch number, there
+    /// are no versions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let start = Version::parse("1.4.1").unwrap();
+    /// let end = Version::parse("1.4.3").unwrap();
+    /// let tags: Vec<String> = start.iter_patches_to(&end).map(|v| format!("v{}", v)).collect();
+    /// assert_eq!(tags, ["v1.4.1", "v1.4.2", "v1.4.3"]);
+    /// ```
+    pub fn iter_patches_to(&self, end: &Self) -> Steps {
+        steps::patches(self, end)
+    }
+
+    /// The `major.minor.0` versions from this one's minor line through
+    /// `end`'s on the same major line, bumping the minor number by one each
+    /// time.
+    ///
+    /// The first version produced is this version's `major.minor.0`,
+    /// whatever its patch number, and the last is `end`'s `major.minor.0`.
+    /// If `end` is on a different major line or an earlier minor line, there
+    /// are no versions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let start = Version::parse("1.70.2").unwrap();
+    /// let end = Version::parse("1.72.1").unwrap();
+    /// let matrix: Vec<Version> = start.iter_minors_to(&end).collect();
+    /// assert_eq!(matrix[0].to_string(), "1.70.0");
+    /// assert_eq!(matrix[2].to_string(), "1.72.0");
+    /// assert_eq!(matrix.len(), 3);
+    /// ```
+    pub fn iter_minors_to(&self, end: &Self) -> Steps {
+        steps::minors(self, end)
+    }
+
+    /// Share the storage of the pre-release and build metadata with every
+    /// other interned version that has the same ones.
+    ///
+    /// Pre-releases and build metadata of up to 8 characters, like `alpha.1`
+    /// or a short commit hash, are stored inline in the `Version` and need no
+    /// storage of their own. A longer one is normally given a heap allocation
+    /// by each version holding it, even when millions of versions in an index
+    /// hold the same few tags. Interning a version instead makes it point to
+    /// a single copy of each distinct text, kept in a global table. Clones of
+    /// an interned version share that copy too.
+    ///
+    /// Interned text is never freed, so interning is meant for long-lived
+    /// indexes of versions with a limited vocabulary of tags, not for
+    /// arbitrary untrusted input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let mut a = Version::parse("1.0.0-nightly.20240601").unwrap();
+    /// let mut b = Version::parse("2.0.0-nightly.20240601").unwrap();
+    /// a.intern();
+    /// b.intern();
+    /// assert_eq!(a.pre.as_str().as_ptr(), b.pre.as_str().as_ptr());
+    /// ```
+    #[cfg(feature = "intern")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "intern")))]
+    pub fn intern(&mut self) {
+        self.pre.intern();
+        self.build.intern();
+    }
+
+    /// Strings close to this version, for testing how a system that consumes
+    /// versions copes with values near the ones it expects.
+    ///
+    /// First come valid versions: each of major, minor and patch one higher
+    /// and one lower, the pre-release and build metadata added or removed,
+    /// and their identifiers in reverse order. Then near misses that are not
+    /// valid versions: a dot dropped from `major.minor.patch`, a leading
+    /// zero, a `v` prefix, a stray trailing dot or `-` or `+`. The original
+    /// version and duplicates are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse("1.2.3-rc.1").unwrap();
+    /// let mutations: Vec<String> = version.mutations().collect();
+    /// assert!(mutations.contains(&"1.2.4-rc.1".to_owned()));
+    /// assert!(mutations.contains(&"1.2.3-1.rc".to_owned()));
+    /// assert!(mutations.contains(&"12.3-rc.1".to_owned()));
+    /// ```
+    #[cfg(feature = "testing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    pub fn mutations(&self) -> Mutations {
+        mutations::version(self)
+    }
+}
+
+impl VersionReq {
+    /// A `VersionReq` with no constraint on the version numbers it matches.
+    /// Equivalent to `VersionReq::parse("*").unwrap()`.
+    ///
+    /// In terms of comparators this is equivalent to `>=0.0.0`.
+    ///
+    /// Counterintuitively a `*` VersionReq does not match every possible
+    /// version number. In particular, in order for *any* `VersionReq` to match
+    /// a pre-release version, the `VersionReq` must contain at least one
+    /// `Comparator` that has an explicit major, minor, and patch version
+    /// identical to the pre-release being matched, and that has a nonempty
+    /// pre-release component. Since `*` is not written with an explicit major,
+    /// minor, and patch version, and does not contain a nonempty pre-release
+    /// component, it does not match any pre-release versions.
+    #[cfg(not(no_const_vec_new))] // rustc <1.39
+    pub const STAR: Self = VersionReq {
+        comparators: Vec::new(),
+    };
+
+    /// The requirement `I.*`, matching every version with the given major
+    /// version. Same as parsing `format!("{}.*", major)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionReq};
+    ///
+    /// let req = VersionReq::any_of_major(1);
+    /// assert_eq!(req, VersionReq::parse("1.*").unwrap());
+    /// assert!(req.matches(&Version::new(1, 9, 0)));
+    /// ```
+    pub fn any_of_major(major: u64) -> Self {
+        VersionReq::wildcard(major, None)
+    }
+
+    /// The requirement `I.J.*`, matching every version with the given major
+    /// and minor version. Same as parsing `format!("{}.{}.*", major, minor)`.
+    pub fn any_of_minor(major: u64, minor: u64) -> Self {
+        VersionReq::wildcard(major, Some(minor))
+    }
+
+    /// The requirement matching the versions in a Rust range, such as
+    /// `v1..v2` for `>=v1, <v2` or `v1..=v2` for `>=v1, <=v2`.
+    ///
+    /// An unbounded side adds no comparator, so `..` is `*`. Build metadata of
+    /// the bounds is dropped. The requirement matches by the usual rules,
+    /// comparing by precedence and matching a pre-release only if a bound is
+    /// a pre-release of the same major.minor.patch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionReq};
+    ///
+    /// let v1 = Version::new(1, 2, 0);
+    /// let v2 = Version::new(1, 5, 0);
+    ///
+    /// let req = VersionReq::from_range(v1.clone()..v2.clone());
+    /// assert_eq!(req.to_string(), ">=1.2.0, <1.5.0");
+    ///
+    /// let req = VersionReq::from_range(v1..=v2);
+    /// assert_eq!(req.to_string(), ">=1.2.0, <=1.5.0");
+    /// assert!(req.matches(&Version::new(1, 5, 0)));
+    /// ```
+    pub fn from_range<R: RangeBounds<Version>>(range: R) -> Self {
+        range::to_req(&range)
+    }
+
+    fn wildcard(major: u64, minor: Option<u64>) -> Self {
+        let cmp = Comparator {
+            op: Op::Wildcard,
+            major,
+            minor,
+            patch: None,
+            pre: Prerelease::EMPTY,
+        };
+        VersionReq {
+            comparators: iter::once(cmp).collect(),
+        }
+    }
+
+    /// Create `VersionReq` by parsing from string representation.
+    ///
+    /// # Errors
+    ///
+    /// Possible reasons for the parse to fail include:
+    ///
+    /// - `>a.b` &mdash; unexpected characters in the partial version.
+    ///
+    /// - `@1.0.0` &mdash; unrecognized comparison operator.
+    ///
+    /// - `^1.0.0, ` &mdash; unexpected end of input.
+    ///
+    /// - `>=1.0 <2.0` &mdash; missing comma between comparators.
+    ///
+    /// - `*.*` &mdash; unsupported wildcard syntax.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        VersionReq::from_str(text)
+    }
+
+    /// Parse a version requirement, rejecting syntax that Cargo accepts only
+    /// for compatibility with other ecosystems.
+    ///
+    /// In addition to everything [`VersionReq::parse`] rejects, this rejects:
+    ///
+    /// - `1.x` or `1.2.X` &mdash; wildcards other than `*`.
+    ///
+    /// - `=1.2.3+build` &mdash; build metadata, which `parse` accepts and
+    ///   ignores.
+    ///
+    /// - `>= 1.2.3` &mdash; whitespace between an operator and its version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::VersionReq;
+    ///
+    /// assert!(VersionReq::parse_strict_spec(">=1.2.3, <2.0.0-0").is_ok());
+    /// assert!(VersionReq::parse_strict_spec("1.2.x").is_err());
+    /// ```
+    pub fn parse_strict_spec(text: &str) -> Result<Self, Error> {
+        let mut config = ParseConfig::new().whitespace(false);
+        config.strict = true;
+        parse::parse_version_req(text, &config)
+    }
+
+    /// Parse a version requirement with the given options, such as reading a
+    /// bare version like `1.2.3` as some other operator than caret.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Op, ParseConfig, VersionReq};
+    ///
+    /// let exact = ParseConfig::new().default_op(Op::Exact);
+    /// let req = VersionReq::parse_with(">=1.0, 1.4", &exact).unwrap();
+    /// assert_eq!(req.to_string(), ">=1.0, =1.4");
+    /// ```
+    pub fn parse_with(text: &str, config: &ParseConfig) -> Result<Self, Error> {
+        parse::parse_version_req(text, config)
+    }
+
+    /// Parse a version requirement with the given options, also returning the
+    /// build metadata written on each comparator, in the order of
+    /// [`comparators`][Self::comparators].
+    ///
+    /// Build metadata never affects matching, so it is not part of a
+    /// [`Comparator`], and [`parse_with`][Self::parse_with] accepts but
+    /// discards it. The entry of a comparator written without build metadata
+    /// is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{ParseConfig, Version, VersionReq};
+    ///
+    /// let (req, build) =
+    ///     VersionReq::parse_with_build("=1.2.3+g8e6aa2a, <2", &ParseConfig::new()).unwrap();
+    /// assert_eq!(req.to_string(), "=1.2.3, <2");
+    /// assert_eq!(build[0].as_str(), "g8e6aa2a");
+    /// assert!(build[1].is_empty());
+    /// assert!(req.matches(&Version::parse("1.2.3").unwrap()));
+    /// ```
+    pub fn parse_with_build(
+        text: &str,
+        config: &ParseConfig,
+    ) -> Result<(Self, Vec<BuildMetadata>), Error> {
+        parse::parse_version_req_with_build(text, config)
+    }
+
+    /// Evaluate whether the given `Version` satisfies the version requirement
+    /// described by `self`.
+    ///
+    /// With the "verify" feature, every result is checked against a separate,
+    /// much slower evaluation based on the interval of versions each
+    /// comparator allows, and this panics if the two disagree. That is meant
+    /// for gaining confidence in a new version of this crate by running a
+    /// staging environment with the feature enabled, not for production.
+    pub fn matches(&self, version: &Version) -> bool {
+        eval::matches_req(self, version)
+    }
+
+    /// Evaluate whether the given release `Version` satisfies the version
+    /// requirement described by `self`.
+    ///
+    /// This assumes `version` has no pre-release, which lets it skip the
+    /// pre-release rule of [`matches`][Self::matches]. For a version without
+    /// pre-release the result is the same as `matches`, only faster. A
+    /// pre-release version is evaluated as if by
+    /// [`MatchOptions::include_prerelease`], so `^1.2.3` matches `1.3.0-beta`
+    /// here even though `matches` rejects it.
+    pub fn matches_stable(&self, version: &Version) -> bool {
+        eval::matches_stable(self, version)
+    }
+
+    /// Evaluate whether the given `Version` satisfies the version requirement
+    /// described by `self`, subject to the additional constraints in `options`.
+    ///
+    /// With [`MatchOptions::new()`] this is the same as [`matches`][Self::matches].
+    pub fn matches_with(&self, version: &Version, options: &MatchOptions) -> bool {
+        eval::matches_req_with(self, version, options)
+    }
+
+    /// Render a SQL boolean expression that is true for exactly the rows whose
+    /// version, stored across the given `columns`, satisfies this requirement.
+    ///
+    /// The expression includes the pre-release rule of
+    /// [`matches`][Self::matches], so registry databases can filter
+    /// server-side.
+    ///
+    /// Pre-releases are ordered by the sort key column described in
+    /// [`SqlColumns`], which must use a binary collation, so `beta.10` comes
+    /// after `beta.2` as in SemVer precedence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{SqlColumns, SqlDialect, VersionReq};
+    ///
+    /// let req = VersionReq::parse("~1.2").unwrap();
+    /// let sql = req.to_sql_predicate(SqlDialect::Postgres, &SqlColumns::default());
+    /// assert_eq!(
+    ///     sql,
+    ///     r#"(("major" = 1 AND "minor" = 2 AND "pre" = '')) AND ("pre" = '')"#,
+    /// );
+    /// ```
+    pub fn to_sql_predicate(&self, dialect: SqlDialect, columns: &SqlColumns) -> String {
+        sql::predicate(self, dialect, columns)
+    }
+
+    /// Render this requirement in a canonical form, for use as a cache or
+    /// database key.
+    ///
+    /// Every comparator is rewritten as the primitive `=`, `>`, `>=`, `<` or
+    /// `<=` comparators with a full major.minor.patch version that match the
+    /// same versions, the way [`desugar_caret`] and friends do, and these are
+    /// sorted by version and then by operator in that order, with duplicates
+    /// and `>=0.0.0` left out. Comparators are separated by `", "`. A
+    /// requirement with no comparators left is rendered as `*`.
+    ///
+    /// Requirements that differ only in the order of their comparators, in
+    /// duplicated comparators, or in writing a comparator in shorthand rather
+    /// than spelled out, like `^1.2` and `>=1.2.0, <2.0.0-0` or `1.*` and `=1`,
+    /// have the same canonical string. Requirements that match the same
+    /// versions for less direct reasons, like `>=1.0.0, >=1.2.0` and
+    /// `>=1.2.0`, are not recognized as equal.
+    ///
+    /// Unlike the `Display` impl, this format is stable: the canonical string
+    /// of a given requirement will not change in any future version of this
+    /// crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::VersionReq;
+    ///
+    /// let a = VersionReq::parse("<2, ^1.2").unwrap();
+    /// let b = VersionReq::parse(">=1.2.0, <2.0.0-0, <2.0.0").unwrap();
+    /// assert_eq!(a.canonical_string(), ">=1.2.0, <2.0.0-0, <2.0.0");
+    /// assert_eq!(a.canonical_string(), b.canonical_string());
+    /// ```
+    pub fn canonical_string(&self) -> String {
+        canonical::canonical_string(self)
+    }
+
+    /// Every version at which one of the comparators starts or stops
+    /// matching, ordered by precedence.
+    ///
+    /// Each comparator contributes its [`lower_bound`][Comparator::lower_bound]
+    /// and [`upper_bound`][Comparator::upper_bound], if bounded, tagged with
+    /// the index of the comparator, so audit tools can show where a
+    /// constraint flips from matching to not matching and why. Events at the
+    /// same version are in comparator order.
+    ///
+    /// As with the comparator bounds, a pre-release between the boundaries
+    /// still only matches if the requirement names a pre-release of the same
+    /// major.minor.patch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{BoundarySide, VersionReq};
+    ///
+    /// let req = VersionReq::parse("^1.2, <1.8").unwrap();
+    /// let events = req.boundaries();
+    /// assert_eq!(events.len(), 3);
+    ///
+    /// assert_eq!(events[0].version.to_string(), "1.2.0");
+    /// assert_eq!((events[0].side, events[0].inclusive), (BoundarySide::Lower, true));
+    /// assert_eq!(events[1].version.to_string(), "1.8.0");
+    /// assert_eq!((events[1].side, events[1].comparator), (BoundarySide::Upper, 1));
+    /// assert_eq!(events[2].version.to_string(), "2.0.0-0");
+    /// assert_eq!((events[2].side, events[2].comparator), (BoundarySide::Upper, 0));
+    /// ```
+    pub fn boundaries(&self) -> Vec<BoundaryEvent> {
+        boundary::boundaries(self)
+    }
+
+    /// The releases from `start` onward that match this requirement, in
+    /// order of precedence, for enumerating every version of a small range
+    /// in tests or generated documentation.
+    ///
+    /// Versions are produced by stepping the patch number, carrying into the
+    /// minor and major numbers at `u64::MAX`, and the iterator skips straight
+    /// to the requirement's lower bound and ends at its upper bound. It owns a
+    /// copy of the requirement. Pre-releases are not produced, as there is no
+    /// end to the pre-releases between two releases: a pre-release `start`
+    /// begins at its release. An unbounded requirement yields versions
+    /// practically forever, so take as many as needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionReq};
+    ///
+    /// let req = VersionReq::parse(">1.2.1, <=1.2.4").unwrap();
+    /// let versions: Vec<String> = req
+    ///     .iter_matching(Version::new(0, 0, 0))
+    ///     .map(|v| v.to_string())
+    ///     .collect();
+    /// assert_eq!(versions, ["1.2.2", "1.2.3", "1.2.4"]);
+    ///
+    /// let req = VersionReq::parse("^1").unwrap();
+    /// let mut versions = req.iter_matching(Version::new(1, 7, 0));
+    /// assert_eq!(versions.next(), Some(Version::new(1, 7, 0)));
+    /// assert_eq!(versions.next(), Some(Version::new(1, 7, 1)));
+    /// ```
+    pub fn iter_matching(&self, start: Version) -> Matching {
+        matching::matching(self, start)
+    }
+
+    /// Describe how this requirement differs from `old`, for dependency
+    /// update tools writing the description of a change to a manifest.
+    ///
+    /// Comparators are paired up between the two requirements: identical
+    /// ones first, then those with the same operator, reported as a bound
+    /// raised or lowered, then those limiting versions from the same side,
+    /// like `>=` and `>`, reported as an operator change. The rest are
+    /// reported as added or removed. The order of comparators does not
+    /// matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{ReqChange, VersionReq};
+    ///
+    /// let old = VersionReq::parse(">=1.0, ~1.2, <2").unwrap();
+    /// let new = VersionReq::parse("^1.4, >=1.0, <1.8, >1.0.0-0").unwrap();
+    /// let diff = new.diff_display(&old);
+    /// assert_eq!(
+    ///     diff.to_string(),
+    ///     "changed `~1.2` to `^1.4`, lowered `<2` to `<1.8`, added `>1.0.0-0`",
+    /// );
+    /// assert_eq!(diff.changes[1], ReqChange::BoundChanged {
+    ///     old: "<2".parse().unwrap(),
+    ///     new: "<1.8".parse().unwrap(),
+    /// });
+    ///
+    /// let new = VersionReq::parse(">=1.0, <2").unwrap();
+    /// assert_eq!(new.diff_display(&old).to_string(), "removed `~1.2`");
+    /// ```
+    pub fn diff_display(&self, old: &VersionReq) -> ReqDiff {
+        diff::diff(old, self)
+    }
+
+    /// Indices of the comparators that are implied by the others, like
+    /// `>=1.0` alongside `^1.2`, so that linting tools can suggest removing
+    /// them.
+    ///
+    /// Removing all of the reported comparators together leaves a
+    /// requirement that matches exactly the same versions, pre-releases
+    /// included. Of comparators that are implied by each other, like a
+    /// duplicate, the first one written is kept, and a requirement is never
+    /// reduced to no comparators at all. Indices are in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::VersionReq;
+    ///
+    /// let req = VersionReq::parse(">=1.0, ^1.2, <3").unwrap();
+    /// assert_eq!(req.redundant_comparators(), [0, 2]);
+    ///
+    /// let req = VersionReq::parse(">=1.2, <1.8").unwrap();
+    /// assert!(req.redundant_comparators().is_empty());
+    /// ```
+    pub fn redundant_comparators(&self) -> Vec<usize> {
+        simplify::redundant_comparators(self)
+    }
+
+    /// Summarize which of the versions in `corpus` this requirement matches,
+    /// so interactive tools can show the effect of a requirement as it is
+    /// typed.
+    ///
+    /// Of several matching versions with the same precedence, the first is
+    /// reported as newest or oldest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionReq};
+    ///
+    /// let corpus = ["1.1.0", "1.2.0", "1.3.0-beta", "1.3.0", "2.0.0"]
+    ///     .iter()
+    ///     .map(|v| Version::parse(v).unwrap())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let req = VersionReq::parse("^1.2").unwrap();
+    /// let preview = req.preview(&corpus);
+    /// assert_eq!(preview.matched, 2);
+    /// assert_eq!(preview.newest, Some(Version::new(1, 3, 0)));
+    /// assert_eq!(preview.oldest, Some(Version::new(1, 2, 0)));
+    /// assert_eq!(preview.excluded_prerelease, [Version::parse("1.3.0-beta").unwrap()]);
+    /// ```
+    pub fn preview(&self, corpus: &[Version]) -> Preview {
+        preview::preview(self, corpus)
+    }
+
+    /// Render the requirement following the given formatting conventions,
+    /// for tools that rewrite requirements in a file and must match how the
+    /// rest of the file is written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{DisplayStyle, Separator, VersionReq};
+    ///
+    /// let req = VersionReq::parse("~1.4").unwrap();
+    /// let style = DisplayStyle::new()
+    ///     .separator(Separator::DoubleAmpersand)
+    ///     .desugar(true);
+    /// assert_eq!(req.display_with(&style).to_string(), ">=1.4.0 && <1.5.0-0");
+    /// ```
+    pub fn display_with<'a>(&'a self, style: &'a DisplayStyle) -> StyledReq<'a> {
+        StyledReq { req: self, style }
+    }
+
+    /// Strings close to this requirement, for testing how a system that
+    /// consumes requirements copes with values near the ones it expects.
+    ///
+    /// First come valid requirements: each version number of each comparator
+    /// one higher and one lower, each comparator's operator swapped for a
+    /// neighboring one like `>` for `>=` or `~` for `^`, and the comparators in
+    /// reverse order. Then each comparator with a dot dropped, which is valid
+    /// or not depending on what follows the dot. Last come near misses that
+    /// are not valid requirements: a stray trailing dot or comma, a missing
+    /// comma between comparators, and a `*` alongside other comparators. The
+    /// original requirement and duplicates are left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::VersionReq;
+    ///
+    /// let req = VersionReq::parse(">=1.2, <2").unwrap();
+    /// let mutations: Vec<String> = req.mutations().collect();
+    /// assert!(mutations.contains(&">1.2, <2".to_owned()));
+    /// assert!(mutations.contains(&"<2, >=1.2".to_owned()));
+    /// assert!(mutations.contains(&">=1.2 <2".to_owned()));
+    /// ```
+    #[cfg(feature = "testing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    pub fn mutations(&self) -> Mutations {
+        mutations::version_req(self)
+    }
+
+    /// Precompile this requirement for fast repeated matching.
+    ///
+    /// Shorthand for [`CompiledReq::new`].
+    pub fn compile(&self) -> CompiledReq {
+        CompiledReq::new(self)
+    }
+
+    /// The number of comparators.
+    pub fn len(&self) -> usize {
+        self.comparators.len()
+    }
+
+    /// Whether there are no comparators, which is the requirement `*`,
+    /// [`VersionReq::STAR`].
+    pub fn is_empty(&self) -> bool {
+        self.comparators.is_empty()
+    }
+
+    /// Iterate over the comparators, in the order written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Op, VersionReq};
+    ///
+    /// let req = VersionReq::parse(">=1.2, <1.8").unwrap();
+    /// assert_eq!(req.len(), 2);
+    /// assert!(req.iter().any(|cmp| cmp.op == Op::Less));
+    ///
+    /// for cmp in &req {
+    ///     assert_eq!(cmp.major, 1);
+    /// }
+    /// ```
+    pub fn iter(&self) -> slice::Iter<'_, Comparator> {
+        self.comparators.iter()
+    }
+}
+
+/// The default VersionReq is the same as [`VersionReq::STAR`].
+#[cfg(not(no_const_vec_new))]
+impl Default for VersionReq {
+    fn default() -> Self {
+        VersionReq::STAR
+    }
+}
+
+impl Comparator {
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        Comparator::from_str(text)
+    }
+
+    /// Parse a comparator, also returning the build metadata written on its
+    /// version, which [`parse`][Self::parse] accepts but discards. See
+    /// [`VersionReq::parse_with_build`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Comparator;
+    ///
+    /// let (cmp, build) = Comparator::parse_with_build(">=1.2.3+build.5").unwrap();
+    /// assert_eq!(cmp.to_string(), ">=1.2.3");
+    /// assert_eq!(build.as_str(), "build.5");
+    /// ```
+    pub fn parse_with_build(text: &str) -> Result<(Self, BuildMetadata), Error> {
+        parse::parse_comparator_with_build(text)
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        eval::matches_comparator(self, version)
+    }
+
+    /// Evaluate whether the given version satisfies this comparator, under
+    /// the given matching options.
+    ///
+    /// `matches_with(version, &MatchOptions::new())` is the same as
+    /// [`matches`][Self::matches].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Comparator, MatchOptions, PreReleasePolicy, Version};
+    ///
+    /// let cmp = Comparator::parse("^1.2.3").unwrap();
+    /// let version = Version::parse("1.3.0-beta").unwrap();
+    /// let allowed = MatchOptions::new().policy(PreReleasePolicy::Allowed);
+    ///
+    /// assert!(!cmp.matches(&version));
+    /// assert!(cmp.matches_with(&version, &allowed));
+    /// ```
+    pub fn matches_with(&self, version: &Version, options: &MatchOptions) -> bool {
+        eval::matches_comparator_with(self, version, options)
+    }
+
+    /// Whether this is a wildcard comparator written as `I.*` or as `I.J.*`.
+    ///
+    /// Returns `None` if `op` is not [`Op::Wildcard`], or if all of major,
+    /// minor and patch are present so there is nothing left for a wildcard to
+    /// stand in for.
+    ///
+    /// The position is preserved by formatting a comparator with Display and
+    /// parsing the result, so tools rewriting requirements can keep the form
+    /// the user wrote. `I.*.*` is the same comparator as `I.*`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Comparator, WildcardPosition};
+    ///
+    /// let cmp = Comparator::parse("1.2.*").unwrap();
+    /// assert_eq!(cmp.wildcard_position(), Some(WildcardPosition::Patch));
+    ///
+    /// let cmp = Comparator::parse("1.2").unwrap();
+    /// assert_eq!(cmp.wildcard_position(), None);
+    /// ```
+    pub fn wildcard_position(&self) -> Option<WildcardPosition> {
+        if self.op != Op::Wildcard {
+            return None;
+        }
+        match (self.minor, self.patch) {
+            (None, _) => Some(WildcardPosition::Minor),
+            (Some(_), None) => Some(WildcardPosition::Patch),
+            (Some(_), Some(_)) => None,
+        }
+    }
+
+    /// The structure of this comparator, distinguishing an exact comparator
+    /// on a full version from one on a partial version, and grouping the
+    /// strict and non-strict forms of `>` and `<`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Comparator, ComparatorKind};
+    ///
+    /// let cmp = Comparator::parse("=1.2.3").unwrap();
+    /// assert_eq!(cmp.classify(), ComparatorKind::ExactFull);
+    ///
+    /// let cmp = Comparator::parse("=1.2").unwrap();
+    /// assert_eq!(cmp.classify(), ComparatorKind::ExactPartial);
+    ///
+    /// let cmp = Comparator::parse(">=1.2").unwrap();
+    /// assert_eq!(cmp.classify(), ComparatorKind::RangeLower);
+    /// ```
+    pub fn classify(&self) -> ComparatorKind {
+        match self.op {
+            Op::Exact => {
+                if self.patch.is_some() {
+                    ComparatorKind::ExactFull
+                } else {
+                    ComparatorKind::ExactPartial
+                }
+            }
+            Op::Greater | Op::GreaterEq => ComparatorKind::RangeLower,
+            Op::Less | Op::LessEq => ComparatorKind::RangeUpper,
+            Op::Tilde => ComparatorKind::Tilde,
+            Op::Caret => ComparatorKind::Caret,
+            Op::Wildcard => ComparatorKind::Wildcard,
+            #[cfg(no_non_exhaustive)]
+            Op::__NonExhaustive => unreachable!(),
+        }
+    }
+
+    /// Whether any version matches this comparator.
+    ///
+    /// Only comparators that run off either end of the version space match
+    /// nothing, such as `<0.0.0`, which no release is below and whose
+    /// pre-releases it does not name, or `>18446744073709551615`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Comparator;
+    ///
+    /// assert!(Comparator::parse("<0.0.1").unwrap().is_satisfiable());
+    /// assert!(Comparator::parse("<0.0.0-beta").unwrap().is_satisfiable());
+    /// assert!(!Comparator::parse("<0.0.0").unwrap().is_satisfiable());
+    /// ```
+    pub fn is_satisfiable(&self) -> bool {
+        let req = VersionReq {
+            comparators: iter::once(self.clone()).collect(),
+        };
+        simplify::is_satisfiable(&req)
+    }
+
+    /// The lowest version this comparator admits, by precedence.
+    ///
+    /// Each operator is taken on its own, with caret, tilde and wildcard
+    /// comparators expanded to the range they stand for: `^1.2` is bounded
+    /// below by `Included(1.2.0)`, and `>1.2` by `Included(1.3.0)`, as
+    /// every version greater than `1.2.*` is at least `1.3.0`. Build metadata
+    /// is dropped.
+    ///
+    /// The bounds disregard the rule that a comparator matches a pre-release
+    /// only if it names a pre-release of the same major.minor.patch: `1.5.0-rc`
+    /// lies within the bounds of `^1.2` without matching it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Comparator, Version};
+    /// use std::ops::Bound;
+    ///
+    /// let cmp = Comparator::parse("~1.2").unwrap();
+    /// assert_eq!(cmp.lower_bound(), Bound::Included(Version::new(1, 2, 0)));
+    /// assert_eq!(cmp.upper_bound(), Bound::Excluded(Version::parse("1.3.0-0").unwrap()));
+    ///
+    /// let cmp = Comparator::parse("<=1.2.3").unwrap();
+    /// assert_eq!(cmp.lower_bound(), Bound::Unbounded);
+    /// assert_eq!(cmp.upper_bound(), Bound::Included(Version::new(1, 2, 3)));
+    /// ```
+    pub fn lower_bound(&self) -> Bound<Version> {
+        to_bound(range_set::comparator_range(self).lower)
+    }
+
+    /// The highest version this comparator admits, by precedence.
+    ///
+    /// See [`lower_bound`][Self::lower_bound]. `^1.2` is bounded above by
+    /// `Excluded(2.0.0-0)`, and `<=1.2` by `Excluded(1.3.0-0)`, so that the
+    /// pre-releases of the next line, which precede its release, fall
+    /// outside.
+    pub fn upper_bound(&self) -> Bound<Version> {
+        to_bound(range_set::comparator_range(self).upper)
+    }
+
+    /// The [`lower_bound`][Self::lower_bound] and
+    /// [`upper_bound`][Self::upper_bound] of this comparator together.
+    pub fn range(&self) -> VersionRange {
+        VersionRange::new(self.lower_bound(), self.upper_bound())
+    }
+
+    /// The operator as written by Display, such as `">="`. Empty for
+    /// [`Op::Wildcard`], whose `*` is part of the version.
+    pub fn op_str(&self) -> &'static str {
+        self.op.as_str()
+    }
+
+    /// Everything Display writes after the operator, such as `1.2.3-rc.1` or
+    /// `1.2.*`.
+    ///
+    /// Concatenating [`op_str`][Self::op_str] and this is the same as
+    /// formatting the comparator with Display.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Comparator;
+    ///
+    /// let cmp = Comparator::parse(">= 1.2.3-rc.1").unwrap();
+    /// assert_eq!(cmp.op_str(), ">=");
+    /// assert_eq!(cmp.version_str(), "1.2.3-rc.1");
+    ///
+    /// let cmp = Comparator::parse("1.2.*").unwrap();
+    /// assert_eq!(cmp.op_str(), "");
+    /// assert_eq!(cmp.version_str(), "1.2.*");
+    /// ```
+    pub fn version_str(&self) -> String {
+        format!("{}", display::ComparatorVersion(self))
+    }
+}
+
+impl Op {
+    /// The operator as written in a requirement, such as `">="` or `"^"`.
+    ///
+    /// [`Op::Wildcard`] has no operator token and gives the empty string.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+            Op::Wildcard => "",
+            #[cfg(no_non_exhaustive)]
+            Op::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+impl Prerelease {
+    pub const EMPTY: Self = Prerelease {
+        identifier: Identifier::empty(),
+    };
+
+    pub fn new(text: &str) -> Result<Self, Error> {
+        Prerelease::from_str(text)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+
+    /// Compare by precedence like `Ord`, but without regard to ASCII case in
+    /// alphanumeric identifiers, for ecosystems that write `RC.1` and `rc.1`
+    /// interchangeably.
+    ///
+    /// ```
+    /// use semver::Prerelease;
+    /// use std::cmp::Ordering;
+    ///
+    /// let upper = Prerelease::new("RC.1").unwrap();
+    /// let lower = Prerelease::new("rc.1").unwrap();
+    /// assert_ne!(upper, lower);
+    /// assert_eq!(upper.cmp_ignore_ascii_case(&lower), Ordering::Equal);
+    /// ```
+    pub fn cmp_ignore_ascii_case(&self, other: &Self) -> Ordering {
+        impls::cmp_prerelease(self, other, true)
+    }
+
+    /// Compare the identifiers alone, by [`cmp_prerelease_str`].
+    ///
+    /// This differs from `Ord` only for the empty pre-release, which is the
+    /// empty list of identifiers and so compares less than any other, where
+    /// `Ord` takes it to stand for a release and compares it greater.
+    ///
+    /// ```
+    /// use semver::Prerelease;
+    /// use std::cmp::Ordering;
+    ///
+    /// let alpha = Prerelease::new("alpha").unwrap();
+    /// assert_eq!(Prerelease::EMPTY.cmp(&alpha), Ordering::Greater);
+    /// assert_eq!(Prerelease::EMPTY.cmp_identifiers(&alpha), Ordering::Less);
+    /// ```
+    pub fn cmp_identifiers(&self, other: &Self) -> Ordering {
+        impls::cmp_identifiers(self.as_str(), other.as_str(), false)
+    }
+
+    /// A string whose byte order is the precedence order of pre-releases, for
+    /// storing in a database column of its own next to major, minor and
+    /// patch.
+    ///
+    /// Numeric identifiers are ordered numerically, `beta.2` before
+    /// `beta.11`, and before alphanumeric ones. As with `Ord`, the empty
+    /// pre-release stands for a release and its key sorts after every other,
+    /// so sorting rows by major, minor, patch and this key sorts them by
+    /// version precedence. The key is ASCII, and suits a column with a binary
+    /// collation.
+    ///
+    /// ```
+    /// use semver::Prerelease;
+    ///
+    /// let beta2 = Prerelease::new("beta.2").unwrap();
+    /// let beta11 = Prerelease::new("beta.11").unwrap();
+    /// assert!(beta2.to_sort_key() < beta11.to_sort_key());
+    /// assert!(beta11.to_sort_key() < Prerelease::EMPTY.to_sort_key());
+    /// ```
+    pub fn to_sort_key(&self) -> String {
+        precedence::prerelease_sort_key(self)
+    }
+
+    /// Append one identifier, like `nightly` to `beta.3`, giving
+    /// `beta.3.nightly`.
+    ///
+    /// Returns an error and leaves the pre-release unchanged if `identifier`
+    /// is empty, contains a dot or a character not allowed in a pre-release,
+    /// or is numeric with a leading zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Prerelease;
+    ///
+    /// let mut pre = Prerelease::new("beta.3").unwrap();
+    /// pre.push_identifier("nightly").unwrap();
+    /// pre.push_identifier("20240601").unwrap();
+    /// assert_eq!(pre.as_str(), "beta.3.nightly.20240601");
+    ///
+    /// assert_eq!(pre.pop_identifier().unwrap(), "20240601");
+    /// assert_eq!(pre.as_str(), "beta.3.nightly");
+    /// ```
+    pub fn push_identifier(&mut self, identifier: &str) -> Result<(), Error> {
+        if identifier.contains('.') {
+            return Err(Error::new(ErrorKind::IllegalCharacter(Position::Pre)));
+        }
+        if identifier.is_empty() {
+            return Err(Error::new(ErrorKind::EmptySegment(Position::Pre)));
+        }
+        *self = if self.is_empty() {
+            Prerelease::new(identifier)?
+        } else {
+            Prerelease::new(&format!("{}.{}", self, identifier))?
+        };
+        Ok(())
+    }
+
+    /// Remove the last identifier and return it, or return `None` if the
+    /// pre-release is empty.
+    pub fn pop_identifier(&mut self) -> Option<String> {
+        let (rest, last) = match self.as_str().rfind('.') {
+            Some(dot) => (&self.as_str()[..dot], &self.as_str()[dot + 1..]),
+            None if self.is_empty() => return None,
+            None => ("", self.as_str()),
+        };
+        let last = String::from(last);
+        let rest = Prerelease {
+            identifier: unsafe { Identifier::new_unchecked(rest) },
+        };
+        *self = rest;
+        Some(last)
+    }
+
+    /// This pre-release with its trailing numeric identifier set to `n`,
+    /// replacing the existing one, or appending one if the last identifier is
+    /// not numeric or is too large for a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Prerelease;
+    ///
+    /// let beta = Prerelease::new("beta").unwrap();
+    /// assert_eq!(beta.with_numeric_suffix(1).as_str(), "beta.1");
+    ///
+    /// let beta2 = Prerelease::new("beta.2").unwrap();
+    /// assert_eq!(beta2.with_numeric_suffix(3).as_str(), "beta.3");
+    /// ```
+    pub fn with_numeric_suffix(&self, n: u64) -> Self {
+        let mut pre = self.clone();
+        pre.strip_numeric_suffix();
+        let text = if pre.is_empty() {
+            format!("{}", n)
+        } else {
+            format!("{}.{}", pre, n)
+        };
+        Prerelease {
+            identifier: unsafe { Identifier::new_unchecked(&text) },
+        }
+    }
+
+    /// Remove the trailing identifier if it is numeric, returning its value.
+    ///
+    /// Returns `None` and leaves the pre-release unchanged if the last
+    /// identifier is not numeric, or is too large for a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Prerelease;
+    ///
+    /// let mut pre = Prerelease::new("rc.4").unwrap();
+    /// assert_eq!(pre.strip_numeric_suffix(), Some(4));
+    /// assert_eq!(pre.as_str(), "rc");
+    /// assert_eq!(pre.strip_numeric_suffix(), None);
+    /// ```
+    pub fn strip_numeric_suffix(&mut self) -> Option<u64> {
+        let last = match self.as_str().rfind('.') {
+            Some(dot) => &self.as_str()[dot + 1..],
+            None => self.as_str(),
+        };
+        if last.is_empty() || !last.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let n = last.parse().ok()?;
+        self.pop_identifier();
+        Some(n)
+    }
+
+    /// Share storage with every other interned pre-release of the same text.
+    ///
+    /// See [`Version::intern`].
+    #[cfg(feature = "intern")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "intern")))]
+    pub fn intern(&mut self) {
+        self.identifier = self.identifier.intern();
+    }
+}
+
+impl BuildMetadata {
+    pub const EMPTY: Self = BuildMetadata {
+        identifier: Identifier::empty(),
+    };
+
+    pub fn new(text: &str) -> Result<Self, Error> {
+        BuildMetadata::from_str(text)
+    }
+
+    /// Build metadata consisting of the given identifiers, joined by dots.
+    ///
+    /// Returns an error if any part is empty, contains a dot, or contains a
+    /// character not allowed in build metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::BuildMetadata;
+    ///
+    /// let build = BuildMetadata::from_parts(&["linux", "x86-64", "musl"]).unwrap();
+    /// assert_eq!(build.as_str(), "linux.x86-64.musl");
+    /// assert!(BuildMetadata::from_parts(&["linux", "x86_64"]).is_err());
+    /// ```
+    pub fn from_parts(parts: &[&str]) -> Result<Self, Error> {
+        let mut build = BuildMetadata::EMPTY;
+        for part in parts {
+            build.append(part)?;
+        }
+        Ok(build)
+    }
+
+    /// Append one identifier, like `dirty` to `g8e6aa2a`, giving
+    /// `g8e6aa2a.dirty`.
+    ///
+    /// Returns an error and leaves the build metadata unchanged if
+    /// `identifier` is empty, contains a dot, or contains a character not
+    /// allowed in build metadata.
+    pub fn append(&mut self, identifier: &str) -> Result<(), Error> {
+        if identifier.contains('.') {
+            return Err(Error::new(ErrorKind::IllegalCharacter(Position::Build)));
+        }
+        if identifier.is_empty() {
+            return Err(Error::new(ErrorKind::EmptySegment(Position::Build)));
+        }
+        *self = if self.is_empty() {
+            BuildMetadata::new(identifier)?
+        } else {
+            BuildMetadata::new(&format!("{}.{}", self, identifier))?
+        };
+        Ok(())
+    }
+
+    /// Build metadata identifying a Git commit, like `8e6aa2a`, from a full
+    /// or abbreviated hexadecimal object name. Uppercase hex digits are
+    /// lowercased.
+    ///
+    /// Returns an error if `sha` is empty or not hexadecimal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::BuildMetadata;
+    ///
+    /// let build = BuildMetadata::from_git_sha("8E6AA2A").unwrap();
+    /// assert_eq!(build.as_str(), "8e6aa2a");
+    /// assert!(BuildMetadata::from_git_sha("main").is_err());
+    /// ```
+    pub fn from_git_sha(sha: &str) -> Result<Self, Error> {
+        if sha.is_empty() {
+            return Err(Error::new(ErrorKind::EmptySegment(Position::Build)));
+        }
+        if !sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::new(ErrorKind::IllegalCharacter(Position::Build)));
+        }
+        BuildMetadata::new(&sha.to_ascii_lowercase())
+    }
+
+    /// Build metadata holding a UTC timestamp as `YYYYMMDDhhmmss`, like
+    /// `20240601093000`, from seconds since the Unix epoch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::BuildMetadata;
+    ///
+    /// let build = BuildMetadata::from_timestamp(1717234200);
+    /// assert_eq!(build.as_str(), "20240601093000");
+    /// ```
+    pub fn from_timestamp(unix_seconds: u64) -> Self {
+        let (year, month, day) = civil_from_days(unix_seconds / 86400);
+        let seconds = unix_seconds % 86400;
+        let text = format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}",
+            year,
+            month,
+            day,
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+        );
+        BuildMetadata {
+            identifier: unsafe { Identifier::new_unchecked(&text) },
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.identifier.is_empty()
+    }
+
+    /// Share storage with every other interned build metadata of the same
+    /// text.
+    ///
+    /// See [`Version::intern`].
+    #[cfg(feature = "intern")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "intern")))]
+    pub fn intern(&mut self) {
+        self.identifier = self.identifier.intern();
+    }
+}
+
+// Proleptic Gregorian calendar date of the given number of days since
+// 1970-01-01, after Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as u64;
+    (year, month, day)
+}
+
+fn to_bound(bound: Option<range_set::Bound>) -> Bound<Version> {
+    match bound {
+        Some(bound) => {
+            if bound.inclusive {
+                Bound::Included(bound.version)
+            } else {
+                Bound::Excluded(bound.version)
+            }
+        }
+        None => Bound::Unbounded,
     }
 }
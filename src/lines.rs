@@ -0,0 +1,54 @@
+use crate::backport::*;
+use crate::{Error, Version, VersionReq};
+use core::str::FromStr;
+
+/// Parse a newline-delimited list of versions, collecting the versions that
+/// parse and the errors of the lines that do not.
+///
+/// Each error is paired with the zero-based index of its line. Whitespace
+/// around each line, including the `\r` of a `\r\n` line ending, is ignored,
+/// and lines that are blank are skipped without producing an error.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let dump = "1.0.0\n1.0.1\n\n1.1\n2.0.0-rc.1\n";
+/// let (versions, errors) = semver::parse_lines(dump);
+///
+/// assert_eq!(versions.len(), 3);
+/// assert_eq!(versions[2], Version::parse("2.0.0-rc.1").unwrap());
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].0, 3);
+/// ```
+pub fn parse_lines(text: &str) -> (Vec<Version>, Vec<(usize, Error)>) {
+    parse_each(text)
+}
+
+/// Parse a newline-delimited list of version requirements, collecting the
+/// requirements that parse and the errors of the lines that do not.
+///
+/// Lines are handled the same as by [`parse_lines`].
+pub fn parse_req_lines(text: &str) -> (Vec<VersionReq>, Vec<(usize, Error)>) {
+    parse_each(text)
+}
+
+fn parse_each<T>(text: &str) -> (Vec<T>, Vec<(usize, Error)>)
+where
+    T: FromStr<Err = Error>,
+{
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse() {
+            Ok(value) => parsed.push(value),
+            Err(error) => errors.push((i, error)),
+        }
+    }
+    (parsed, errors)
+}
@@ -0,0 +1,83 @@
+use crate::Version;
+use core::cmp::Ordering;
+
+/// Compare two strings that are each either a SemVer version or not.
+///
+/// - Two valid versions compare by [precedence][Version::cmp_precedence].
+///
+/// - A valid version always compares less than a string that is not one, so
+///   sorting a mixed list puts every version first, in order, followed by
+///   everything else.
+///
+/// - Two strings that are not valid versions compare in natural order: runs
+///   of digits compare numerically, and everything in between compares
+///   bytewise, so `r9` sorts before `r10`. A run of digits sorts before a run
+///   of anything else at the same position.
+///
+/// Strings that compare equal by these rules, such as versions that differ
+/// only in build metadata, or `r010` and `r10`, are finally compared
+/// bytewise, so that the result is only `Equal` for identical strings and
+/// sorting is deterministic.
+///
+/// # Example
+///
+/// ```
+/// let mut tags = ["release-10", "1.10.0", "nightly", "1.9.0", "release-9"];
+/// tags.sort_by(|a, b| semver::cmp_loose(a, b));
+/// assert_eq!(tags, ["1.9.0", "1.10.0", "nightly", "release-9", "release-10"]);
+/// ```
+pub fn cmp_loose(a: &str, b: &str) -> Ordering {
+    let ordering = match (Version::parse(a), Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp_precedence(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => cmp_natural(a, b),
+    };
+    ordering.then_with(|| Ord::cmp(a, b))
+}
+
+fn cmp_natural(a: &str, b: &str) -> Ordering {
+    let mut a = a.as_bytes();
+    let mut b = b.as_bytes();
+    loop {
+        let (a_run, a_digits, a_rest) = run(a);
+        let (b_run, b_digits, b_rest) = run(b);
+        if a_run.is_empty() || b_run.is_empty() {
+            return Ord::cmp(&a_run.len(), &b_run.len());
+        }
+        let ordering = match (a_digits, b_digits) {
+            (true, true) => cmp_numeric(a_run, b_run),
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => Ord::cmp(a_run, b_run),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        a = a_rest;
+        b = b_rest;
+    }
+}
+
+// The leading run of digits or of non-digits, whether it is digits, and the
+// rest of the input.
+fn run(input: &[u8]) -> (&[u8], bool, &[u8]) {
+    let digits = input.first().map_or(false, u8::is_ascii_digit);
+    let len = input
+        .iter()
+        .take_while(|b| b.is_ascii_digit() == digits)
+        .count();
+    let (run, rest) = input.split_at(len);
+    (run, digits, rest)
+}
+
+fn cmp_numeric(a: &[u8], b: &[u8]) -> Ordering {
+    let a = trim_leading_zeros(a);
+    let b = trim_leading_zeros(b);
+    Ord::cmp(&a.len(), &b.len()).then_with(|| Ord::cmp(a, b))
+}
+
+fn trim_leading_zeros(digits: &[u8]) -> &[u8] {
+    let zeros = digits.iter().take_while(|&&b| b == b'0').count();
+    &digits[zeros..]
+}
@@ -0,0 +1,88 @@
+/// The version of the crate being compiled, from `CARGO_PKG_VERSION`.
+///
+/// The version is parsed and validated at compile time, so a malformed
+/// version in Cargo.toml is a compile error rather than a runtime panic, and
+/// evaluating the macro involves no parsing and no unwrap.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq};
+///
+/// let version: Version = semver::crate_version!();
+/// let req = VersionReq::parse(">=0.1").unwrap();
+/// assert!(req.matches(&version));
+/// ```
+#[cfg(not(no_const_panic))]
+#[macro_export]
+macro_rules! crate_version {
+    () => {{
+        const VERSION: $crate::__private::ConstVersion =
+            $crate::__private::ConstVersion::parse(env!("CARGO_PKG_VERSION"));
+        VERSION.to_version()
+    }};
+}
+
+// Compilers without panics in const fn parse at runtime instead.
+#[cfg(no_const_panic)] // rustc <1.57
+#[macro_export]
+macro_rules! crate_version {
+    () => {
+        $crate::Version::parse(env!("CARGO_PKG_VERSION")).expect("invalid CARGO_PKG_VERSION")
+    };
+}
+
+/// A caret requirement on the version of the crate being compiled, from
+/// `CARGO_PKG_VERSION`: `^1.2.3` for a crate at version 1.2.3.
+///
+/// Like [`crate_version!`], the version is parsed and validated at compile
+/// time. Build metadata in the crate's version is discarded.
+///
+/// # Example
+///
+/// ```
+/// let req = semver::pkg_version_req!();
+/// assert!(req.matches(&semver::crate_version!()));
+/// ```
+#[macro_export]
+macro_rules! pkg_version_req {
+    () => {
+        $crate::__private::caret_req($crate::crate_version!())
+    };
+}
+
+/// A version requirement parsed on first use, for a `static` shared across
+/// threads.
+///
+/// Expands to a [`LazyLock<VersionReq>`][std::sync::LazyLock]. The syntax of
+/// the requirement is checked at compile time, so a malformed requirement is
+/// a compile error rather than a panic on first use, and every use after the
+/// first costs no parsing.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq};
+/// use std::sync::LazyLock;
+///
+/// static SUPPORTED: LazyLock<VersionReq> = semver::lazy_req!(">=1.2, <2");
+///
+/// assert!(SUPPORTED.matches(&Version::new(1, 4, 0)));
+/// ```
+///
+/// ```compile_fail
+/// # use semver::VersionReq;
+/// # use std::sync::LazyLock;
+/// static SUPPORTED: LazyLock<VersionReq> = semver::lazy_req!(">=1.2 <2");
+/// ```
+#[cfg(all(feature = "std", not(no_lazy_lock)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[macro_export]
+macro_rules! lazy_req {
+    ($req:expr) => {{
+        const _: () = $crate::__private::check_req($req);
+        ::std::sync::LazyLock::new(|| {
+            $crate::VersionReq::parse($req).expect("requirement checked at compile time")
+        })
+    }};
+}
@@ -0,0 +1,51 @@
+use crate::{bump, Version, VersionReq};
+use core::ops::Bound;
+
+/// Iterator over the releases that match a requirement, in order of
+/// precedence.
+///
+/// Produced by [`VersionReq::iter_matching`].
+#[derive(Clone, Debug)]
+pub struct Matching {
+    req: VersionReq,
+    // None once the iterator is exhausted.
+    next: Option<Version>,
+}
+
+pub(crate) fn matching(req: &VersionReq, start: Version) -> Matching {
+    // Skip straight to the lowest release that every comparator admits.
+    let mut first = Some(release(&start));
+    for cmp in &req.comparators {
+        let lowest = match cmp.lower_bound() {
+            Bound::Included(bound) => Some(release(&bound)),
+            Bound::Excluded(bound) => bump::next_release(&bound),
+            Bound::Unbounded => continue,
+        };
+        first = match (first, lowest) {
+            (Some(first), Some(lowest)) => Some(if lowest > first { lowest } else { first }),
+            _ => None,
+        };
+    }
+    Matching {
+        req: req.clone(),
+        next: first.filter(|first| req.matches(first)),
+    }
+}
+
+// The lowest release at or after `version`.
+fn release(version: &Version) -> Version {
+    Version::new(version.major, version.minor, version.patch)
+}
+
+impl Iterator for Matching {
+    type Item = Version;
+
+    // The releases that a requirement matches are consecutive, so the
+    // first one that does not match ends the iteration.
+    fn next(&mut self) -> Option<Self::Item> {
+        let version = self.next.take()?;
+        let req = &self.req;
+        self.next = bump::next_release(&version).filter(|next| req.matches(next));
+        Some(version)
+    }
+}
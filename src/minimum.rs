@@ -0,0 +1,39 @@
+use crate::{simplify, Version, VersionReq};
+
+/// The lowest version that satisfies every one of `reqs` at once, or `None`
+/// if they conflict.
+///
+/// This is the minimum supported version implied by a set of constraints,
+/// such as the `rust-version` of every dependency of a crate. The result is
+/// exact: it follows caret, tilde and wildcard semantics and the rule that a
+/// pre-release only matches a requirement naming a pre-release of the same
+/// major.minor.patch, checked against each requirement separately, so it is a
+/// pre-release only if every requirement names one of that release.
+/// With no requirements, the result is `0.0.0`.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq};
+///
+/// let reqs = ["^1.56", ">=1.60", "<2"]
+///     .iter()
+///     .map(|req| req.parse::<VersionReq>().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(semver::minimum_supported(&reqs), Some(Version::new(1, 60, 0)));
+///
+/// let reqs = ["^1.56", ">1.70.0-beta.2"]
+///     .iter()
+///     .map(|req| req.parse::<VersionReq>().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(semver::minimum_supported(&reqs), Some(Version::new(1, 70, 0)));
+///
+/// let reqs = ["^1.56", "<1.50"]
+///     .iter()
+///     .map(|req| req.parse::<VersionReq>().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(semver::minimum_supported(&reqs), None);
+/// ```
+pub fn minimum_supported(reqs: &[VersionReq]) -> Option<Version> {
+    simplify::min_match(reqs)
+}
@@ -0,0 +1,195 @@
+use crate::alloc::vec;
+use crate::backport::*;
+use crate::{Comparator, Op, Version, VersionReq};
+
+/// Iterator over variants of a version or requirement, for robustness
+/// testing.
+///
+/// Produced by [`Version::mutations`] and [`VersionReq::mutations`].
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+#[derive(Clone, Debug)]
+pub struct Mutations {
+    iter: vec::IntoIter<String>,
+}
+
+impl Iterator for Mutations {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Mutations {}
+
+pub(crate) fn version(version: &Version) -> Mutations {
+    let original = version.to_string();
+    let mut out = Vec::new();
+
+    for component in 0..3 {
+        for &delta in &[-1, 1] {
+            let mut nearby = version.clone();
+            let number = match component {
+                0 => &mut nearby.major,
+                1 => &mut nearby.minor,
+                _ => &mut nearby.patch,
+            };
+            if let Some(moved) = offset(*number, delta) {
+                *number = moved;
+                out.push(nearby.to_string());
+            }
+        }
+    }
+
+    let core = format!("{}.{}.{}", version.major, version.minor, version.patch);
+    let pre = version.pre.as_str();
+    let build = version.build.as_str();
+    let with = |pre: &str, build: &str| {
+        let mut text = core.clone();
+        if !pre.is_empty() {
+            text.push('-');
+            text.push_str(pre);
+        }
+        if !build.is_empty() {
+            text.push('+');
+            text.push_str(build);
+        }
+        text
+    };
+    if pre.is_empty() {
+        out.push(with("0", build));
+    } else {
+        out.push(with("", build));
+        out.push(with(&swapped(pre), build));
+    }
+    if build.is_empty() {
+        out.push(with(pre, "0"));
+    } else {
+        out.push(with(pre, ""));
+        out.push(with(pre, &swapped(build)));
+    }
+
+    // Near misses, none of which are valid versions.
+    for dot in core.match_indices('.').map(|(i, _)| i) {
+        out.push(format!("{}{}", &core[..dot], &original[dot + 1..]));
+    }
+    out.push(format!("0{}", original));
+    out.push(format!("v{}", original));
+    out.push(format!("{}.{}", core, &original[core.len()..]));
+    if pre.is_empty() {
+        out.push(format!("{}-", core));
+    }
+    out.push(format!("{}+", original));
+
+    finish(out, &original)
+}
+
+pub(crate) fn version_req(req: &VersionReq) -> Mutations {
+    let original = req.to_string();
+    let comparators: Vec<String> = req.comparators.iter().map(ToString::to_string).collect();
+    let mut out = Vec::new();
+    let replaced = |i: usize, replacement: &str| {
+        let mut parts = comparators.clone();
+        parts[i] = String::from(replacement);
+        parts.join(", ")
+    };
+
+    for (i, cmp) in req.comparators.iter().enumerate() {
+        for component in 0..3 {
+            for &delta in &[-1, 1] {
+                let mut nearby = cmp.clone();
+                let number = match component {
+                    0 => Some(&mut nearby.major),
+                    1 => nearby.minor.as_mut(),
+                    _ => nearby.patch.as_mut(),
+                };
+                if let Some(number) = number {
+                    if let Some(moved) = offset(*number, delta) {
+                        *number = moved;
+                        out.push(replaced(i, &nearby.to_string()));
+                    }
+                }
+            }
+        }
+        if let Some(op) = neighbor_op(cmp.op) {
+            let nearby = Comparator { op, ..cmp.clone() };
+            out.push(replaced(i, &nearby.to_string()));
+        }
+    }
+    if comparators.len() > 1 {
+        let mut parts = comparators.clone();
+        parts.reverse();
+        out.push(parts.join(", "));
+    }
+
+    // A dropped dot leaves a shorter partial version, like `^12` from
+    // `^1.2`, which is still valid unless it runs into a wildcard or
+    // pre-release.
+    for (i, text) in comparators.iter().enumerate() {
+        for dot in text.match_indices('.').map(|(i, _)| i) {
+            out.push(replaced(
+                i,
+                &format!("{}{}", &text[..dot], &text[dot + 1..]),
+            ));
+        }
+    }
+
+    // Near misses, none of which are valid requirements.
+    for (i, text) in comparators.iter().enumerate() {
+        out.push(replaced(i, &format!("{}.", text)));
+    }
+    if comparators.len() > 1 {
+        out.push(comparators.join(" "));
+    }
+    out.push(format!("{},", original));
+    out.push(format!("{}, *", original));
+
+    finish(out, &original)
+}
+
+fn finish(mut out: Vec<String>, original: &str) -> Mutations {
+    let mut seen = Vec::new();
+    out.retain(|text| {
+        if text == original || seen.contains(text) {
+            false
+        } else {
+            seen.push(text.clone());
+            true
+        }
+    });
+    Mutations {
+        iter: out.into_iter(),
+    }
+}
+
+fn offset(number: u64, delta: i8) -> Option<u64> {
+    if delta < 0 {
+        number.checked_sub(1)
+    } else {
+        number.checked_add(1)
+    }
+}
+
+// The dot-separated identifiers in reverse order.
+fn swapped(identifiers: &str) -> String {
+    let mut parts: Vec<&str> = identifiers.split('.').collect();
+    parts.reverse();
+    parts.join(".")
+}
+
+fn neighbor_op(op: Op) -> Option<Op> {
+    match op {
+        Op::Exact => Some(Op::GreaterEq),
+        Op::Greater => Some(Op::GreaterEq),
+        Op::GreaterEq => Some(Op::Greater),
+        Op::Less => Some(Op::LessEq),
+        Op::LessEq => Some(Op::Less),
+        Op::Tilde => Some(Op::Caret),
+        Op::Caret => Some(Op::Tilde),
+        _ => None,
+    }
+}
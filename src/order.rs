@@ -0,0 +1,146 @@
+use crate::Version;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Orders versions from oldest to newest, by [`Version`]'s `Ord`.
+///
+/// This is the order versions already have. The wrapper exists for symmetry
+/// with [`NewestFirst`], and to give anything that borrows as a `Version`,
+/// like a struct holding a version and its download URL, the ordering of
+/// that version.
+///
+/// Sorted collections such as `BTreeSet` list the oldest version first. A
+/// `BinaryHeap`, which pops its greatest element, pops the newest first.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct OldestFirst<T = Version>(pub T);
+
+/// Orders versions from newest to oldest, the reverse of [`Version`]'s `Ord`.
+///
+/// Sorted collections such as `BTreeSet` list the newest version first. A
+/// `BinaryHeap`, which pops its greatest element, pops the oldest first.
+///
+/// # Example
+///
+/// ```
+/// use semver::{NewestFirst, Version};
+/// use std::collections::BTreeSet;
+///
+/// let set = ["1.0.0", "1.2.0", "1.1.0"]
+///     .iter()
+///     .map(|v| NewestFirst(v.parse::<Version>().unwrap()))
+///     .collect::<BTreeSet<_>>();
+///
+/// let newest = set.iter().next().unwrap();
+/// assert_eq!(newest.0.to_string(), "1.2.0");
+/// ```
+#[derive(Copy, Clone, Default, Debug)]
+pub struct NewestFirst<T = Version>(pub T);
+
+/// Orders versions by [precedence][Version::cmp_precedence], from lowest to
+/// highest, disregarding build metadata.
+///
+/// Unlike `Version`'s own `Eq`, versions that differ only in build metadata
+/// are equal under `ByPrecedence`, so a `BTreeSet<ByPrecedence<Version>>`
+/// holds at most one version per precedence and a `HashSet` agrees with it.
+///
+/// # Example
+///
+/// ```
+/// use semver::{ByPrecedence, Version};
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap = ["1.0.0+linux", "1.0.0+macos", "1.0.0-rc.1"]
+///     .iter()
+///     .map(|v| ByPrecedence(v.parse::<Version>().unwrap()))
+///     .collect::<BinaryHeap<_>>();
+///
+/// assert_eq!(heap.pop().unwrap().0.pre.as_str(), "");
+/// assert_eq!(heap.pop().unwrap().0.pre.as_str(), "");
+/// assert_eq!(heap.pop().unwrap().0.pre.as_str(), "rc.1");
+/// ```
+#[derive(Copy, Clone, Default, Debug)]
+pub struct ByPrecedence<T = Version>(pub T);
+
+impl<T: Borrow<Version>> PartialEq for OldestFirst<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.borrow() == other.0.borrow()
+    }
+}
+
+impl<T: Borrow<Version>> Eq for OldestFirst<T> {}
+
+impl<T: Borrow<Version>> PartialOrd for OldestFirst<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Borrow<Version>> Ord for OldestFirst<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(self.0.borrow(), other.0.borrow())
+    }
+}
+
+impl<T: Borrow<Version>> Hash for OldestFirst<T> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.0.borrow().hash(hasher);
+    }
+}
+
+impl<T: Borrow<Version>> PartialEq for NewestFirst<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.borrow() == other.0.borrow()
+    }
+}
+
+impl<T: Borrow<Version>> Eq for NewestFirst<T> {}
+
+impl<T: Borrow<Version>> PartialOrd for NewestFirst<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Borrow<Version>> Ord for NewestFirst<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(other.0.borrow(), self.0.borrow())
+    }
+}
+
+impl<T: Borrow<Version>> Hash for NewestFirst<T> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.0.borrow().hash(hasher);
+    }
+}
+
+impl<T: Borrow<Version>> PartialEq for ByPrecedence<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Borrow<Version>> Eq for ByPrecedence<T> {}
+
+impl<T: Borrow<Version>> PartialOrd for ByPrecedence<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Borrow<Version>> Ord for ByPrecedence<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.borrow().cmp_precedence(other.0.borrow())
+    }
+}
+
+impl<T: Borrow<Version>> Hash for ByPrecedence<T> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        // Consistent with Eq: everything but build metadata.
+        let version = self.0.borrow();
+        version.major.hash(hasher);
+        version.minor.hash(hasher);
+        version.patch.hash(hasher);
+        version.pre.hash(hasher);
+    }
+}
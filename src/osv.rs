@@ -0,0 +1,189 @@
+use crate::backport::*;
+use crate::range_set::{Bound, Range};
+use crate::{Comparator, Error, Op, Version, VersionRangeSet, VersionReq};
+use core::cmp::Ordering;
+
+/// One event of an OSV `SEMVER` range, as found in the `events` array of an
+/// entry of `affected[].ranges[]` in an [OSV] advisory.
+///
+/// Each event holds the version string exactly as it appears in the
+/// advisory. The special introduced version `"0"` means the range starts
+/// before every version.
+///
+/// [OSV]: https://ossf.github.io/osv-schema/
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum OsvEvent<'a> {
+    /// `{"introduced": "..."}`: this version and later ones are affected.
+    Introduced(&'a str),
+    /// `{"fixed": "..."}`: this version and later ones are not affected.
+    Fixed(&'a str),
+    /// `{"last_affected": "..."}`: versions after this one are not affected.
+    LastAffected(&'a str),
+}
+
+/// Translate the events of one OSV `SEMVER` range into requirements, one per
+/// affected interval.
+///
+/// Events are evaluated in order of their versions, as the OSV schema
+/// specifies, regardless of the order they are given in. A range that is
+/// introduced and never fixed extends to every later version.
+///
+/// The requirements follow the rules of [`VersionReq::matches`], so a
+/// pre-release version inside an interval, like `1.1.0-rc.1` for `>=1.0.0,
+/// <1.2.0`, does not match, whereas OSV considers it affected. Use
+/// [`VersionRangeSet::from_osv`] to test versions exactly as OSV intends.
+///
+/// # Errors
+///
+/// Returns an error if a version other than the introduced `"0"` is not a
+/// valid SemVer version.
+///
+/// # Example
+///
+/// ```
+/// use semver::OsvEvent;
+///
+/// let events = [
+///     OsvEvent::Introduced("0"),
+///     OsvEvent::Fixed("1.4.2"),
+///     OsvEvent::Introduced("2.0.0"),
+///     OsvEvent::LastAffected("2.3.5"),
+/// ];
+/// let reqs = semver::osv_reqs(&events).unwrap();
+/// assert_eq!(reqs[0].to_string(), "<1.4.2");
+/// assert_eq!(reqs[1].to_string(), ">=2.0.0, <=2.3.5");
+/// ```
+pub fn osv_reqs(events: &[OsvEvent]) -> Result<Vec<VersionReq>, Error> {
+    let ranges = osv_ranges(events)?;
+    Ok(ranges.into_iter().map(req_of).collect())
+}
+
+impl VersionRangeSet {
+    /// Build a set from many OSV `SEMVER` ranges, each given as its list of
+    /// events, for example all ranges of every advisory affecting a package.
+    ///
+    /// Unlike requirements, the set contains every version lying in an
+    /// affected interval by precedence, pre-releases included, which is how
+    /// OSV defines a range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a version other than the introduced `"0"` is not
+    /// a valid SemVer version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{OsvEvent, Version, VersionRangeSet};
+    ///
+    /// let advisory = [OsvEvent::Introduced("1.0.0"), OsvEvent::Fixed("1.2.0")];
+    /// let set = VersionRangeSet::from_osv(vec![&advisory[..]]).unwrap();
+    ///
+    /// assert!(set.contains(&Version::parse("1.1.0-rc.1").unwrap()));
+    /// assert!(set.contains(&Version::parse("1.2.0-rc.1").unwrap()));
+    /// assert!(!set.contains(&Version::parse("1.2.0").unwrap()));
+    /// ```
+    pub fn from_osv<'a, 'b, I>(ranges: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = &'b [OsvEvent<'a>]>,
+        'a: 'b,
+    {
+        let mut all = Vec::new();
+        for events in ranges {
+            all.extend(osv_ranges(events)?);
+        }
+        Ok(VersionRangeSet::from_precedence_ranges(all))
+    }
+}
+
+fn osv_ranges(events: &[OsvEvent]) -> Result<Vec<Range>, Error> {
+    // None is the introduced version "0", which sorts before everything.
+    let mut sorted = Vec::with_capacity(events.len());
+    for event in events {
+        let (text, upper) = match *event {
+            OsvEvent::Introduced(text) => (text, None),
+            OsvEvent::Fixed(text) => (text, Some(false)),
+            OsvEvent::LastAffected(text) => (text, Some(true)),
+        };
+        let version = if upper.is_none() && text == "0" {
+            None
+        } else {
+            Some(Version::parse(text)?)
+        };
+        sorted.push((version, upper));
+    }
+    sorted.sort_by(|a, b| match (&a.0, &b.0) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => a.cmp_precedence(b),
+    });
+
+    let mut ranges = Vec::new();
+    let mut introduced = None;
+    for (version, upper) in sorted {
+        match upper {
+            None => {
+                if introduced.is_none() {
+                    introduced = Some(version);
+                }
+            }
+            Some(inclusive) => {
+                if let Some(lower) = introduced.take() {
+                    let version = version.unwrap();
+                    let empty = match &lower {
+                        Some(lower) => {
+                            !inclusive && lower.cmp_precedence(&version) == Ordering::Equal
+                        }
+                        None => false,
+                    };
+                    if !empty {
+                        ranges.push(Range {
+                            lower: lower.map(|version| Bound {
+                                version,
+                                inclusive: true,
+                            }),
+                            upper: Some(Bound { version, inclusive }),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    if let Some(lower) = introduced {
+        ranges.push(Range {
+            lower: lower.map(|version| Bound {
+                version,
+                inclusive: true,
+            }),
+            upper: None,
+        });
+    }
+    Ok(ranges)
+}
+
+fn req_of(range: Range) -> VersionReq {
+    let mut comparators = Vec::new();
+    if let Some(lower) = range.lower {
+        comparators.push(comparator(Op::GreaterEq, lower.version));
+    }
+    if let Some(upper) = range.upper {
+        let op = if upper.inclusive {
+            Op::LessEq
+        } else {
+            Op::Less
+        };
+        comparators.push(comparator(op, upper.version));
+    }
+    VersionReq { comparators }
+}
+
+fn comparator(op: Op, version: Version) -> Comparator {
+    Comparator {
+        op,
+        major: version.major,
+        minor: Some(version.minor),
+        patch: Some(version.patch),
+        pre: version.pre,
+    }
+}
@@ -1,7 +1,7 @@
 use crate::backport::*;
 use crate::error::{ErrorKind, Position};
 use crate::identifier::Identifier;
-use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use crate::{BuildMetadata, Comparator, Op, PartialVersion, Prerelease, Version, VersionReq};
 use core::str::FromStr;
 
 /// Error parsing a SemVer version or version requirement.
@@ -20,107 +20,408 @@ use core::str::FromStr;
 /// ```
 pub struct Error {
     pub(crate) kind: ErrorKind,
+    input: Option<String>,
 }
 
+// Longest prefix of the input kept by Error::with_input, in bytes.
+const MAX_INPUT: usize = 64;
+
 impl FromStr for Version {
     type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        if text.is_empty() {
-            return Err(Error::new(ErrorKind::Empty));
-        }
+        let (major, minor, patch, pre, build) = version_parts(text)?;
+        // SAFETY: pre and build were validated by version_parts.
+        Ok(Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease {
+                identifier: unsafe { Identifier::new_unchecked(pre) },
+            },
+            build: BuildMetadata {
+                identifier: unsafe { Identifier::new_unchecked(build) },
+            },
+        })
+    }
+}
 
-        let mut pos = Position::Major;
-        let (major, text) = numeric_identifier(text, pos)?;
-        let text = dot(text, pos)?;
+// Major, minor, patch, pre-release and build metadata of a version, with the
+// last two as validated substrings of the input, empty if absent.
+pub(crate) fn version_parts(text: &str) -> Result<(u64, u64, u64, &str, &str), Error> {
+    if text.is_empty() {
+        return Err(Error::new(ErrorKind::Empty));
+    }
 
-        pos = Position::Minor;
-        let (minor, text) = numeric_identifier(text, pos)?;
-        let text = dot(text, pos)?;
+    let mut pos = Position::Major;
+    let (major, text) = numeric_identifier(text, pos)?;
+    let text = dot(text, pos)?;
 
-        pos = Position::Patch;
-        let (patch, text) = numeric_identifier(text, pos)?;
+    pos = Position::Minor;
+    let (minor, text) = numeric_identifier(text, pos)?;
+    let text = dot(text, pos)?;
 
-        if text.is_empty() {
-            return Ok(Version::new(major, minor, patch));
-        }
+    pos = Position::Patch;
+    let (patch, text) = numeric_identifier(text, pos)?;
 
-        let (pre, text) = if let Some(text) = text.strip_prefix('-') {
-            pos = Position::Pre;
-            let (pre, text) = prerelease_identifier(text)?;
-            if pre.is_empty() {
-                return Err(Error::new(ErrorKind::EmptySegment(pos)));
-            }
-            (pre, text)
-        } else {
-            (Prerelease::EMPTY, text)
-        };
-
-        let (build, text) = if let Some(text) = text.strip_prefix('+') {
-            pos = Position::Build;
-            let (build, text) = build_identifier(text)?;
-            if build.is_empty() {
-                return Err(Error::new(ErrorKind::EmptySegment(pos)));
-            }
-            (build, text)
-        } else {
-            (BuildMetadata::EMPTY, text)
-        };
+    if text.is_empty() {
+        return Ok((major, minor, patch, "", ""));
+    }
 
-        if let Some(unexpected) = text.chars().next() {
-            return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
+    let (pre, text) = if let Some(text) = text.strip_prefix('-') {
+        pos = Position::Pre;
+        let (pre, text) = identifier(text, pos)?;
+        if pre.is_empty() {
+            return Err(Error::new(ErrorKind::EmptySegment(pos)));
         }
+        (pre, text)
+    } else {
+        ("", text)
+    };
 
-        Ok(Version {
-            major,
-            minor,
-            patch,
-            pre,
-            build,
-        })
+    let (build, text) = if let Some(text) = text.strip_prefix('+') {
+        pos = Position::Build;
+        let (build, text) = identifier(text, pos)?;
+        if build.is_empty() {
+            return Err(Error::new(ErrorKind::EmptySegment(pos)));
+        }
+        (build, text)
+    } else {
+        ("", text)
+    };
+
+    if let Some(unexpected) = text.chars().next() {
+        return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
     }
+
+    Ok((major, minor, patch, pre, build))
 }
 
 impl FromStr for VersionReq {
     type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let text = text.trim_start_matches(' ');
-        if let Some((ch, text)) = wildcard(text) {
-            let rest = text.trim_start_matches(' ');
-            if rest.is_empty() {
-                #[cfg(not(no_const_vec_new))]
-                return Ok(VersionReq::STAR);
-                #[cfg(no_const_vec_new)] // rustc <1.39
-                return Ok(VersionReq {
-                    comparators: Vec::new(),
-                });
-            } else if rest.starts_with(',') {
-                return Err(Error::new(ErrorKind::WildcardNotTheOnlyComparator(ch)));
-            } else {
-                return Err(Error::new(ErrorKind::UnexpectedAfterWildcard));
-            }
+        parse_version_req(text, &ParseConfig::new())
+    }
+}
+
+/// Options for parsing a version requirement with [`VersionReq::parse_with`].
+///
+/// # Example
+///
+/// ```
+/// use semver::{Op, ParseConfig, Version, VersionReq};
+///
+/// // A bare version is an exact requirement, as in pip's `==1.2.3`.
+/// let exact = ParseConfig::new().default_op(Op::Exact);
+/// let req = VersionReq::parse_with("1.2.3", &exact).unwrap();
+///
+/// assert_eq!(req.comparators[0].op, Op::Exact);
+/// assert!(req.matches(&Version::parse("1.2.3").unwrap()));
+/// assert!(!req.matches(&Version::parse("1.2.4").unwrap()));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParseConfig {
+    default_op: Op,
+    // Bitset of Separator discriminants.
+    separators: u8,
+    whitespace: bool,
+    v_prefix: bool,
+    keep_input: bool,
+    // The extensions to the comparator syntax that other ecosystems brought
+    // along are rejected: `x` and `X` wildcards and build metadata.
+    pub(crate) strict: bool,
+}
+
+/// What may stand between two comparators of a requirement, as configured by
+/// [`ParseConfig::separators`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Separator {
+    /// `>=1.2.3, <2.0.0`, as written by Cargo.
+    Comma,
+    /// `>=1.2.3 <2.0.0`, as written by npm.
+    Whitespace,
+    /// `>=1.2.3 && <2.0.0`, as written by some CI and templating tools.
+    DoubleAmpersand,
+}
+
+impl Separator {
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+const COMMA_ONLY: u8 = 1 << Separator::Comma as u8;
+
+impl ParseConfig {
+    /// Options that parse exactly like [`VersionReq::parse`].
+    pub const fn new() -> Self {
+        ParseConfig {
+            default_op: Op::DEFAULT,
+            separators: COMMA_ONLY,
+            whitespace: true,
+            v_prefix: false,
+            keep_input: false,
+            strict: false,
         }
+    }
+
+    /// Options that read a bare version like `1.2.3` as `~1.2.3`, allowing
+    /// only patch updates where Cargo would allow any compatible update.
+    ///
+    /// This is for tooling that applies a stricter update policy than Cargo
+    /// to the manifests it reads. Requirements with an explicit operator are
+    /// unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{ParseConfig, Version, VersionReq};
+    ///
+    /// let req = VersionReq::parse_with("1.2.3", &ParseConfig::pessimistic()).unwrap();
+    /// assert_eq!(req.to_string(), "~1.2.3");
+    /// assert!(req.matches(&Version::parse("1.2.9").unwrap()));
+    /// assert!(!req.matches(&Version::parse("1.3.0").unwrap()));
+    /// ```
+    pub const fn pessimistic() -> Self {
+        ParseConfig {
+            default_op: Op::Tilde,
+            separators: COMMA_ONLY,
+            whitespace: true,
+            v_prefix: false,
+            keep_input: false,
+            strict: false,
+        }
+    }
+
+    /// Operator of a comparator written without one, like `1.2.3`. The
+    /// default is [`Op::Caret`], which is how Cargo reads a bare version.
+    ///
+    /// Partial versions with a wildcard, like `1.2.*`, are [`Op::Wildcard`]
+    /// regardless of this setting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `op` is `Op::Wildcard`, which is not meaningful for a version
+    /// without a wildcard.
+    #[must_use]
+    pub fn default_op(mut self, op: Op) -> Self {
+        assert!(
+            op != Op::Wildcard,
+            "Op::Wildcard cannot be the default operator",
+        );
+        self.default_op = op;
+        self
+    }
+
+    /// Separators accepted between comparators. The default is only
+    /// [`Separator::Comma`].
+    ///
+    /// Spaces are allowed on either side of a comma or `&&`. With
+    /// [`Separator::Whitespace`], one or more spaces alone also separate two
+    /// comparators. An empty list accepts only requirements consisting of a
+    /// single comparator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{ParseConfig, Separator, VersionReq};
+    ///
+    /// let config = ParseConfig::new().separators(&[
+    ///     Separator::Comma,
+    ///     Separator::Whitespace,
+    ///     Separator::DoubleAmpersand,
+    /// ]);
+    /// for text in &[">=1.2.3, <2", ">=1.2.3 <2", ">=1.2.3 && <2"] {
+    ///     let req = VersionReq::parse_with(text, &config).unwrap();
+    ///     assert_eq!(req.to_string(), ">=1.2.3, <2");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn separators(mut self, separators: &[Separator]) -> Self {
+        self.separators = 0;
+        for separator in separators {
+            self.separators |= separator.bit();
+        }
+        self
+    }
+
+    /// Whether spaces are accepted between an operator and its version, as in
+    /// `>= 1.2.3`. The default is true, as for [`VersionReq::parse`].
+    #[must_use]
+    pub fn whitespace(mut self, whitespace: bool) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
 
-        let depth = 0;
-        let mut comparators = Vec::new();
-        let len = version_req(text, &mut comparators, depth)?;
-        unsafe { comparators.set_len(len) }
-        Ok(VersionReq { comparators })
+    /// Whether a `v` or `V` in front of a comparator's version, as in
+    /// `>=v1.2.0` copied from a Git tag, is accepted and ignored. The default
+    /// is false.
+    ///
+    /// This only concerns requirements; parsing a [`Version`] is unaffected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{ParseConfig, VersionReq};
+    ///
+    /// let config = ParseConfig::new().v_prefix(true);
+    /// let req = VersionReq::parse_with(">=v1.2.0, <V2", &config).unwrap();
+    /// assert_eq!(req.to_string(), ">=1.2.0, <2");
+    /// assert!(VersionReq::parse(">=v1.2.0").is_err());
+    /// ```
+    #[must_use]
+    pub fn v_prefix(mut self, v_prefix: bool) -> Self {
+        self.v_prefix = v_prefix;
+        self
     }
+
+    /// Whether a parse error keeps a copy of the input, retrievable with
+    /// [`Error::input`]. The default is false, which leaves errors free of
+    /// allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{ParseConfig, VersionReq};
+    ///
+    /// let config = ParseConfig::new().keep_input(true);
+    /// let err = VersionReq::parse_with(">=1.2, <1.x.3", &config).unwrap_err();
+    /// assert_eq!(err.input(), Some(">=1.2, <1.x.3"));
+    ///
+    /// let err = VersionReq::parse(">=1.2, <1.x.3").unwrap_err();
+    /// assert_eq!(err.input(), None);
+    /// ```
+    #[must_use]
+    pub fn keep_input(mut self, keep_input: bool) -> Self {
+        self.keep_input = keep_input;
+        self
+    }
+
+    fn accepts(&self, separator: Separator) -> bool {
+        self.separators & separator.bit() != 0
+    }
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        ParseConfig::new()
+    }
+}
+
+pub(crate) fn parse_version_req(text: &str, config: &ParseConfig) -> Result<VersionReq, Error> {
+    parse_version_req_keeping(text, config, None)
+}
+
+pub(crate) fn parse_version_req_with_build(
+    text: &str,
+    config: &ParseConfig,
+) -> Result<(VersionReq, Vec<BuildMetadata>), Error> {
+    let mut build = Vec::new();
+    let req = parse_version_req_keeping(text, config, Some(&mut build))?;
+    Ok((req, build))
+}
+
+// Parse, and if `build` is Some, fill it with the build metadata of each
+// comparator.
+fn parse_version_req_keeping(
+    text: &str,
+    config: &ParseConfig,
+    build: Option<&mut Vec<BuildMetadata>>,
+) -> Result<VersionReq, Error> {
+    if config.keep_input {
+        return parse_version_req_impl(text, config, build).map_err(|error| error.with_input(text));
+    }
+    parse_version_req_impl(text, config, build)
+}
+
+fn parse_version_req_impl(
+    text: &str,
+    config: &ParseConfig,
+    build: Option<&mut Vec<BuildMetadata>>,
+) -> Result<VersionReq, Error> {
+    let strict = config.strict;
+    let text = text.trim_start_matches(' ');
+    if let Some((ch, text)) = wildcard(text, strict) {
+        let rest = text.trim_start_matches(' ');
+        if rest.is_empty() {
+            #[cfg(not(no_const_vec_new))]
+            return Ok(VersionReq::STAR);
+            #[cfg(no_const_vec_new)] // rustc <1.39
+            return Ok(VersionReq {
+                comparators: Vec::new(),
+            });
+        } else if rest.starts_with(',') {
+            return Err(Error::new(ErrorKind::WildcardNotTheOnlyComparator(ch)));
+        } else {
+            return Err(Error::new(ErrorKind::UnexpectedAfterWildcard));
+        }
+    }
+
+    let depth = 0;
+    let mut comparators = Vec::new();
+    let len = version_req(text, &mut comparators, build, depth, config)?;
+    unsafe { comparators.set_len(len) }
+    Ok(VersionReq { comparators })
 }
 
 impl FromStr for Comparator {
     type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let text = text.trim_start_matches(' ');
-        let (comparator, pos, rest) = comparator(text)?;
-        if !rest.is_empty() {
-            let unexpected = rest.chars().next().unwrap();
+        parse_comparator_with_build(text).map(|(comparator, _build)| comparator)
+    }
+}
+
+pub(crate) fn parse_comparator_with_build(
+    text: &str,
+) -> Result<(Comparator, BuildMetadata), Error> {
+    let text = text.trim_start_matches(' ');
+    let (comparator, build, pos, rest) =
+        comparator_with_build(text, &ParseConfig::new()).map_err(|(error, _at)| error)?;
+    if !rest.is_empty() {
+        let unexpected = rest.chars().next().unwrap();
+        return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
+    }
+    Ok((comparator, build))
+}
+
+impl FromStr for PartialVersion {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.is_empty() {
+            return Err(Error::new(ErrorKind::Empty));
+        }
+
+        let mut pos = Position::Major;
+        let (major, mut text) = numeric_identifier(text, pos)?;
+        let mut minor = None;
+        let mut patch = None;
+
+        if let Some(rest) = text.strip_prefix('.') {
+            pos = Position::Minor;
+            let (value, rest) = numeric_identifier(rest, pos)?;
+            minor = Some(value);
+            text = rest;
+
+            if let Some(rest) = text.strip_prefix('.') {
+                pos = Position::Patch;
+                let (value, rest) = numeric_identifier(rest, pos)?;
+                patch = Some(value);
+                text = rest;
+            }
+        }
+
+        if let Some(unexpected) = text.chars().next() {
             return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
         }
-        Ok(comparator)
+
+        Ok(PartialVersion {
+            major,
+            minor,
+            patch,
+        })
     }
 }
 
@@ -149,8 +450,42 @@ impl FromStr for BuildMetadata {
 }
 
 impl Error {
-    fn new(kind: ErrorKind) -> Self {
-        Error { kind }
+    pub(crate) fn new(kind: ErrorKind) -> Self {
+        Error { kind, input: None }
+    }
+
+    /// The input that failed to parse, if it was attached with
+    /// [`with_input`][Self::with_input] or by parsing with
+    /// [`ParseConfig::keep_input`].
+    ///
+    /// Inputs longer than 64 bytes are cut short at a character boundary.
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_ref().map(String::as_str)
+    }
+
+    /// Attach a copy of the input that failed to parse, so that the error
+    /// still identifies it when handled far from where it was parsed.
+    ///
+    /// Parsing does not do this by default, so that errors cost no allocation
+    /// where they are expected and discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let text = "1.2.x";
+    /// let err = Version::parse(text).unwrap_err().with_input(text);
+    /// assert_eq!(err.input(), Some("1.2.x"));
+    /// ```
+    #[must_use]
+    pub fn with_input(mut self, input: &str) -> Self {
+        let mut len = input.len().min(MAX_INPUT);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.input = Some(String::from(&input[..len]));
+        self
     }
 }
 
@@ -158,7 +493,7 @@ impl Op {
     const DEFAULT: Self = Op::Caret;
 }
 
-fn numeric_identifier(input: &str, pos: Position) -> Result<(u64, &str), Error> {
+pub(crate) fn numeric_identifier(input: &str, pos: Position) -> Result<(u64, &str), Error> {
     let mut len = 0;
     let mut value = 0u64;
 
@@ -188,9 +523,11 @@ fn numeric_identifier(input: &str, pos: Position) -> Result<(u64, &str), Error>
     }
 }
 
-fn wildcard(input: &str) -> Option<(char, &str)> {
+pub(crate) fn wildcard(input: &str, strict: bool) -> Option<(char, &str)> {
     if let Some(rest) = input.strip_prefix('*') {
         Some(('*', rest))
+    } else if strict {
+        None
     } else if let Some(rest) = input.strip_prefix('x') {
         Some(('x', rest))
     } else if let Some(rest) = input.strip_prefix('X') {
@@ -210,13 +547,13 @@ fn dot(input: &str, pos: Position) -> Result<&str, Error> {
     }
 }
 
-fn prerelease_identifier(input: &str) -> Result<(Prerelease, &str), Error> {
+pub(crate) fn prerelease_identifier(input: &str) -> Result<(Prerelease, &str), Error> {
     let (string, rest) = identifier(input, Position::Pre)?;
     let identifier = unsafe { Identifier::new_unchecked(string) };
     Ok((Prerelease { identifier }, rest))
 }
 
-fn build_identifier(input: &str) -> Result<(BuildMetadata, &str), Error> {
+pub(crate) fn build_identifier(input: &str) -> Result<(BuildMetadata, &str), Error> {
     let (string, rest) = identifier(input, Position::Build)?;
     let identifier = unsafe { Identifier::new_unchecked(string) };
     Ok((BuildMetadata { identifier }, rest))
@@ -264,7 +601,7 @@ fn identifier(input: &str, pos: Position) -> Result<(&str, &str), Error> {
     }
 }
 
-fn op(input: &str) -> (Op, &str) {
+fn op(input: &str, default_op: Op) -> (Op, &str) {
     let bytes = input.as_bytes();
     if bytes.first() == Some(&b'=') {
         (Op::Exact, &input[1..])
@@ -285,29 +622,51 @@ fn op(input: &str) -> (Op, &str) {
     } else if bytes.first() == Some(&b'^') {
         (Op::Caret, &input[1..])
     } else {
-        (Op::DEFAULT, input)
+        (default_op, input)
     }
 }
 
-fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
-    let (mut op, text) = op(input);
+// On failure, the error is returned together with the remainder of the input
+// starting at the token where parsing failed.
+pub(crate) fn comparator<'a>(
+    input: &'a str,
+    config: &ParseConfig,
+) -> Result<(Comparator, Position, &'a str), (Error, &'a str)> {
+    let (comparator, _build, pos, text) = comparator_with_build(input, config)?;
+    Ok((comparator, pos, text))
+}
+
+fn comparator_with_build<'a>(
+    input: &'a str,
+    config: &ParseConfig,
+) -> Result<(Comparator, BuildMetadata, Position, &'a str), (Error, &'a str)> {
+    let strict = config.strict;
+    let (mut op, text) = op(input, config.default_op);
     let default_op = input.len() == text.len();
-    let text = text.trim_start_matches(' ');
+    let text = if config.whitespace {
+        text.trim_start_matches(' ')
+    } else {
+        text
+    };
+    let text = match text.as_bytes().first() {
+        Some(b'v') | Some(b'V') if config.v_prefix => &text[1..],
+        _ => text,
+    };
 
     let mut pos = Position::Major;
-    let (major, text) = numeric_identifier(text, pos)?;
+    let (major, text) = numeric_identifier(text, pos).map_err(|error| (error, text))?;
     let mut has_wildcard = false;
 
     let (minor, text) = if let Some(text) = text.strip_prefix('.') {
         pos = Position::Minor;
-        if let Some((_, text)) = wildcard(text) {
+        if let Some((_, text)) = wildcard(text, strict) {
             has_wildcard = true;
             if default_op {
                 op = Op::Wildcard;
             }
             (None, text)
         } else {
-            let (minor, text) = numeric_identifier(text, pos)?;
+            let (minor, text) = numeric_identifier(text, pos).map_err(|error| (error, text))?;
             (Some(minor), text)
         }
     } else {
@@ -316,15 +675,15 @@ fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
 
     let (patch, text) = if let Some(text) = text.strip_prefix('.') {
         pos = Position::Patch;
-        if let Some((_, text)) = wildcard(text) {
+        if let Some((_, text)) = wildcard(text, strict) {
             if default_op {
                 op = Op::Wildcard;
             }
             (None, text)
         } else if has_wildcard {
-            return Err(Error::new(ErrorKind::UnexpectedAfterWildcard));
+            return Err((Error::new(ErrorKind::UnexpectedAfterWildcard), text));
         } else {
-            let (patch, text) = numeric_identifier(text, pos)?;
+            let (patch, text) = numeric_identifier(text, pos).map_err(|error| (error, text))?;
             (Some(patch), text)
         }
     } else {
@@ -334,25 +693,28 @@ fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
     let (pre, text) = if patch.is_some() && text.starts_with('-') {
         pos = Position::Pre;
         let text = &text[1..];
-        let (pre, text) = prerelease_identifier(text)?;
+        let (pre, rest) = prerelease_identifier(text).map_err(|error| (error, text))?;
         if pre.is_empty() {
-            return Err(Error::new(ErrorKind::EmptySegment(pos)));
+            return Err((Error::new(ErrorKind::EmptySegment(pos)), text));
         }
-        (pre, text)
+        (pre, rest)
     } else {
         (Prerelease::EMPTY, text)
     };
 
-    let text = if patch.is_some() && text.starts_with('+') {
+    let (build, text) = if patch.is_some() && text.starts_with('+') {
+        if strict {
+            return Err((Error::new(ErrorKind::UnexpectedCharAfter(pos, '+')), text));
+        }
         pos = Position::Build;
         let text = &text[1..];
-        let (build, text) = build_identifier(text)?;
+        let (build, rest) = build_identifier(text).map_err(|error| (error, text))?;
         if build.is_empty() {
-            return Err(Error::new(ErrorKind::EmptySegment(pos)));
+            return Err((Error::new(ErrorKind::EmptySegment(pos)), text));
         }
-        text
+        (build, rest)
     } else {
-        text
+        (BuildMetadata::EMPTY, text)
     };
 
     let text = text.trim_start_matches(' ');
@@ -365,14 +727,21 @@ fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
         pre,
     };
 
-    Ok((comparator, pos, text))
+    Ok((comparator, build, pos, text))
 }
 
-fn version_req(input: &str, out: &mut Vec<Comparator>, depth: usize) -> Result<usize, Error> {
-    let (comparator, pos, text) = match comparator(input) {
+fn version_req(
+    input: &str,
+    out: &mut Vec<Comparator>,
+    mut build_out: Option<&mut Vec<BuildMetadata>>,
+    depth: usize,
+    config: &ParseConfig,
+) -> Result<usize, Error> {
+    let strict = config.strict;
+    let (comparator, build, pos, text) = match comparator_with_build(input, config) {
         Ok(success) => success,
-        Err(mut error) => {
-            if let Some((ch, mut rest)) = wildcard(input) {
+        Err((mut error, _at)) => {
+            if let Some((ch, mut rest)) = wildcard(input, strict) {
                 rest = rest.trim_start_matches(' ');
                 if rest.is_empty() || rest.starts_with(',') {
                     error.kind = ErrorKind::WildcardNotTheOnlyComparator(ch);
@@ -385,11 +754,27 @@ fn version_req(input: &str, out: &mut Vec<Comparator>, depth: usize) -> Result<u
     if text.is_empty() {
         out.reserve_exact(depth + 1);
         unsafe { out.as_mut_ptr().add(depth).write(comparator) }
+        if let Some(build_out) = build_out {
+            build_out.resize(depth + 1, BuildMetadata::EMPTY);
+            build_out[depth] = build;
+        }
         return Ok(depth + 1);
     }
 
-    let text = if let Some(text) = text.strip_prefix(',') {
+    let text = if let Some(text) = text
+        .strip_prefix(',')
+        .filter(|_| config.accepts(Separator::Comma))
+    {
+        text.trim_start_matches(' ')
+    } else if let Some(text) = text
+        .strip_prefix("&&")
+        .filter(|_| config.accepts(Separator::DoubleAmpersand))
+    {
         text.trim_start_matches(' ')
+    } else if config.accepts(Separator::Whitespace)
+        && input[..input.len() - text.len()].ends_with(' ')
+    {
+        text
     } else {
         let unexpected = text.chars().next().unwrap();
         return Err(Error::new(ErrorKind::ExpectedCommaFound(pos, unexpected)));
@@ -403,7 +788,16 @@ fn version_req(input: &str, out: &mut Vec<Comparator>, depth: usize) -> Result<u
     // Recurse to collect parsed Comparator objects on the stack. We perform a
     // single allocation to allocate exactly the right sized Vec only once the
     // total number of comparators is known.
-    let len = version_req(text, out, depth + 1)?;
+    let len = version_req(
+        text,
+        out,
+        build_out.as_mut().map(|build_out| &mut **build_out),
+        depth + 1,
+        config,
+    )?;
     unsafe { out.as_mut_ptr().add(depth).write(comparator) }
+    if let Some(build_out) = build_out {
+        build_out[depth] = build;
+    }
     Ok(len)
 }
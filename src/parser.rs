@@ -0,0 +1,389 @@
+//! Parsers for the pieces of the SemVer grammar, for embedding in parsers of
+//! other formats.
+//!
+//! Each parser recognizes its piece at the start of the input and returns the
+//! value together with its [`Span`] and the rest of the input, so it can be
+//! called in the middle of a larger parser. Failures report the span of the
+//! input that caused them.
+//!
+//! Spans are byte offsets into the string passed to the parser. A caller that
+//! passes a suffix of some larger document adds the offset of that suffix.
+//!
+//! # Example
+//!
+//! ```
+//! use semver::parser::{self, Span};
+//!
+//! // A made-up manifest line: `name@requirement`.
+//! let line = "serde@>=1.0.100, <2";
+//! let at = line.find('@').unwrap() + 1;
+//!
+//! let parsed = parser::parse_comparator(&line[at..]).unwrap();
+//! assert_eq!(parsed.value.to_string(), ">=1.0.100");
+//! assert_eq!(parsed.span, Span { start: 0, end: 9 });
+//! assert_eq!(parsed.rest, ", <2");
+//!
+//! let err = parser::parse_comparator(">=1.0.100-beta..1").unwrap_err();
+//! assert_eq!(err.to_string(), "empty identifier segment in pre-release identifier");
+//! assert_eq!(err.span(), Span { start: 15, end: 15 });
+//! ```
+
+use crate::backport::*;
+use crate::error::{ErrorKind, Position};
+use crate::parse::{self, Error, ParseConfig};
+use crate::{BuildMetadata, Comparator, Prerelease, VersionReq};
+use core::fmt::{self, Debug, Display};
+
+/// A region of the input, as byte offsets. `end` is exclusive.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A value parsed from the start of the input.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Parsed<'a, T> {
+    pub value: T,
+    /// Where in the input the value was written.
+    pub span: Span,
+    /// The input following the value.
+    pub rest: &'a str,
+}
+
+/// A parse error with the region of the input that caused it.
+///
+/// The span is empty for an error about something missing, such as an
+/// unexpected end of input, and points where the missing piece was expected.
+pub struct SpannedError {
+    error: Error,
+    span: Span,
+}
+
+impl SpannedError {
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+}
+
+impl Display for SpannedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.error, formatter)
+    }
+}
+
+impl Debug for SpannedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("SpannedError")
+            .field("error", &self.error)
+            .field("span", &self.span)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for SpannedError {}
+
+/// Parse a number without leading zeros, like the major, minor and patch
+/// numbers of a version.
+pub fn parse_numeric_identifier(input: &str) -> Result<Parsed<'_, u64>, SpannedError> {
+    match parse::numeric_identifier(input, Position::Numeric) {
+        Ok((value, rest)) => Ok(parsed(input, value, rest)),
+        Err(error) => Err(spanned(input, error, input)),
+    }
+}
+
+/// Parse the pre-release of a version, not including the `-` that introduces
+/// it.
+///
+/// Parsing stops at the first character that cannot be part of a pre-release,
+/// such as `+`. If there is no such character at the start of the input, the
+/// result is [`Prerelease::EMPTY`].
+pub fn parse_prerelease(input: &str) -> Result<Parsed<'_, Prerelease>, SpannedError> {
+    match parse::prerelease_identifier(input) {
+        Ok((value, rest)) => Ok(parsed(input, value, rest)),
+        Err(error) => Err(spanned(input, error, input)),
+    }
+}
+
+/// Parse the build metadata of a version, not including the `+` that
+/// introduces it.
+///
+/// Like [`parse_prerelease`], this stops at the first character that cannot
+/// be part of build metadata.
+pub fn parse_build(input: &str) -> Result<Parsed<'_, BuildMetadata>, SpannedError> {
+    match parse::build_identifier(input) {
+        Ok((value, rest)) => Ok(parsed(input, value, rest)),
+        Err(error) => Err(spanned(input, error, input)),
+    }
+}
+
+/// Parse one comparator of a version requirement, such as `>=1.2.3`, the way
+/// [`VersionReq::parse`][crate::VersionReq::parse] does.
+///
+/// Parsing stops after the comparator and any spaces following it, so `rest`
+/// starts with whatever separates it from the next comparator.
+pub fn parse_comparator(input: &str) -> Result<Parsed<'_, Comparator>, SpannedError> {
+    match parse::comparator(input, &ParseConfig::new()) {
+        Ok((value, _pos, rest)) => {
+            let end = input.len() - rest.len();
+            let end = input[..end].trim_end_matches(' ').len();
+            let mut parsed = parsed(input, value, rest);
+            parsed.span.end = end;
+            Ok(parsed)
+        }
+        Err((error, at)) => Err(spanned(input, error, at)),
+    }
+}
+
+/// One comma-separated piece of a requirement parsed by
+/// [`parse_req_tolerant`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ReqNode {
+    /// A comparator that parsed successfully.
+    Comparator { value: Comparator, span: Span },
+    /// Input that could not be parsed as a comparator, up to the next comma
+    /// or the end of the input, not including spaces on either side. Empty
+    /// where a comparator is missing altogether, as after a trailing comma.
+    Error { span: Span },
+}
+
+impl ReqNode {
+    pub fn span(&self) -> Span {
+        match self {
+            ReqNode::Comparator { span, .. } | ReqNode::Error { span } => *span,
+        }
+    }
+}
+
+/// A requirement parsed by [`parse_req_tolerant`], with whatever was wrong
+/// with it.
+#[derive(Debug)]
+pub struct TolerantReq {
+    /// The comparators and unparseable pieces of the requirement, in the
+    /// order written. Empty for `*`.
+    pub nodes: Vec<ReqNode>,
+    /// Everything that made the input invalid, in the order it occurs.
+    pub diagnostics: Vec<SpannedError>,
+}
+
+impl TolerantReq {
+    /// Whether the input was a valid requirement.
+    pub fn is_valid(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// The requirement made of the comparators that did parse, leaving out
+    /// the error nodes.
+    ///
+    /// If the input was valid, this is the same as what
+    /// [`VersionReq::parse`] gives.
+    pub fn to_req(&self) -> VersionReq {
+        let comparators = self
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                ReqNode::Comparator { value, .. } => Some(value.clone()),
+                ReqNode::Error { .. } => None,
+            })
+            .collect();
+        VersionReq { comparators }
+    }
+}
+
+/// Parse a version requirement without stopping at the first error.
+///
+/// This never fails. Each comma-separated piece of the input becomes a node,
+/// either a comparator or an error node covering the piece that did not
+/// parse, and every problem is reported in
+/// [`diagnostics`][TolerantReq::diagnostics] with its span. Two comparators
+/// separated by spaces instead of a comma are both kept, with a diagnostic
+/// for the missing comma. This is for editors, which need to keep
+/// highlighting and completing a requirement while it is being typed.
+///
+/// A valid input gives no diagnostics and exactly the comparators that
+/// [`VersionReq::parse`] gives.
+///
+/// # Example
+///
+/// ```
+/// use semver::parser::{self, ReqNode, Span};
+///
+/// let parsed = parser::parse_req_tolerant(">=1.2, <2.0.0-, ^1.4");
+/// assert_eq!(parsed.nodes.len(), 3);
+/// assert_eq!(parsed.nodes[1], ReqNode::Error { span: Span { start: 7, end: 14 } });
+/// assert_eq!(parsed.to_req().to_string(), ">=1.2, ^1.4");
+///
+/// assert_eq!(parsed.diagnostics.len(), 1);
+/// let diagnostic = &parsed.diagnostics[0];
+/// assert_eq!(diagnostic.to_string(), "empty identifier segment in pre-release identifier");
+/// assert_eq!(diagnostic.span(), Span { start: 14, end: 14 });
+/// ```
+pub fn parse_req_tolerant(input: &str) -> TolerantReq {
+    let mut nodes = Vec::new();
+    let mut diagnostics = Vec::new();
+    let mut at = skip_spaces(input, 0);
+
+    loop {
+        let rest = &input[at..];
+
+        if let Some((ch, after)) = parse::wildcard(rest, false) {
+            let after = after.trim_start_matches(' ');
+            if after.is_empty() && at == skip_spaces(input, 0) {
+                break;
+            }
+            let kind = if after.is_empty() || after.starts_with(',') {
+                ErrorKind::WildcardNotTheOnlyComparator(ch)
+            } else {
+                ErrorKind::UnexpectedAfterWildcard
+            };
+            let span = Span {
+                start: at,
+                end: at + 1,
+            };
+            diagnostics.push(SpannedError {
+                error: Error::new(kind),
+                span,
+            });
+            match skip_piece(input, at, &mut nodes) {
+                Some(next) => at = next,
+                None => break,
+            }
+            continue;
+        }
+
+        match parse::comparator(rest, &ParseConfig::new()) {
+            Ok((value, pos, after)) => {
+                let end = input.len() - after.len();
+                let span = Span {
+                    start: at,
+                    end: input[..end].trim_end_matches(' ').len(),
+                };
+                nodes.push(ReqNode::Comparator { value, span });
+                at = end;
+                if let Some(after) = after.strip_prefix(',') {
+                    at = skip_spaces(input, input.len() - after.len());
+                } else if let Some(unexpected) = after.chars().next() {
+                    diagnostics.push(SpannedError {
+                        error: Error::new(ErrorKind::ExpectedCommaFound(pos, unexpected)),
+                        span: Span {
+                            start: at,
+                            end: at + unexpected.len_utf8(),
+                        },
+                    });
+                } else {
+                    break;
+                }
+            }
+            Err((error, err_at)) => {
+                let mut error = spanned(rest, error, err_at);
+                error.span.start += at;
+                error.span.end += at;
+                diagnostics.push(error);
+                match skip_piece(input, at, &mut nodes) {
+                    Some(next) => at = next,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    const MAX_COMPARATORS: usize = 32;
+    if let Some(node) = nodes.get(MAX_COMPARATORS) {
+        diagnostics.push(SpannedError {
+            error: Error::new(ErrorKind::ExcessiveComparators),
+            span: node.span(),
+        });
+    }
+    diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+
+    TolerantReq { nodes, diagnostics }
+}
+
+// Push an error node for the piece of the requirement starting at `at`, and
+// return where the next piece starts, if there is a next piece.
+fn skip_piece(input: &str, at: usize, nodes: &mut Vec<ReqNode>) -> Option<usize> {
+    let comma = input[at..].find(',').map(|i| at + i);
+    let end = comma.unwrap_or(input.len());
+    nodes.push(ReqNode::Error {
+        span: Span {
+            start: at,
+            end: input[..end].trim_end_matches(' ').len().max(at),
+        },
+    });
+    Some(skip_spaces(input, comma? + 1))
+}
+
+fn skip_spaces(input: &str, at: usize) -> usize {
+    input.len() - input[at..].trim_start_matches(' ').len()
+}
+
+fn parsed<'a, T>(input: &str, value: T, rest: &'a str) -> Parsed<'a, T> {
+    Parsed {
+        value,
+        span: Span {
+            start: 0,
+            end: input.len() - rest.len(),
+        },
+        rest,
+    }
+}
+
+// Locate the error within the token starting at `at`, which is a suffix of
+// `input`.
+fn spanned(input: &str, error: Error, at: &str) -> SpannedError {
+    let offset = input.len() - at.len();
+    let (start, end) = match error.kind {
+        ErrorKind::LeadingZero(Position::Pre) | ErrorKind::EmptySegment(_) => {
+            bad_segment(at, &error.kind)
+        }
+        ErrorKind::LeadingZero(_) | ErrorKind::Overflow(_) => {
+            (0, at.bytes().take_while(u8::is_ascii_digit).count())
+        }
+        ErrorKind::UnexpectedEnd(_) => (at.len(), at.len()),
+        _ => (0, at.chars().next().map_or(0, char::len_utf8)),
+    };
+    SpannedError {
+        error,
+        span: Span {
+            start: offset + start,
+            end: offset + end,
+        },
+    }
+}
+
+// The first empty dot-separated segment of a pre-release or build metadata,
+// or for a leading zero error, the first numeric segment with a leading zero.
+fn bad_segment(at: &str, kind: &ErrorKind) -> (usize, usize) {
+    let len = at
+        .bytes()
+        .take_while(|&b| b.is_ascii_alphanumeric() || b == b'-' || b == b'.')
+        .count();
+    let mut start = 0;
+    for segment in at[..len].split('.') {
+        let end = start + segment.len();
+        let bad = match kind {
+            ErrorKind::EmptySegment(_) => segment.is_empty(),
+            _ => {
+                segment.len() > 1
+                    && segment.starts_with('0')
+                    && segment.bytes().all(|b| b.is_ascii_digit())
+            }
+        };
+        if bad {
+            return (start, end);
+        }
+        start = end + 1;
+    }
+    (0, 0)
+}
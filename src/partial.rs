@@ -0,0 +1,121 @@
+use crate::{Error, Version};
+
+/// How many of the major, minor and patch numbers to keep, for
+/// [`Version::truncated`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Precision {
+    /// Keep the major number, as in `1.0.0`.
+    Major,
+    /// Keep the major and minor numbers, as in `1.2.0`.
+    Minor,
+    /// Keep the major, minor and patch numbers, as in `1.2.9`.
+    Patch,
+}
+
+/// The leading components of a version, like `1` or `1.2`, for selecting
+/// the versions that start with them with [`Version::matches_prefix`].
+///
+/// A `PartialVersion` parses and prints as one to three dot-separated
+/// numbers, with no operator, wildcard, pre-release or build metadata. This
+/// makes its string form suitable as a cache key or as the name of a bucket
+/// of artifacts grouped by major or minor line.
+///
+/// # Example
+///
+/// ```
+/// use semver::{PartialVersion, Version};
+///
+/// let line = PartialVersion::parse("1.2").unwrap();
+/// assert!(Version::parse("1.2.9-beta").unwrap().matches_prefix(&line));
+/// assert!(!Version::parse("1.20.0").unwrap().matches_prefix(&line));
+/// assert_eq!(line.to_string(), "1.2");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PartialVersion {
+    pub major: u64,
+    pub minor: Option<u64>,
+    /// Disregarded when `minor` is `None`.
+    pub patch: Option<u64>,
+}
+
+impl PartialVersion {
+    pub const fn new(major: u64, minor: Option<u64>, patch: Option<u64>) -> Self {
+        PartialVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Create `PartialVersion` by parsing from string representation.
+    ///
+    /// # Errors
+    ///
+    /// Possible reasons for the parse to fail include:
+    ///
+    /// - `1.2.3.4` &mdash; more than three components.
+    ///
+    /// - `1.2.*` &mdash; wildcards are not allowed.
+    ///
+    /// - `1.2.3-rc.1` &mdash; neither are pre-release and build metadata.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        text.parse()
+    }
+
+    /// The leading components of `version` up to `precision`.
+    pub fn of(version: &Version, precision: Precision) -> Self {
+        PartialVersion {
+            major: version.major,
+            minor: if precision >= Precision::Minor {
+                Some(version.minor)
+            } else {
+                None
+            },
+            patch: if precision == Precision::Patch {
+                Some(version.patch)
+            } else {
+                None
+            },
+        }
+    }
+
+    /// How many components are present.
+    pub fn precision(&self) -> Precision {
+        match (self.minor, self.patch) {
+            (None, _) => Precision::Major,
+            (Some(_), None) => Precision::Minor,
+            (Some(_), Some(_)) => Precision::Patch,
+        }
+    }
+}
+
+impl From<Version> for PartialVersion {
+    fn from(version: Version) -> Self {
+        PartialVersion::of(&version, Precision::Patch)
+    }
+}
+
+impl From<&Version> for PartialVersion {
+    fn from(version: &Version) -> Self {
+        PartialVersion::of(version, Precision::Patch)
+    }
+}
+
+pub(crate) fn truncated(version: &Version, precision: Precision) -> Version {
+    match precision {
+        Precision::Major => Version::new(version.major, 0, 0),
+        Precision::Minor => Version::new(version.major, version.minor, 0),
+        Precision::Patch => Version::new(version.major, version.minor, version.patch),
+    }
+}
+
+pub(crate) fn matches_prefix(version: &Version, prefix: &PartialVersion) -> bool {
+    if version.major != prefix.major {
+        return false;
+    }
+    let minor = match prefix.minor {
+        Some(minor) => minor,
+        None => return true,
+    };
+    version.minor == minor && prefix.patch.map_or(true, |patch| version.patch == patch)
+}
@@ -0,0 +1,75 @@
+use crate::backport::*;
+use crate::Version;
+use core::cmp::Ordering;
+
+/// Versions lying between two anchors, grouped by the kind of release they
+/// represent relative to the lower anchor.
+///
+/// Produced by [`partition_between`].
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct Partitioned {
+    /// Versions whose major number differs from the lower anchor.
+    pub major: Vec<Version>,
+    /// Versions on the same major as the lower anchor but a different minor.
+    pub minor: Vec<Version>,
+    /// Versions on the same major.minor line as the lower anchor.
+    pub patch: Vec<Version>,
+    /// Pre-release versions, regardless of which components changed.
+    pub prerelease: Vec<Version>,
+}
+
+/// Group the versions of `all` that are newer than `from` and no newer than
+/// `to` into major, minor, patch and pre-release buckets.
+///
+/// Versions are compared by [precedence][Version::cmp_precedence], so build
+/// metadata plays no role in deciding whether a version lies between the two
+/// anchors. Each bucket is sorted by precedence. If `to` is not newer than
+/// `from`, every bucket is empty.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let all = ["1.2.3", "1.2.4", "1.3.0", "2.0.0-rc.1", "2.0.0", "2.1.0"]
+///     .iter()
+///     .map(|v| v.parse::<Version>().unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let from = Version::parse("1.2.3").unwrap();
+/// let to = Version::parse("2.0.0").unwrap();
+/// let partitioned = semver::partition_between(&from, &to, &all);
+///
+/// assert_eq!(partitioned.patch, [Version::parse("1.2.4").unwrap()]);
+/// assert_eq!(partitioned.minor, [Version::parse("1.3.0").unwrap()]);
+/// assert_eq!(partitioned.major, [Version::parse("2.0.0").unwrap()]);
+/// assert_eq!(partitioned.prerelease, [Version::parse("2.0.0-rc.1").unwrap()]);
+/// ```
+pub fn partition_between(from: &Version, to: &Version, all: &[Version]) -> Partitioned {
+    let mut partitioned = Partitioned::default();
+
+    for version in all {
+        if version.cmp_precedence(from) != Ordering::Greater
+            || version.cmp_precedence(to) == Ordering::Greater
+        {
+            continue;
+        }
+
+        let bucket = if !version.pre.is_empty() {
+            &mut partitioned.prerelease
+        } else if version.major != from.major {
+            &mut partitioned.major
+        } else if version.minor != from.minor {
+            &mut partitioned.minor
+        } else {
+            &mut partitioned.patch
+        };
+        bucket.push(version.clone());
+    }
+
+    partitioned.major.sort_by(Version::cmp_precedence);
+    partitioned.minor.sort_by(Version::cmp_precedence);
+    partitioned.patch.sort_by(Version::cmp_precedence);
+    partitioned.prerelease.sort_by(Version::cmp_precedence);
+    partitioned
+}
@@ -0,0 +1,110 @@
+use crate::{CompiledReq, Version, VersionReq};
+use core::u32;
+use std::collections::HashMap;
+
+/// A handle to a requirement interned in a [`ReqPool`].
+///
+/// Handles are 4 bytes, so a dependency graph can label its edges with them
+/// instead of with whole requirements. Two handles from the same pool are
+/// equal exactly when the requirements they stand for are equal.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ReqId(u32);
+
+impl ReqId {
+    /// The position of the requirement in its pool, counting from zero in
+    /// the order the requirements were first interned.
+    pub fn index(self) -> u32 {
+        self.0
+    }
+}
+
+/// Interned requirements, each stored and compiled once and referred to by a
+/// [`ReqId`].
+///
+/// Interning a requirement equal to one already in the pool, including in
+/// the order of its comparators and in build metadata, returns the existing
+/// handle. Requirements stay in the pool for as long as it exists.
+///
+/// Handles are only meaningful to the pool that produced them: looking up a
+/// handle from another pool returns some other requirement or panics.
+///
+/// # Example
+///
+/// ```
+/// use semver::{ReqPool, Version, VersionReq};
+///
+/// let mut pool = ReqPool::new();
+/// let a = pool.intern(VersionReq::parse("^1.2").unwrap());
+/// let b = pool.intern(VersionReq::parse(">=2.0.0").unwrap());
+/// let c = pool.intern(VersionReq::parse("^1.2").unwrap());
+/// assert_eq!(a, c);
+/// assert_ne!(a, b);
+/// assert_eq!(pool.len(), 2);
+///
+/// assert_eq!(pool.get(b).to_string(), ">=2.0.0");
+/// assert!(pool.matches(a, &Version::new(1, 4, 0)));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Default, Debug)]
+pub struct ReqPool {
+    reqs: Vec<(VersionReq, CompiledReq)>,
+    ids: HashMap<VersionReq, ReqId>,
+}
+
+impl ReqPool {
+    pub fn new() -> Self {
+        ReqPool::default()
+    }
+
+    /// The handle of `req`, adding it to the pool if it is not there yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pool already holds `u32::MAX + 1` distinct
+    /// requirements.
+    pub fn intern(&mut self, req: VersionReq) -> ReqId {
+        if let Some(&id) = self.ids.get(&req) {
+            return id;
+        }
+        assert!(
+            self.reqs.len() <= u32::MAX as usize,
+            "too many requirements in ReqPool",
+        );
+        let id = ReqId(self.reqs.len() as u32);
+        let compiled = CompiledReq::new(&req);
+        self.ids.insert(req.clone(), id);
+        self.reqs.push((req, compiled));
+        id
+    }
+
+    /// The handle of `req` if it has been interned.
+    pub fn id(&self, req: &VersionReq) -> Option<ReqId> {
+        self.ids.get(req).cloned()
+    }
+
+    /// The requirement behind a handle.
+    pub fn get(&self, id: ReqId) -> &VersionReq {
+        &self.reqs[id.0 as usize].0
+    }
+
+    /// The requirement behind a handle, compiled for fast matching.
+    pub fn compiled(&self, id: ReqId) -> &CompiledReq {
+        &self.reqs[id.0 as usize].1
+    }
+
+    /// Evaluate whether `version` satisfies the requirement behind a handle,
+    /// using its compiled form.
+    pub fn matches(&self, id: ReqId, version: &Version) -> bool {
+        self.compiled(id).matches(version)
+    }
+
+    /// The number of distinct requirements in the pool.
+    pub fn len(&self) -> usize {
+        self.reqs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reqs.is_empty()
+    }
+}
@@ -0,0 +1,116 @@
+use crate::backport::*;
+use crate::{Prerelease, Version};
+
+/// An owned key that orders versions by SemVer precedence, returned by
+/// [`Version::precedence_key`].
+///
+/// Comparing two keys gives the same result as [`Version::cmp_precedence`],
+/// but without splitting and classifying the pre-release identifiers again
+/// on every comparison, so it pays for itself when sorting many versions with
+/// [`slice::sort_by_key`] or [`slice::sort_unstable_by_key`]. Like
+/// precedence, the key disregards build metadata: versions that differ only in
+/// build metadata have equal keys, and equal hashes.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let mut versions = ["1.0.0", "1.0.0-beta.11", "1.0.0-beta.2", "0.9.0"]
+///     .iter()
+///     .map(|v| v.parse::<Version>().unwrap())
+///     .collect::<Vec<_>>();
+///
+/// versions.sort_by_key(Version::precedence_key);
+/// assert_eq!(versions[1].to_string(), "1.0.0-beta.2");
+/// assert_eq!(versions[2].to_string(), "1.0.0-beta.11");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PrecedenceKey {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: PreKey,
+}
+
+// Variant order matters for the derived Ord: a pre-release has lower
+// precedence than the release, and numeric identifiers have lower precedence
+// than alphanumeric ones.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+enum PreKey {
+    Pre(Vec<PreToken>),
+    Release,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+enum PreToken {
+    // Numeric identifiers never have leading zeros, so ordering by length
+    // first and then by digits is numeric ordering, for any length.
+    Numeric { len: usize, digits: String },
+    Alphanumeric(String),
+}
+
+impl PrecedenceKey {
+    pub(crate) fn new(version: &Version) -> Self {
+        let pre = if version.pre.is_empty() {
+            PreKey::Release
+        } else {
+            PreKey::Pre(
+                version
+                    .pre
+                    .as_str()
+                    .split('.')
+                    .map(|identifier| {
+                        if identifier.bytes().all(|b| b.is_ascii_digit()) {
+                            PreToken::Numeric {
+                                len: identifier.len(),
+                                digits: String::from(identifier),
+                            }
+                        } else {
+                            PreToken::Alphanumeric(String::from(identifier))
+                        }
+                    })
+                    .collect(),
+            )
+        };
+        PrecedenceKey {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            pre,
+        }
+    }
+}
+
+// Layout of the key, chosen so that byte order is precedence order:
+//
+//   - '~' alone for the empty pre-release, which stands for a release and
+//     sorts after every pre-release;
+//   - otherwise for each identifier, '1' followed by the number of digits in
+//     its length, its length, and its digits if it is numeric, or '2'
+//     followed by the identifier and '!' if it is alphanumeric;
+//   - and '0' to end the identifiers, so that a shorter list of identifiers
+//     sorts before a longer one.
+//
+// '!' sorts before every character allowed in an identifier, so an
+// alphanumeric identifier sorts before any longer identifier it is a prefix
+// of.
+pub(crate) fn prerelease_sort_key(pre: &Prerelease) -> String {
+    if pre.is_empty() {
+        return String::from("~");
+    }
+    let mut key = String::new();
+    for identifier in pre.as_str().split('.') {
+        if identifier.bytes().all(|b| b.is_ascii_digit()) {
+            let len = format!("{}", identifier.len());
+            key.push('1');
+            key.push_str(&format!("{}{}{}", len.len(), len, identifier));
+        } else {
+            key.push('2');
+            key.push_str(identifier);
+            key.push('!');
+        }
+    }
+    key.push('0');
+    key
+}
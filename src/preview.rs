@@ -0,0 +1,48 @@
+use crate::backport::*;
+use crate::{MatchOptions, PreReleasePolicy, Version, VersionReq};
+use core::cmp::Ordering;
+
+/// The effect of a requirement on a set of versions, returned by
+/// [`VersionReq::preview`].
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct Preview {
+    /// How many of the versions match.
+    pub matched: usize,
+    /// The matching version of highest precedence.
+    pub newest: Option<Version>,
+    /// The matching version of lowest precedence.
+    pub oldest: Option<Version>,
+    /// Pre-release versions that do not match only because the requirement
+    /// does not name a pre-release of the same major.minor.patch, in the order
+    /// given.
+    pub excluded_prerelease: Vec<Version>,
+}
+
+pub(crate) fn preview(req: &VersionReq, corpus: &[Version]) -> Preview {
+    let allowed = MatchOptions::new().policy(PreReleasePolicy::Allowed);
+    let mut preview = Preview::default();
+    let mut newest: Option<&Version> = None;
+    let mut oldest: Option<&Version> = None;
+
+    for version in corpus {
+        if req.matches(version) {
+            preview.matched += 1;
+            if newest.map_or(true, |newest| {
+                version.cmp_precedence(newest) == Ordering::Greater
+            }) {
+                newest = Some(version);
+            }
+            if oldest.map_or(true, |oldest| {
+                version.cmp_precedence(oldest) == Ordering::Less
+            }) {
+                oldest = Some(version);
+            }
+        } else if !version.pre.is_empty() && req.matches_with(version, &allowed) {
+            preview.excluded_prerelease.push(version.clone());
+        }
+    }
+
+    preview.newest = newest.cloned();
+    preview.oldest = oldest.cloned();
+    preview
+}
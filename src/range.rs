@@ -0,0 +1,90 @@
+use crate::backport::*;
+use crate::{Comparator, Op, Version, VersionReq};
+use core::ops::{Bound, RangeBounds};
+
+/// A range of versions between two bounds, usable anywhere a Rust range of
+/// versions is, such as [`VersionReq::from_range`] or `BTreeSet::range`.
+///
+/// Code using the range through `RangeBounds` compares versions by their
+/// `Ord` impl, which orders build metadata after the release it is attached
+/// to. Convert the range to a requirement with [`VersionReq::from_range`] to
+/// compare by precedence instead.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Comparator, Version, VersionRange};
+/// use std::collections::BTreeSet;
+/// use std::ops::Bound;
+///
+/// let published: BTreeSet<Version> = ["1.1.0", "1.2.0", "1.2.7", "1.3.0"]
+///     .iter()
+///     .map(|v| v.parse().unwrap())
+///     .collect();
+///
+/// let range = Comparator::parse("~1.2").unwrap().range();
+/// assert_eq!(range.start, Bound::Included(Version::new(1, 2, 0)));
+/// let matching: Vec<String> = published.range(range).map(Version::to_string).collect();
+/// assert_eq!(matching, ["1.2.0", "1.2.7"]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VersionRange {
+    pub start: Bound<Version>,
+    pub end: Bound<Version>,
+}
+
+impl VersionRange {
+    pub fn new(start: Bound<Version>, end: Bound<Version>) -> Self {
+        VersionRange { start, end }
+    }
+}
+
+impl RangeBounds<Version> for VersionRange {
+    fn start_bound(&self) -> Bound<&Version> {
+        as_ref(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&Version> {
+        as_ref(&self.end)
+    }
+}
+
+// Bound::as_ref is not available before rustc 1.65.
+fn as_ref(bound: &Bound<Version>) -> Bound<&Version> {
+    match bound {
+        Bound::Included(version) => Bound::Included(version),
+        Bound::Excluded(version) => Bound::Excluded(version),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+pub(crate) fn to_req<R>(range: &R) -> VersionReq
+where
+    R: RangeBounds<Version> + ?Sized,
+{
+    let mut comparators = Vec::new();
+    let lower = match range.start_bound() {
+        Bound::Included(version) => Some((Op::GreaterEq, version)),
+        Bound::Excluded(version) => Some((Op::Greater, version)),
+        Bound::Unbounded => None,
+    };
+    let upper = match range.end_bound() {
+        Bound::Included(version) => Some((Op::LessEq, version)),
+        Bound::Excluded(version) => Some((Op::Less, version)),
+        Bound::Unbounded => None,
+    };
+    for (op, version) in lower.into_iter().chain(upper) {
+        comparators.push(comparator(op, version));
+    }
+    VersionReq { comparators }
+}
+
+fn comparator(op: Op, version: &Version) -> Comparator {
+    Comparator {
+        op,
+        major: version.major,
+        minor: Some(version.minor),
+        patch: Some(version.patch),
+        pre: version.pre.clone(),
+    }
+}
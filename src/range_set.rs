@@ -0,0 +1,374 @@
+use crate::backport::*;
+use crate::canonical::push_primitive;
+use crate::{BuildMetadata, Comparator, Op, Version, VersionReq};
+use core::cmp::Ordering;
+use core::iter::FromIterator;
+use core::u64;
+
+/// A union of many version requirements, answering whether any of them
+/// matches a version in logarithmic time.
+///
+/// This is meant for deny-lists and advisory databases, where a version is
+/// checked against thousands of affected ranges. Construction turns every
+/// requirement into an interval of versions, then sorts and merges the
+/// intervals, after which [`contains`][VersionRangeSet::contains] is a binary
+/// search.
+///
+/// `contains` agrees with [`VersionReq::matches`] on every requirement the
+/// set was built from, including the rule that a pre-release version only
+/// matches a requirement naming a pre-release of the same major.minor.patch.
+/// A set built from OSV advisories by [`VersionRangeSet::from_osv`] instead
+/// contains every version within the affected ranges.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionRangeSet, VersionReq};
+///
+/// let affected = [">=1.0.0, <1.4.2", "=2.0.0-rc.1", ">=2.3.0, <2.3.5"]
+///     .iter()
+///     .map(|req| req.parse::<VersionReq>().unwrap())
+///     .collect::<VersionRangeSet>();
+///
+/// assert!(affected.contains(&Version::parse("1.2.0").unwrap()));
+/// assert!(affected.contains(&Version::parse("2.0.0-rc.1").unwrap()));
+/// assert!(!affected.contains(&Version::parse("1.4.2").unwrap()));
+/// assert!(!affected.contains(&Version::parse("2.0.0").unwrap()));
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct VersionRangeSet {
+    // Disjoint, sorted by lower bound. Only consulted for versions without a
+    // pre-release.
+    ranges: Vec<Range>,
+    // Requirements with a pre-release comparator, sorted by the
+    // major.minor.patch of that comparator. A requirement appears once per
+    // distinct triple. Only consulted for versions with a pre-release.
+    prerelease: Vec<((u64, u64, u64), VersionReq)>,
+    // Disjoint, sorted by lower bound. Consulted for every version, with
+    // pre-releases ordered by precedence like any other version. These come
+    // from sources other than VersionReq, such as OSV advisories.
+    precedence: Vec<Range>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Range {
+    pub(crate) lower: Option<Bound>,
+    pub(crate) upper: Option<Bound>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Bound {
+    pub(crate) version: Version,
+    pub(crate) inclusive: bool,
+}
+
+impl VersionRangeSet {
+    /// A set matching no versions.
+    pub fn new() -> Self {
+        VersionRangeSet::default()
+    }
+
+    /// Build a set from many requirements at once.
+    ///
+    /// This is the same as collecting the requirements with `FromIterator`.
+    pub fn from_reqs<'a, I>(reqs: I) -> Self
+    where
+        I: IntoIterator<Item = &'a VersionReq>,
+    {
+        let mut ranges = Vec::new();
+        let mut prerelease = Vec::new();
+        for req in reqs {
+            ranges.extend(range_of(req));
+            let start = prerelease.len();
+            for cmp in &req.comparators {
+                if let (Some(minor), Some(patch)) = (cmp.minor, cmp.patch) {
+                    let triple = (cmp.major, minor, patch);
+                    if !cmp.pre.is_empty() && !prerelease[start..].iter().any(|(t, _)| *t == triple)
+                    {
+                        prerelease.push((triple, req.clone()));
+                    }
+                }
+            }
+        }
+
+        prerelease.sort_by_key(|entry| entry.0);
+
+        VersionRangeSet {
+            ranges: merge(ranges),
+            prerelease,
+            precedence: Vec::new(),
+        }
+    }
+
+    // A set containing every version, pre-release or not, that lies in one of
+    // the ranges by precedence.
+    pub(crate) fn from_precedence_ranges(ranges: Vec<Range>) -> Self {
+        VersionRangeSet {
+            ranges: Vec::new(),
+            prerelease: Vec::new(),
+            precedence: merge(ranges),
+        }
+    }
+
+    /// Whether any of the requirements the set was built from matches
+    /// `version`.
+    pub fn contains(&self, version: &Version) -> bool {
+        if in_ranges(&self.precedence, version) {
+            return true;
+        }
+
+        if !version.pre.is_empty() {
+            let triple = (version.major, version.minor, version.patch);
+            let start = partition_point(&self.prerelease, |(t, _)| *t < triple);
+            return self.prerelease[start..]
+                .iter()
+                .take_while(|(t, _)| *t == triple)
+                .any(|(_, req)| req.matches(version));
+        }
+
+        in_ranges(&self.ranges, version)
+    }
+
+    /// Whether the set matches no versions at all.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty() && self.prerelease.is_empty() && self.precedence.is_empty()
+    }
+
+    /// Split the published versions of a package into those the set contains
+    /// and those it does not.
+    ///
+    /// Built from the affected ranges of an advisory together with its
+    /// unaffected or patched ranges, the set should classify every published
+    /// version; the versions in [`CoverageReport::uncovered`] are the ones
+    /// the advisory forgot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{Version, VersionRangeSet, VersionReq};
+    ///
+    /// let affected = VersionReq::parse(">=1.0.0, <1.4.2").unwrap();
+    /// let patched = VersionReq::parse(">=1.4.2, <2").unwrap();
+    /// let set = VersionRangeSet::from_reqs(&[affected, patched]);
+    ///
+    /// let published = ["0.9.0", "1.0.0", "1.4.2", "2.0.0"]
+    ///     .iter()
+    ///     .map(|v| Version::parse(v).unwrap())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let report = set.covers(&published);
+    /// assert!(!report.is_complete());
+    /// assert_eq!(report.uncovered, [Version::new(0, 9, 0), Version::new(2, 0, 0)]);
+    /// ```
+    pub fn covers(&self, all_published: &[Version]) -> CoverageReport {
+        let mut report = CoverageReport::default();
+        for version in all_published {
+            if self.contains(version) {
+                report.covered.push(version.clone());
+            } else {
+                report.uncovered.push(version.clone());
+            }
+        }
+        report
+    }
+}
+
+/// The published versions a [`VersionRangeSet`] does and does not contain,
+/// returned by [`VersionRangeSet::covers`].
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct CoverageReport {
+    /// Versions the set contains, in the order given.
+    pub covered: Vec<Version>,
+    /// Versions outside every range of the set, in the order given.
+    pub uncovered: Vec<Version>,
+}
+
+impl CoverageReport {
+    /// Whether the set contains every published version.
+    pub fn is_complete(&self) -> bool {
+        self.uncovered.is_empty()
+    }
+}
+
+impl FromIterator<VersionReq> for VersionRangeSet {
+    fn from_iter<I: IntoIterator<Item = VersionReq>>(iter: I) -> Self {
+        let reqs: Vec<VersionReq> = iter.into_iter().collect();
+        VersionRangeSet::from_reqs(&reqs)
+    }
+}
+
+impl<'a> FromIterator<&'a VersionReq> for VersionRangeSet {
+    fn from_iter<I: IntoIterator<Item = &'a VersionReq>>(iter: I) -> Self {
+        VersionRangeSet::from_reqs(iter)
+    }
+}
+
+// The interval of versions without a pre-release that req matches, or None if
+// it matches none.
+fn range_of(req: &VersionReq) -> Option<Range> {
+    let mut primitive = Vec::new();
+    for cmp in &req.comparators {
+        push_primitive(cmp, &mut primitive);
+    }
+    let range = intersect(primitive);
+
+    if let (Some(lower), Some(upper)) = (&range.lower, &range.upper) {
+        match lower.version.cmp_precedence(&upper.version) {
+            Ordering::Greater => return None,
+            Ordering::Equal if !(lower.inclusive && upper.inclusive) => return None,
+            _ => {}
+        }
+    }
+    Some(range)
+}
+
+// The bounds of a single comparator, which may describe an empty interval.
+pub(crate) fn comparator_range(cmp: &Comparator) -> Range {
+    let mut primitive = Vec::new();
+    push_primitive(cmp, &mut primitive);
+    intersect(primitive)
+}
+
+fn intersect(primitive: Vec<Comparator>) -> Range {
+    let mut range = Range {
+        lower: None,
+        upper: None,
+    };
+    for cmp in primitive {
+        // The only partial primitive is `>I.J` or `>I` on the last possible
+        // line, which nothing is greater than.
+        let missing = if cmp.op == Op::Greater { u64::MAX } else { 0 };
+        let version = Version {
+            major: cmp.major,
+            minor: cmp.minor.unwrap_or(missing),
+            patch: cmp.patch.unwrap_or(missing),
+            pre: cmp.pre,
+            build: BuildMetadata::EMPTY,
+        };
+        let bound = |inclusive| {
+            Some(Bound {
+                version: version.clone(),
+                inclusive,
+            })
+        };
+        let (lower, upper) = match cmp.op {
+            Op::Exact => (bound(true), bound(true)),
+            Op::Greater => (bound(false), None),
+            Op::GreaterEq => (bound(true), None),
+            Op::Less => (None, bound(false)),
+            Op::LessEq => (None, bound(true)),
+            _ => unreachable!(),
+        };
+        if lower.is_some() && cmp_lower(&lower, &range.lower) == Ordering::Greater {
+            range.lower = lower;
+        }
+        if upper.is_some() && cmp_upper(&upper, &range.upper) == Ordering::Less {
+            range.upper = upper;
+        }
+    }
+    range
+}
+
+fn merge(mut ranges: Vec<Range>) -> Vec<Range> {
+    ranges.sort_by(|a, b| cmp_lower(&a.lower, &b.lower));
+    let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        if let Some(last) = merged.last_mut() {
+            if touches(&last.upper, &range.lower) {
+                if cmp_upper(&range.upper, &last.upper) == Ordering::Greater {
+                    last.upper = range.upper;
+                }
+                continue;
+            }
+        }
+        merged.push(range);
+    }
+    merged.shrink_to_fit();
+    merged
+}
+
+fn in_ranges(ranges: &[Range], version: &Version) -> bool {
+    let count = partition_point(ranges, |range| admits_lower(&range.lower, version));
+    match count.checked_sub(1) {
+        Some(i) => admits_upper(&ranges[i].upper, version),
+        None => false,
+    }
+}
+
+// Orders lower bounds from least to most restrictive. None is unbounded.
+fn cmp_lower(a: &Option<Bound>, b: &Option<Bound>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => a
+            .version
+            .cmp_precedence(&b.version)
+            .then_with(|| b.inclusive.cmp(&a.inclusive)),
+    }
+}
+
+// Orders upper bounds from most to least restrictive. None is unbounded.
+fn cmp_upper(a: &Option<Bound>, b: &Option<Bound>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a
+            .version
+            .cmp_precedence(&b.version)
+            .then_with(|| a.inclusive.cmp(&b.inclusive)),
+    }
+}
+
+// Whether a range ending at upper and a range starting at lower, which starts
+// no earlier than the first one, overlap or meet without a gap.
+fn touches(upper: &Option<Bound>, lower: &Option<Bound>) -> bool {
+    match (upper, lower) {
+        (None, _) | (_, None) => true,
+        (Some(upper), Some(lower)) => match lower.version.cmp_precedence(&upper.version) {
+            Ordering::Less => true,
+            Ordering::Equal => lower.inclusive || upper.inclusive,
+            Ordering::Greater => false,
+        },
+    }
+}
+
+fn admits_lower(lower: &Option<Bound>, version: &Version) -> bool {
+    match lower {
+        None => true,
+        Some(lower) => match lower.version.cmp_precedence(version) {
+            Ordering::Less => true,
+            Ordering::Equal => lower.inclusive,
+            Ordering::Greater => false,
+        },
+    }
+}
+
+fn admits_upper(upper: &Option<Bound>, version: &Version) -> bool {
+    match upper {
+        None => true,
+        Some(upper) => match version.cmp_precedence(&upper.version) {
+            Ordering::Less => true,
+            Ordering::Equal => upper.inclusive,
+            Ordering::Greater => false,
+        },
+    }
+}
+
+// Number of leading elements satisfying pred, which must be true for a prefix
+// of the slice and false for the rest. Equivalent to `slice::partition_point`,
+// which is newer than the minimum supported compiler.
+fn partition_point<T>(slice: &[T], pred: impl Fn(&T) -> bool) -> usize {
+    let mut lo = 0;
+    let mut hi = slice.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&slice[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
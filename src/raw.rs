@@ -0,0 +1,56 @@
+//! The semantics of each comparison operator, applied to a comparator's
+//! version regardless of the comparator's own [`Op`][crate::Op].
+//!
+//! [`Comparator::matches`][crate::Comparator::matches] dispatches on the
+//! comparator's operator to one of these functions, then applies the
+//! pre-release rule: a version with a pre-release only matches if the
+//! comparator has a pre-release on the same major.minor.patch. The functions
+//! here do neither. They look only at the major, minor, patch and pre-release
+//! of `cmp`, compare them to `version` the way the named operator would, and
+//! leave the pre-release rule to the caller. This is for composing operator
+//! semantics without building a copy of a comparator with a different `op`.
+//!
+//! # Example
+//!
+//! ```
+//! use semver::{raw, Comparator, Version};
+//!
+//! // The bounds implied by a bare `1.2` under two different readings.
+//! let cmp = Comparator::parse("1.2").unwrap();
+//! let version = Version::parse("1.5.0").unwrap();
+//! assert!(raw::matches_caret(&cmp, &version));
+//! assert!(!raw::matches_tilde(&cmp, &version));
+//!
+//! // `>=` is `=` or `>`.
+//! let cmp = Comparator::parse("1.2.3").unwrap();
+//! let version = Version::parse("1.2.3").unwrap();
+//! assert!(raw::matches_exact(&cmp, &version) || raw::matches_greater(&cmp, &version));
+//! ```
+
+use crate::{eval, Comparator, MatchOptions, Version};
+
+/// Whether `version` matches `cmp` as `=` would, which for a partial version
+/// like `1.2` is the same as the wildcard `1.2.*`.
+pub fn matches_exact(cmp: &Comparator, version: &Version) -> bool {
+    eval::matches_exact(cmp, version, &MatchOptions::new())
+}
+
+/// Whether `version` matches `cmp` as `>` would.
+pub fn matches_greater(cmp: &Comparator, version: &Version) -> bool {
+    eval::matches_greater(cmp, version, &MatchOptions::new())
+}
+
+/// Whether `version` matches `cmp` as `<` would.
+pub fn matches_less(cmp: &Comparator, version: &Version) -> bool {
+    eval::matches_less(cmp, version, &MatchOptions::new())
+}
+
+/// Whether `version` matches `cmp` as `~` would.
+pub fn matches_tilde(cmp: &Comparator, version: &Version) -> bool {
+    eval::matches_tilde(cmp, version, &MatchOptions::new())
+}
+
+/// Whether `version` matches `cmp` as `^` would.
+pub fn matches_caret(cmp: &Comparator, version: &Version) -> bool {
+    eval::matches_caret(cmp, version, &MatchOptions::new())
+}
@@ -0,0 +1,133 @@
+use crate::backport::*;
+use crate::error::ErrorKind;
+use crate::parse::Error;
+use crate::{BuildMetadata, Prerelease, Version};
+use core::str;
+use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+/// Fixed-layout mirror of a [`Version`], for tables of versions that are
+/// read in place from a memory-mapped file.
+///
+/// The numeric core is stored inline. Pre-release and build metadata are
+/// stored as offset and length into a separate string table, a byte buffer
+/// shared by all records of the table, so that every record has the same
+/// size and a slice of records can be reinterpreted from bytes with
+/// [`FromBytes`] without any per-record deserialization.
+///
+/// Fields are in native byte order, so tables are only portable between
+/// machines of the same endianness.
+///
+/// # Example
+///
+/// ```
+/// use semver::{RawVersion, Version};
+/// use zerocopy::{AsBytes, FromBytes};
+///
+/// let mut records = Vec::new();
+/// let mut strings = Vec::new();
+/// for v in ["1.0.0", "1.1.0-beta.1", "2.0.0+build.5"] {
+///     let version = Version::parse(v).unwrap();
+///     records.push(RawVersion::encode(&version, &mut strings).unwrap());
+/// }
+///
+/// // Write `bytes` and `strings` to disk, and later map them back in.
+/// let bytes = records.as_bytes();
+///
+/// let table = RawVersion::slice_from(bytes).unwrap();
+/// assert_eq!(table[1].minor, 1);
+/// assert_eq!(table[1].pre(&strings), Some("beta.1"));
+/// assert_eq!(table[2].decode(&strings).unwrap().to_string(), "2.0.0+build.5");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "zerocopy")))]
+#[derive(AsBytes, FromBytes, FromZeroes, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(C)]
+pub struct RawVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// Offset of the pre-release in the string table.
+    pub pre_offset: u32,
+    /// Length in bytes of the pre-release, 0 if there is none.
+    pub pre_len: u32,
+    /// Offset of the build metadata in the string table.
+    pub build_offset: u32,
+    /// Length in bytes of the build metadata, 0 if there is none.
+    pub build_len: u32,
+}
+
+impl RawVersion {
+    /// Mirror `version`, appending its pre-release and build metadata to the
+    /// string table `strings`.
+    ///
+    /// Returns `None`, leaving `strings` unchanged, if the string table would
+    /// grow beyond the `u32::MAX` bytes that the offsets can address.
+    pub fn encode(version: &Version, strings: &mut Vec<u8>) -> Option<Self> {
+        let original_len = strings.len();
+        let pre = push_str(strings, version.pre.as_str());
+        let build = push_str(strings, version.build.as_str());
+        match (pre, build) {
+            (Some((pre_offset, pre_len)), Some((build_offset, build_len))) => Some(RawVersion {
+                major: version.major,
+                minor: version.minor,
+                patch: version.patch,
+                pre_offset,
+                pre_len,
+                build_offset,
+                build_len,
+            }),
+            _ => {
+                strings.truncate(original_len);
+                None
+            }
+        }
+    }
+
+    /// The pre-release, looked up in the string table without validating
+    /// it. Returns `None` if the offsets are out of bounds of `strings` or
+    /// the bytes are not UTF-8.
+    pub fn pre<'a>(&self, strings: &'a [u8]) -> Option<&'a str> {
+        lookup(strings, self.pre_offset, self.pre_len)
+    }
+
+    /// The build metadata, looked up in the string table without validating
+    /// it. Returns `None` if the offsets are out of bounds of `strings` or
+    /// the bytes are not UTF-8.
+    pub fn build<'a>(&self, strings: &'a [u8]) -> Option<&'a str> {
+        lookup(strings, self.build_offset, self.build_len)
+    }
+
+    /// Reconstruct the owned [`Version`], validating the pre-release and
+    /// build metadata found in the string table.
+    pub fn decode(&self, strings: &[u8]) -> Result<Version, Error> {
+        let pre = self
+            .pre(strings)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidRawVersion))?;
+        let build = self
+            .build(strings)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidRawVersion))?;
+        Ok(Version {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease::new(pre)?,
+            build: BuildMetadata::new(build)?,
+        })
+    }
+}
+
+fn push_str(strings: &mut Vec<u8>, string: &str) -> Option<(u32, u32)> {
+    let offset = strings.len();
+    let end = offset.checked_add(string.len())?;
+    if end > u32::max_value() as usize {
+        return None;
+    }
+    strings.extend_from_slice(string.as_bytes());
+    Some((offset as u32, string.len() as u32))
+}
+
+fn lookup(strings: &[u8], offset: u32, len: u32) -> Option<&str> {
+    let start = offset as usize;
+    let end = start.checked_add(len as usize)?;
+    let bytes = strings.get(start..end)?;
+    str::from_utf8(bytes).ok()
+}
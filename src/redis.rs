@@ -0,0 +1,68 @@
+use crate::backport::*;
+use crate::{Version, VersionReq};
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+/// Written as a single argument holding the version string, such as
+/// `1.4.2-rc.1`.
+impl ToRedisArgs for Version {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(self);
+    }
+}
+
+impl ToSingleRedisArg for Version {}
+
+/// Read from a string reply, such as the value of `GET` or a member returned
+/// by `ZRANGE`.
+impl FromRedisValue for Version {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let string = String::from_redis_value(v)?;
+        Version::parse(&string)
+            .map_err(|err| ParsingError::from(format!("invalid version {:?}: {}", string, err)))
+    }
+}
+
+/// Written as a single argument holding the requirement string, such as
+/// `>=1.2.3, <2`.
+impl ToRedisArgs for VersionReq {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(self);
+    }
+}
+
+impl ToSingleRedisArg for VersionReq {}
+
+impl FromRedisValue for VersionReq {
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        let string = String::from_redis_value(v)?;
+        VersionReq::parse(&string).map_err(|err| {
+            ParsingError::from(format!("invalid version requirement {:?}: {}", string, err))
+        })
+    }
+}
+
+const MAJOR_BITS: u32 = 16;
+const MINOR_BITS: u32 = 16;
+const PATCH_BITS: u32 = 20;
+
+// Major, minor, patch and one bit telling a release from a pre-release take
+// 53 bits, the most that a double holds exactly.
+pub(crate) fn score(version: &Version) -> Option<f64> {
+    if version.major >> MAJOR_BITS != 0
+        || version.minor >> MINOR_BITS != 0
+        || version.patch >> PATCH_BITS != 0
+    {
+        return None;
+    }
+    let mut packed = version.major;
+    packed = packed << MINOR_BITS | version.minor;
+    packed = packed << PATCH_BITS | version.patch;
+    packed = packed << 1 | version.pre.is_empty() as u64;
+    Some(packed as f64)
+}
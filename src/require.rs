@@ -0,0 +1,119 @@
+use crate::backport::*;
+use crate::{Comparator, MatchOptions, PreReleasePolicy, Version, VersionReq};
+use core::fmt::{self, Display};
+
+/// Error returned by [`require_compatible`] when a version does not satisfy a
+/// requirement.
+///
+/// The Display impl is a complete sentence meant to be shown to end users,
+/// naming the component, the requirement, the version found, and why the
+/// version was rejected.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IncompatibleError {
+    component: String,
+    found: Version,
+    req: VersionReq,
+    unsatisfied: Vec<Comparator>,
+}
+
+/// Check that `found`, the version of `component`, satisfies `req`.
+///
+/// This is [`VersionReq::matches`] with an error that explains the failure,
+/// for plugin systems and handshakes that check a peer's protocol version.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=1.4, <2").unwrap();
+/// let found = Version::parse("2.0.1").unwrap();
+///
+/// let err = semver::require_compatible("protocol", &found, &req).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "protocol version 2.0.1 is not compatible with the required >=1.4, <2: \
+///      version does not satisfy <2",
+/// );
+/// ```
+pub fn require_compatible(
+    component: &str,
+    found: &Version,
+    req: &VersionReq,
+) -> Result<(), IncompatibleError> {
+    if req.matches(found) {
+        return Ok(());
+    }
+
+    let in_range = MatchOptions::new().policy(PreReleasePolicy::Allowed);
+    let unsatisfied = req
+        .comparators
+        .iter()
+        .filter(|cmp| !cmp.matches_with(found, &in_range))
+        .cloned()
+        .collect();
+
+    Err(IncompatibleError {
+        component: String::from(component),
+        found: found.clone(),
+        req: req.clone(),
+        unsatisfied,
+    })
+}
+
+impl IncompatibleError {
+    /// The name of the component whose version was checked.
+    pub fn component(&self) -> &str {
+        &self.component
+    }
+
+    /// The version that was found.
+    pub fn found(&self) -> &Version {
+        &self.found
+    }
+
+    /// The requirement the version was checked against.
+    pub fn req(&self) -> &VersionReq {
+        &self.req
+    }
+
+    /// The comparators of the requirement that the version is outside of.
+    ///
+    /// This is empty if the version lies within every comparator and was
+    /// rejected only because it is a pre-release, which a requirement matches
+    /// only if one of its comparators names a pre-release of the same
+    /// major.minor.patch.
+    pub fn unsatisfied(&self) -> &[Comparator] {
+        &self.unsatisfied
+    }
+}
+
+impl Display for IncompatibleError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} version {} is not compatible with the required {}: ",
+            self.component, self.found, self.req,
+        )?;
+        if self.unsatisfied.is_empty() {
+            write!(
+                formatter,
+                "pre-release versions are only compatible with a requirement that names a pre-release of {}.{}.{}",
+                self.found.major, self.found.minor, self.found.patch,
+            )
+        } else {
+            formatter.write_str("version does not satisfy ")?;
+            for (i, cmp) in self.unsatisfied.iter().enumerate() {
+                if i > 0 {
+                    formatter.write_str(", ")?;
+                }
+                write!(formatter, "{}", cmp)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for IncompatibleError {}
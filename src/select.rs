@@ -0,0 +1,207 @@
+use crate::channel::ChannelRule;
+use crate::{Version, VersionReq};
+use core::cmp::{Ordering, Reverse};
+
+/// A rule ranking candidate versions, for picking the best one with
+/// [`select`].
+///
+/// A policy scores each candidate, or rules it out by returning `None`. The
+/// candidate with the highest score is selected, and among candidates with
+/// equal scores the one with the highest [precedence][Version::cmp_precedence]
+/// wins. Policies therefore only need to express what they prefer over plain
+/// "newest".
+///
+/// # Example
+///
+/// A policy that stays on the current major line:
+///
+/// ```
+/// use semver::{SelectionContext, SelectionPolicy, Version};
+///
+/// struct SameMajor;
+///
+/// impl SelectionPolicy for SameMajor {
+///     type Score = ();
+///
+///     fn score(&self, version: &Version, ctx: &SelectionContext) -> Option<()> {
+///         match ctx.current {
+///             Some(current) if current.major != version.major => None,
+///             _ => Some(()),
+///         }
+///     }
+/// }
+///
+/// let versions = ["1.2.0", "1.9.3", "2.0.0"]
+///     .iter()
+///     .map(|v| v.parse::<Version>().unwrap())
+///     .collect::<Vec<_>>();
+/// let current = Version::parse("1.2.0").unwrap();
+/// let ctx = SelectionContext {
+///     current: Some(&current),
+///     req: None,
+/// };
+///
+/// let best = semver::select(&versions, &SameMajor, &ctx).unwrap();
+/// assert_eq!(best.to_string(), "1.9.3");
+/// ```
+pub trait SelectionPolicy {
+    /// How candidates compare under this policy. Greater is better.
+    type Score: Ord;
+
+    /// Score a candidate, or return `None` if the policy rules it out.
+    fn score(&self, version: &Version, ctx: &SelectionContext) -> Option<Self::Score>;
+}
+
+/// What a [`SelectionPolicy`] may take into account besides the candidate
+/// itself.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct SelectionContext<'a> {
+    /// The version in use now, if any, which policies like
+    /// [`ClosestToCurrent`] measure candidates against.
+    pub current: Option<&'a Version>,
+    /// A requirement every candidate must match, checked by [`select`]
+    /// before the policy sees the candidate.
+    pub req: Option<&'a VersionReq>,
+}
+
+impl<'a> SelectionContext<'a> {
+    /// A context with no current version and no requirement.
+    pub const fn new() -> Self {
+        SelectionContext {
+            current: None,
+            req: None,
+        }
+    }
+}
+
+/// Select the best of `versions` according to `policy`, or `None` if the
+/// requirement in `ctx` or the policy rules out every version.
+///
+/// # Example
+///
+/// ```
+/// use semver::{FewestBreakingChanges, SelectionContext, Version, VersionReq};
+///
+/// let versions = ["0.3.1", "0.3.4", "0.4.0", "1.0.0"]
+///     .iter()
+///     .map(|v| v.parse::<Version>().unwrap())
+///     .collect::<Vec<_>>();
+/// let current = Version::parse("0.3.1").unwrap();
+/// let ctx = SelectionContext {
+///     current: Some(&current),
+///     req: None,
+/// };
+///
+/// let best = semver::select(&versions, &FewestBreakingChanges, &ctx).unwrap();
+/// assert_eq!(best.to_string(), "0.3.4");
+/// ```
+pub fn select<'a, P>(
+    versions: &'a [Version],
+    policy: &P,
+    ctx: &SelectionContext,
+) -> Option<&'a Version>
+where
+    P: SelectionPolicy + ?Sized,
+{
+    versions
+        .iter()
+        .filter(|version| ctx.req.map_or(true, |req| req.matches(version)))
+        .filter_map(|version| policy.score(version, ctx).map(|score| (score, version)))
+        .max_by(|a, b| Ord::cmp(&a.0, &b.0).then_with(|| a.1.cmp_precedence(b.1)))
+        .map(|(_score, version)| version)
+}
+
+/// The highest version that has no pre-release identifier.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct HighestStable;
+
+/// The highest version, whether or not it is a pre-release.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct HighestAny;
+
+/// The version without a pre-release identifier whose major, minor and patch
+/// numbers are nearest those of the current version, by
+/// [`VersionDelta::distance`][crate::VersionDelta::distance]. Ties go to the
+/// higher version. Without a current version, this is [`HighestStable`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct ClosestToCurrent;
+
+/// The highest version without a pre-release identifier that crosses the
+/// fewest compatibility boundaries from the current version, in the sense of
+/// Cargo's caret requirements: major versions, or minor versions on major
+/// version 0, or patch versions on 0.0. Without a current version, this is
+/// [`HighestStable`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct FewestBreakingChanges;
+
+impl SelectionPolicy for HighestStable {
+    type Score = ();
+
+    fn score(&self, version: &Version, _ctx: &SelectionContext) -> Option<()> {
+        if version.pre.is_empty() {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+impl SelectionPolicy for HighestAny {
+    type Score = ();
+
+    fn score(&self, _version: &Version, _ctx: &SelectionContext) -> Option<()> {
+        Some(())
+    }
+}
+
+impl SelectionPolicy for ClosestToCurrent {
+    type Score = Reverse<u128>;
+
+    fn score(&self, version: &Version, ctx: &SelectionContext) -> Option<Self::Score> {
+        HighestStable.score(version, ctx)?;
+        let distance = match ctx.current {
+            Some(current) => current.delta(version).distance(),
+            None => 0,
+        };
+        Some(Reverse(distance))
+    }
+}
+
+impl SelectionPolicy for FewestBreakingChanges {
+    type Score = Reverse<u64>;
+
+    fn score(&self, version: &Version, ctx: &SelectionContext) -> Option<Self::Score> {
+        HighestStable.score(version, ctx)?;
+        let breaking = match ctx.current {
+            Some(current) => breaking_changes(current, version),
+            None => 0,
+        };
+        Some(Reverse(breaking))
+    }
+}
+
+impl SelectionPolicy for ChannelRule {
+    type Score = ();
+
+    fn score(&self, version: &Version, _ctx: &SelectionContext) -> Option<()> {
+        if self.accepts(version) {
+            Some(())
+        } else {
+            None
+        }
+    }
+}
+
+// Number of caret-compatibility boundaries between the two versions' lines.
+fn breaking_changes(a: &Version, b: &Version) -> u64 {
+    let diff = |x: u64, y: u64| if x > y { x - y } else { y - x };
+    match Ord::cmp(&a.major, &b.major) {
+        Ordering::Equal if a.major != 0 => 0,
+        Ordering::Equal => match Ord::cmp(&a.minor, &b.minor) {
+            Ordering::Equal if a.minor != 0 => 0,
+            Ordering::Equal => diff(a.patch, b.patch),
+            _ => diff(a.minor, b.minor),
+        },
+        _ => diff(a.major, b.major),
+    }
+}
@@ -0,0 +1,111 @@
+use crate::alloc::collections::BTreeMap;
+use crate::backport::*;
+use crate::{PrecedenceKey, Version};
+use core::cmp::Ordering;
+
+/// A problem with one publish in a release history, found by
+/// [`validate_sequence`].
+///
+/// Indices are positions in the slice given to `validate_sequence`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SequenceIssue {
+    /// The version at `index` has lower precedence than the one at
+    /// `previous`, published earlier in the same compatibility line: the
+    /// same major version, or for `0.y.z` the same major and minor.
+    NotMonotonic { index: usize, previous: usize },
+    /// The version at `index` has the same precedence as the one at
+    /// `earlier`, differing at most in build metadata.
+    Reused { index: usize, earlier: usize },
+    /// The version at `index` is a pre-release of the release at `release`,
+    /// which was published earlier.
+    PrereleaseAfterStable { index: usize, release: usize },
+}
+
+impl SequenceIssue {
+    /// Index of the publish that has the problem.
+    pub fn index(&self) -> usize {
+        match *self {
+            SequenceIssue::NotMonotonic { index, .. }
+            | SequenceIssue::Reused { index, .. }
+            | SequenceIssue::PrereleaseAfterStable { index, .. } => index,
+        }
+    }
+}
+
+/// Check the versions of a package, in the order they were published, for
+/// publishes a registry would want to reject or warn about.
+///
+/// Each publish is reported at most once, preferring
+/// [`Reused`][SequenceIssue::Reused], then
+/// [`PrereleaseAfterStable`][SequenceIssue::PrereleaseAfterStable], then
+/// [`NotMonotonic`][SequenceIssue::NotMonotonic]. A backport to an older
+/// compatibility line, like `1.4.3` after `2.0.0`, is not an issue. Issues
+/// are in publish order.
+///
+/// # Example
+///
+/// ```
+/// use semver::{SequenceIssue, Version};
+///
+/// let published = ["1.0.0", "1.1.0", "1.0.5", "1.1.0+rebuild", "1.2.0", "1.2.0-rc.1"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     semver::validate_sequence(&published),
+///     [
+///         SequenceIssue::NotMonotonic { index: 2, previous: 1 },
+///         SequenceIssue::Reused { index: 3, earlier: 1 },
+///         SequenceIssue::PrereleaseAfterStable { index: 5, release: 4 },
+///     ],
+/// );
+/// ```
+pub fn validate_sequence(versions_in_publish_order: &[Version]) -> Vec<SequenceIssue> {
+    let mut issues = Vec::new();
+    let mut seen = BTreeMap::new();
+    let mut releases = BTreeMap::new();
+    let mut highest: BTreeMap<(u64, u64), usize> = BTreeMap::new();
+
+    for (index, version) in versions_in_publish_order.iter().enumerate() {
+        let triple = (version.major, version.minor, version.patch);
+        let line = if version.major == 0 {
+            (0, version.minor)
+        } else {
+            (version.major, 0)
+        };
+
+        let issue = if let Some(&earlier) = seen.get(&version.precedence_key()) {
+            Some(SequenceIssue::Reused { index, earlier })
+        } else if let (false, Some(&release)) = (version.pre.is_empty(), releases.get(&triple)) {
+            Some(SequenceIssue::PrereleaseAfterStable { index, release })
+        } else {
+            match highest.get(&line) {
+                Some(&previous)
+                    if version.cmp_precedence(&versions_in_publish_order[previous])
+                        == Ordering::Less =>
+                {
+                    Some(SequenceIssue::NotMonotonic { index, previous })
+                }
+                _ => None,
+            }
+        };
+        issues.extend(issue);
+
+        seen.entry(PrecedenceKey::new(version)).or_insert(index);
+        if version.pre.is_empty() {
+            releases.entry(triple).or_insert(index);
+        }
+        let is_highest = match highest.get(&line) {
+            Some(&previous) => {
+                version.cmp_precedence(&versions_in_publish_order[previous]) == Ordering::Greater
+            }
+            None => true,
+        };
+        if is_highest {
+            highest.insert(line, index);
+        }
+    }
+
+    issues
+}
@@ -107,3 +107,123 @@ impl<'de> Deserialize<'de> for Comparator {
         deserializer.deserialize_str(ComparatorVisitor)
     }
 }
+
+/// Serialize and deserialize a table of dependency requirements, keyed by
+/// name, for use with `#[serde(with = "semver::req_map")]`.
+///
+/// Deserializing a `BTreeMap<String, VersionReq>` directly reports an invalid
+/// requirement with nothing but the parse error, which leaves the user to
+/// guess which entry it came from. This module names the entry in the error,
+/// as in `invalid version requirement for "serde": unexpected character 'x'
+/// while parsing major version number`. The error is raised while the
+/// requirement's value is being deserialized, so deserializers that report
+/// locations, like serde_json and toml, point at the offending value.
+///
+/// # Example
+///
+/// ```
+/// let json = r#"{
+///     "rand": "0.8",
+///     "serde": "1.0.0.0"
+/// }"#;
+///
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// let err = semver::req_map::deserialize(&mut deserializer).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid version requirement for \"serde\": expected comma after patch \
+///      version number, found '.' at line 3 column 22",
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod req_map {
+    use crate::alloc::collections::BTreeMap;
+    use crate::backport::*;
+    use crate::VersionReq;
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::de::{DeserializeSeed, Deserializer, Error, MapAccess, Visitor};
+    use serde::ser::{SerializeMap, Serializer};
+
+    pub fn serialize<S>(
+        map: &BTreeMap<String, VersionReq>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut serializer = serializer.serialize_map(Some(map.len()))?;
+        for (name, req) in map {
+            serializer.serialize_entry(name, req)?;
+        }
+        serializer.end()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BTreeMap<String, VersionReq>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ReqMapVisitor;
+
+        impl<'de> Visitor<'de> for ReqMapVisitor {
+            type Value = BTreeMap<String, VersionReq>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("map of names to semver version requirements")
+            }
+
+            fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut map = BTreeMap::new();
+                while let Some(name) = access.next_key::<String>()? {
+                    let req = access.next_value_seed(ReqSeed {
+                        name: &name,
+                        marker: PhantomData,
+                    })?;
+                    map.insert(name, req);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(ReqMapVisitor)
+    }
+
+    struct ReqSeed<'a, 'de> {
+        name: &'a str,
+        marker: PhantomData<&'de ()>,
+    }
+
+    impl<'a, 'de> DeserializeSeed<'de> for ReqSeed<'a, 'de> {
+        type Value = VersionReq;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(self)
+        }
+    }
+
+    impl<'a, 'de> Visitor<'de> for ReqSeed<'a, 'de> {
+        type Value = VersionReq;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "semver version requirement for {:?}", self.name)
+        }
+
+        fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            string.parse().map_err(|error| {
+                Error::custom(format_args!(
+                    "invalid version requirement for {:?}: {}",
+                    self.name, error,
+                ))
+            })
+        }
+    }
+}
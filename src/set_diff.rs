@@ -0,0 +1,132 @@
+use crate::backport::*;
+use crate::Version;
+use core::cmp::{self, Ordering};
+
+/// What changed between two lists of published versions, returned by
+/// [`diff_version_sets`].
+///
+/// Every list is in precedence order, and versions that differ only in
+/// build metadata are in the order of their build metadata.
+#[derive(Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct SetDiff {
+    /// Versions in the new list but not the old one, other than those paired
+    /// up as a metadata change. This includes any left over when the new list
+    /// has more build variants of a precedence than the old one.
+    pub added: Vec<Version>,
+    /// Versions in the old list but not the new one, other than those paired
+    /// up as a metadata change. This includes any left over when the old list
+    /// has more build variants of a precedence than the new one.
+    pub removed: Vec<Version>,
+    /// Versions in both lists whose build metadata changed, such as
+    /// `1.2.0+b1` republished as `1.2.0+b2`.
+    pub metadata_changed: Vec<MetadataChange>,
+}
+
+/// A version whose build metadata changed, as part of a [`SetDiff`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MetadataChange {
+    pub old: Version,
+    pub new: Version,
+}
+
+impl SetDiff {
+    /// Whether the two lists contain the same versions, in any order.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.metadata_changed.is_empty()
+    }
+}
+
+/// Compare the versions a registry lists before and after an update.
+///
+/// Versions are matched up by precedence. One present in only one of the
+/// lists is added or removed, and one present in both with different build
+/// metadata is a metadata change. Order and duplicates within each list do
+/// not matter.
+///
+/// If a list has several versions of one precedence that differ in build
+/// metadata, those present in both lists are unchanged, and the rest are
+/// paired up as metadata changes in order of their build metadata, with any
+/// left over added or removed.
+///
+/// # Example
+///
+/// ```
+/// use semver::{diff_version_sets, Version};
+///
+/// let parse = |list: &[&str]| {
+///     list.iter()
+///         .map(|v| v.parse::<Version>().unwrap())
+///         .collect::<Vec<_>>()
+/// };
+/// let old = parse(&["1.0.0", "1.1.0+b1", "1.2.0-rc.1"]);
+/// let new = parse(&["1.2.0", "1.1.0+b2", "1.0.0", "1.2.0-rc.1"]);
+///
+/// let diff = diff_version_sets(&old, &new);
+/// assert_eq!(diff.added, parse(&["1.2.0"]));
+/// assert!(diff.removed.is_empty());
+/// assert_eq!(diff.metadata_changed[0].old.to_string(), "1.1.0+b1");
+/// assert_eq!(diff.metadata_changed[0].new.to_string(), "1.1.0+b2");
+/// ```
+pub fn diff_version_sets(old: &[Version], new: &[Version]) -> SetDiff {
+    let old = sorted(old);
+    let new = sorted(new);
+    let mut diff = SetDiff::default();
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        let next = match (old.get(i), new.get(j)) {
+            (Some(a), Some(b)) if a.cmp_precedence(b) == Ordering::Greater => b,
+            (Some(a), _) => a,
+            (None, Some(b)) => b,
+            (None, None) => unreachable!(),
+        };
+        let old_group = group(&old[i..], next);
+        let new_group = group(&new[j..], next);
+        i += old_group.len();
+        j += new_group.len();
+
+        let old_only: Vec<&Version> = old_group
+            .iter()
+            .filter(|version| !new_group.contains(version))
+            .cloned()
+            .collect();
+        let new_only: Vec<&Version> = new_group
+            .iter()
+            .filter(|version| !old_group.contains(version))
+            .cloned()
+            .collect();
+
+        let paired = cmp::min(old_only.len(), new_only.len());
+        for (old, new) in old_only.iter().zip(&new_only) {
+            diff.metadata_changed.push(MetadataChange {
+                old: Version::clone(old),
+                new: Version::clone(new),
+            });
+        }
+        for version in &old_only[paired..] {
+            diff.removed.push(Version::clone(version));
+        }
+        for version in &new_only[paired..] {
+            diff.added.push(Version::clone(version));
+        }
+    }
+
+    diff
+}
+
+// Sorted by precedence and then build metadata, without duplicates.
+fn sorted(versions: &[Version]) -> Vec<&Version> {
+    let mut sorted: Vec<&Version> = versions.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+    sorted
+}
+
+// The leading versions that have the same precedence as `version`.
+fn group<'a, 'b>(versions: &'b [&'a Version], version: &Version) -> &'b [&'a Version] {
+    let len = versions
+        .iter()
+        .take_while(|v| v.cmp_precedence(version) == Ordering::Equal)
+        .count();
+    &versions[..len]
+}
@@ -0,0 +1,178 @@
+use crate::alloc::sync::Arc;
+use crate::Version;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+#[cfg(all(feature = "intern", not(no_once_cell)))]
+use std::collections::HashSet;
+#[cfg(all(feature = "intern", not(no_once_cell)))]
+use std::sync::{Mutex, OnceLock};
+
+/// A reference-counted [`Version`], cloned by bumping a count instead of
+/// copying its pre-release and build metadata.
+///
+/// This is meant for graph structures, such as dependency graphs, that put
+/// the same version into many nodes. `SharedVersion` has the same ordering,
+/// equality and hash as the wrapped `Version`, and dereferences to it. With
+/// the "intern" feature, [`pooled`][Self::pooled] additionally makes equal
+/// versions share one allocation across the whole process.
+///
+/// # Example
+///
+/// ```
+/// use semver::{SharedVersion, Version};
+///
+/// let version = SharedVersion::new(Version::parse("1.4.2-rc.1").unwrap());
+/// let nodes = vec![version.clone(); 3];
+/// assert!(SharedVersion::ptr_eq(&nodes[0], &nodes[2]));
+/// assert_eq!(nodes[1].pre.as_str(), "rc.1");
+/// ```
+#[derive(Clone)]
+pub struct SharedVersion {
+    version: Arc<Version>,
+}
+
+#[cfg(all(feature = "intern", not(no_once_cell)))]
+static POOL: OnceLock<Mutex<HashSet<Arc<Version>>>> = OnceLock::new();
+
+impl SharedVersion {
+    pub fn new(version: Version) -> Self {
+        SharedVersion {
+            version: Arc::new(version),
+        }
+    }
+
+    /// A `SharedVersion` sharing its allocation with every other pooled
+    /// version equal to `version`, including in build metadata.
+    ///
+    /// The pool holds on to every version put into it until
+    /// [`trim_pool`][Self::trim_pool] is called. Before Rust 1.70 there is no
+    /// pool and this is the same as [`new`][Self::new].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{SharedVersion, Version};
+    ///
+    /// let a = SharedVersion::pooled(Version::parse("1.4.2").unwrap());
+    /// let b = SharedVersion::pooled(Version::parse("1.4.2").unwrap());
+    /// assert!(SharedVersion::ptr_eq(&a, &b));
+    /// ```
+    #[cfg(feature = "intern")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "intern")))]
+    pub fn pooled(version: Version) -> Self {
+        #[cfg(not(no_once_cell))]
+        {
+            let pool = POOL.get_or_init(|| Mutex::new(HashSet::new()));
+            let mut pool = pool.lock().unwrap_or_else(|poison| poison.into_inner());
+            if let Some(shared) = pool.get(&version) {
+                return SharedVersion {
+                    version: Arc::clone(shared),
+                };
+            }
+            let shared = Arc::new(version);
+            pool.insert(Arc::clone(&shared));
+            SharedVersion { version: shared }
+        }
+        #[cfg(no_once_cell)] // rustc <1.70
+        SharedVersion::new(version)
+    }
+
+    /// Drop the pooled versions that are no longer used outside the pool,
+    /// returning how many were dropped.
+    #[cfg(feature = "intern")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "intern")))]
+    pub fn trim_pool() -> usize {
+        #[cfg(not(no_once_cell))]
+        {
+            let pool = match POOL.get() {
+                Some(pool) => pool,
+                None => return 0,
+            };
+            let mut pool = pool.lock().unwrap_or_else(|poison| poison.into_inner());
+            let before = pool.len();
+            pool.retain(|shared| Arc::strong_count(shared) > 1);
+            before - pool.len()
+        }
+        #[cfg(no_once_cell)]
+        0
+    }
+
+    /// Whether `a` and `b` share one allocation, which implies that they are
+    /// equal.
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        Arc::ptr_eq(&a.version, &b.version)
+    }
+
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// The wrapped version, copied if it is shared with other values.
+    pub fn into_inner(self) -> Version {
+        Arc::try_unwrap(self.version).unwrap_or_else(|shared| Version::clone(&shared))
+    }
+}
+
+impl From<Version> for SharedVersion {
+    fn from(version: Version) -> Self {
+        SharedVersion::new(version)
+    }
+}
+
+impl Deref for SharedVersion {
+    type Target = Version;
+
+    fn deref(&self) -> &Self::Target {
+        &self.version
+    }
+}
+
+impl Borrow<Version> for SharedVersion {
+    fn borrow(&self) -> &Version {
+        &self.version
+    }
+}
+
+impl PartialEq for SharedVersion {
+    fn eq(&self, other: &Self) -> bool {
+        SharedVersion::ptr_eq(self, other) || self.version == other.version
+    }
+}
+
+impl Eq for SharedVersion {}
+
+impl PartialOrd for SharedVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if SharedVersion::ptr_eq(self, other) {
+            return Ordering::Equal;
+        }
+        self.version.cmp(&other.version)
+    }
+}
+
+impl Hash for SharedVersion {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.version.hash(hasher);
+    }
+}
+
+impl Display for SharedVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.version, formatter)
+    }
+}
+
+impl Debug for SharedVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.version, formatter)
+    }
+}
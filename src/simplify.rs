@@ -0,0 +1,248 @@
+use crate::backport::*;
+use crate::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+use core::cmp::Ordering;
+use core::iter;
+
+/// Drop the alternatives that are redundant in a union of requirements.
+///
+/// `reqs` are alternatives, a version being accepted if it matches any of
+/// them, as for [`VersionRangeSet`][crate::VersionRangeSet]. A requirement is
+/// dropped if every version it matches is also matched by another one that is
+/// kept, so `^1.2` and `^1.4` simplify to `^1.2`, and of alternatives that
+/// match exactly the same versions only the first is kept. The remaining
+/// requirements keep their order and their text.
+///
+/// Subsumption is decided exactly, including the rule that a pre-release
+/// version only matches a requirement naming a pre-release of the same
+/// major.minor.patch. Alternatives that overlap without either containing the
+/// other are both kept.
+///
+/// # Example
+///
+/// ```
+/// use semver::VersionReq;
+///
+/// let reqs = ["^1.4", ">=1.2, <1.3", "^1.2", "~1.2.7", "=2.0.0-rc.1", "^2.0.0-rc.1"]
+///     .iter()
+///     .map(|req| req.parse::<VersionReq>().unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let simplified = semver::simplify_union(&reqs);
+/// assert_eq!(simplified.len(), 2);
+/// assert_eq!(simplified[0].to_string(), "^1.2");
+/// assert_eq!(simplified[1].to_string(), "^2.0.0-rc.1");
+/// ```
+pub fn simplify_union(reqs: &[VersionReq]) -> Vec<VersionReq> {
+    let mut keep: Vec<bool> = iter::repeat(true).take(reqs.len()).collect();
+    for i in 0..reqs.len() {
+        for j in 0..reqs.len() {
+            if i == j || !keep[j] || !is_subset(&reqs[i], &reqs[j]) {
+                continue;
+            }
+            // Of two equivalent requirements, the first one is kept.
+            if j > i && is_subset(&reqs[j], &reqs[i]) {
+                continue;
+            }
+            keep[i] = false;
+            break;
+        }
+    }
+    reqs.iter()
+        .zip(keep)
+        .filter(|&(_, keep)| keep)
+        .map(|(req, _)| req.clone())
+        .collect()
+}
+
+// Indices of comparators that can be removed from `req` without changing the
+// versions it matches. Later comparators are dropped first, so of two
+// equivalent comparators the first is kept, and at least one comparator is
+// always kept.
+pub(crate) fn redundant_comparators(req: &VersionReq) -> Vec<usize> {
+    let mut keep: Vec<bool> = iter::repeat(true).take(req.comparators.len()).collect();
+    for i in (0..req.comparators.len()).rev() {
+        keep[i] = false;
+        let rest = VersionReq {
+            comparators: req
+                .comparators
+                .iter()
+                .zip(&keep)
+                .filter(|&(_, keep)| *keep)
+                .map(|(cmp, _)| cmp.clone())
+                .collect(),
+        };
+        if rest.comparators.is_empty() || !is_subset(&rest, req) || !is_subset(req, &rest) {
+            keep[i] = true;
+        }
+    }
+    keep.iter()
+        .enumerate()
+        .filter(|&(_, keep)| !*keep)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+// Whether every version matched by `a` is matched by `b`.
+pub(crate) fn is_subset(a: &VersionReq, b: &VersionReq) -> bool {
+    !any_probe(a, &b.comparators, |version| {
+        a.matches(version) && !b.matches(version)
+    })
+}
+
+// Whether any version matches `req`.
+pub(crate) fn is_satisfiable(req: &VersionReq) -> bool {
+    any_probe(req, &[], |version| req.matches(version))
+}
+
+// The version of lowest precedence that matches every one of `reqs`, without
+// build metadata.
+pub(crate) fn min_match(reqs: &[VersionReq]) -> Option<Version> {
+    let mut comparators = Vec::new();
+    for req in reqs {
+        comparators.extend(req.comparators.iter().cloned());
+    }
+    let all = VersionReq { comparators };
+    let mut min: Option<Version> = None;
+    any_probe(&all, &[], |version| {
+        let lower = match &min {
+            Some(min) => version.cmp_precedence(min) == Ordering::Less,
+            None => true,
+        };
+        if lower && reqs.iter().all(|req| req.matches(version)) {
+            min = Some(version.clone());
+        }
+        false
+    });
+    min
+}
+
+// Whether `f` holds for some version, given that `f` is a combination of
+// matching `a` and requirements made of `others`.
+//
+// Ordered by precedence, the versions that a requirement matches change only
+// at the bounds of its comparators. Checking each bound of either requirement,
+// and one version strictly between each pair of adjacent bounds of every kind
+// that the pre-release rule tells apart, covers every version that `a` can
+// match. The lowest version that `a` matches is always among those checked.
+fn any_probe<F>(a: &VersionReq, others: &[Comparator], mut f: F) -> bool
+where
+    F: FnMut(&Version) -> bool,
+{
+    let mut points = Vec::new();
+    for cmp in a.comparators.iter().chain(others) {
+        push_points(cmp, &mut points);
+    }
+    points.sort_by(Version::cmp_precedence);
+    points.dedup_by(|x, y| x.cmp_precedence(y) == Ordering::Equal);
+
+    // Releases whose pre-releases `a` can match.
+    let mut lanes = Vec::new();
+    for cmp in &a.comparators {
+        if !cmp.pre.is_empty() {
+            lanes.push(Version::new(
+                cmp.major,
+                cmp.minor.unwrap_or(0),
+                cmp.patch.unwrap_or(0),
+            ));
+        }
+    }
+
+    for (i, point) in points.iter().enumerate() {
+        if f(point) {
+            return true;
+        }
+        let next = points.get(i + 1);
+        let below = |version: &Version| match next {
+            Some(next) => version.cmp_precedence(next) == Ordering::Less,
+            None => true,
+        };
+        if let Some(release) = next_release(point) {
+            if below(&release) && f(&release) {
+                return true;
+            }
+        }
+        for lane in &lanes {
+            if let Some(pre) = next_prerelease(point, lane) {
+                if below(&pre) && f(&pre) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    // Below the lowest bound.
+    let first = points.first();
+    let above = |version: &Version| match first {
+        Some(first) => version.cmp_precedence(first) == Ordering::Less,
+        None => true,
+    };
+    let lowest = Version::new(0, 0, 0);
+    if above(&lowest) && f(&lowest) {
+        return true;
+    }
+    for lane in &lanes {
+        let pre = zero_pre(lane.clone());
+        if above(&pre) && f(&pre) {
+            return true;
+        }
+    }
+    false
+}
+
+// Every version at which the versions matched by `cmp` may start or stop.
+fn push_points(cmp: &Comparator, out: &mut Vec<Version>) {
+    let minor = cmp.minor.unwrap_or(0);
+    let patch = cmp.patch.unwrap_or(0);
+    out.push(Version {
+        major: cmp.major,
+        minor,
+        patch,
+        pre: cmp.pre.clone(),
+        build: BuildMetadata::EMPTY,
+    });
+    out.push(Version::new(cmp.major, minor, patch));
+    out.push(zero_pre(Version::new(cmp.major, minor, patch)));
+    if let Some(major) = cmp.major.checked_add(1) {
+        out.push(zero_pre(Version::new(major, 0, 0)));
+    }
+    if let Some(minor) = minor.checked_add(1) {
+        out.push(zero_pre(Version::new(cmp.major, minor, 0)));
+    }
+    if let Some(patch) = patch.checked_add(1) {
+        out.push(zero_pre(Version::new(cmp.major, minor, patch)));
+    }
+}
+
+// The lowest release with precedence greater than `version`.
+fn next_release(version: &Version) -> Option<Version> {
+    if !version.pre.is_empty() {
+        Some(Version::new(version.major, version.minor, version.patch))
+    } else if let Some(patch) = version.patch.checked_add(1) {
+        Some(Version::new(version.major, version.minor, patch))
+    } else if let Some(minor) = version.minor.checked_add(1) {
+        Some(Version::new(version.major, minor, 0))
+    } else {
+        Some(Version::new(version.major.checked_add(1)?, 0, 0))
+    }
+}
+
+// The lowest pre-release of `lane` with precedence greater than `version`.
+fn next_prerelease(version: &Version, lane: &Version) -> Option<Version> {
+    let lowest = zero_pre(lane.clone());
+    if version.cmp_precedence(&lowest) == Ordering::Less {
+        return Some(lowest);
+    }
+    let same_lane =
+        (version.major, version.minor, version.patch) == (lane.major, lane.minor, lane.patch);
+    if !same_lane || version.pre.is_empty() {
+        return None;
+    }
+    let mut next = lane.clone();
+    next.pre = Prerelease::new(&format!("{}.0", version.pre)).ok()?;
+    Some(next)
+}
+
+fn zero_pre(mut version: Version) -> Version {
+    version.pre = Prerelease::new("0").unwrap();
+    version
+}
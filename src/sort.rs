@@ -0,0 +1,54 @@
+use crate::backport::*;
+use crate::{Error, Version};
+
+/// Sort versions in place by their total order, in which versions that differ
+/// only in build metadata are ordered by the build metadata.
+///
+/// This is the same as `versions.sort()`. Use [`sort_by_precedence`] to leave
+/// versions that differ only in build metadata in their original relative
+/// order instead.
+pub fn sort(versions: &mut [Version]) {
+    versions.sort();
+}
+
+/// Sort versions in place by [precedence][Version::cmp_precedence].
+///
+/// The sort is stable, so versions that differ only in build metadata keep
+/// their original relative order.
+pub fn sort_by_precedence(versions: &mut [Version]) {
+    versions.sort_by(Version::cmp_precedence);
+}
+
+/// Sort version strings in place by the [precedence][Version::cmp_precedence]
+/// of the versions they parse to.
+///
+/// Every string is parsed once up front. If any of them is not a valid
+/// version, the slice is left unmodified and the index of the first invalid
+/// string is returned with its parse error. The sort is stable.
+///
+/// # Example
+///
+/// ```
+/// let mut strings = ["1.10.0", "1.2.0", "1.2.0-rc.1"];
+/// semver::sort_strs(&mut strings).unwrap();
+/// assert_eq!(strings, ["1.2.0-rc.1", "1.2.0", "1.10.0"]);
+///
+/// let mut strings = ["1.10.0", "1.2", "1.2.0-rc.1", "v1"];
+/// let (index, _error) = semver::sort_strs(&mut strings).unwrap_err();
+/// assert_eq!(index, 1);
+/// ```
+pub fn sort_strs(strings: &mut [&str]) -> Result<(), (usize, Error)> {
+    let mut parsed = Vec::with_capacity(strings.len());
+    for (i, string) in strings.iter().enumerate() {
+        match Version::parse(string) {
+            Ok(version) => parsed.push((version, *string)),
+            Err(error) => return Err((i, error)),
+        }
+    }
+
+    parsed.sort_by(|a, b| a.0.cmp_precedence(&b.0));
+    for (slot, (_version, string)) in strings.iter_mut().zip(parsed) {
+        *slot = string;
+    }
+    Ok(())
+}
@@ -0,0 +1,243 @@
+use crate::backport::*;
+use crate::{Comparator, Op, Prerelease, VersionReq};
+use core::fmt::Write;
+
+/// SQL dialect targeted by [`VersionReq::to_sql_predicate`].
+///
+/// The dialects differ only in how column names are quoted.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SqlDialect {
+    /// PostgreSQL, and the SQL standard. Identifiers are quoted as `"major"`.
+    Postgres,
+    /// MySQL and MariaDB. Identifiers are quoted as `` `major` ``.
+    MySql,
+    /// SQLite. Identifiers are quoted as `"major"`.
+    Sqlite,
+}
+
+/// Names of the columns holding each part of a version, for
+/// [`VersionReq::to_sql_predicate`].
+///
+/// The `major`, `minor` and `patch` columns are expected to hold integers. The
+/// `pre` column holds the pre-release as text, with the empty string for
+/// versions that are not a pre-release, and the `pre_key` column holds
+/// [`Prerelease::to_sort_key`] of the same pre-release, with a binary
+/// collation. Pre-releases are ordered by `pre_key`, since SQL would order the
+/// text of `beta.10` before `beta.2`. Build metadata plays no role in matching
+/// so it needs no column.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SqlColumns<'a> {
+    pub major: &'a str,
+    pub minor: &'a str,
+    pub patch: &'a str,
+    pub pre: &'a str,
+    pub pre_key: &'a str,
+}
+
+impl Default for SqlColumns<'_> {
+    /// Columns named `major`, `minor`, `patch`, `pre` and `pre_key`.
+    fn default() -> Self {
+        SqlColumns {
+            major: "major",
+            minor: "minor",
+            patch: "patch",
+            pre: "pre",
+            pre_key: "pre_key",
+        }
+    }
+}
+
+pub(crate) fn predicate(req: &VersionReq, dialect: SqlDialect, columns: &SqlColumns) -> String {
+    let sql = Sql {
+        major: quote(dialect, columns.major),
+        minor: quote(dialect, columns.minor),
+        patch: quote(dialect, columns.patch),
+        pre: quote(dialect, columns.pre),
+        pre_key: quote(dialect, columns.pre_key),
+    };
+
+    let mut out = String::new();
+    for cmp in &req.comparators {
+        let _ = write!(out, "({}) AND ", sql.comparator(cmp));
+    }
+
+    // Mirrors the pre-release rule of matches_req: a pre-release version only
+    // matches if some comparator with the same major.minor.patch also has a
+    // pre-release.
+    let _ = write!(out, "({} = ''", sql.pre);
+    for cmp in &req.comparators {
+        if let (Some(minor), Some(patch), false) = (cmp.minor, cmp.patch, cmp.pre.is_empty()) {
+            let _ = write!(
+                out,
+                " OR ({} = {} AND {} = {} AND {} = {})",
+                sql.major, cmp.major, sql.minor, minor, sql.patch, patch,
+            );
+        }
+    }
+    out.push(')');
+    out
+}
+
+fn quote(dialect: SqlDialect, identifier: &str) -> String {
+    let quote = match dialect {
+        SqlDialect::Postgres | SqlDialect::Sqlite => '"',
+        SqlDialect::MySql => '`',
+    };
+    let mut quoted = String::with_capacity(identifier.len() + 2);
+    quoted.push(quote);
+    for ch in identifier.chars() {
+        if ch == quote {
+            quoted.push(quote);
+        }
+        quoted.push(ch);
+    }
+    quoted.push(quote);
+    quoted
+}
+
+struct Sql {
+    major: String,
+    minor: String,
+    patch: String,
+    pre: String,
+    pre_key: String,
+}
+
+// Each of these mirrors the function of the same name in eval.rs.
+impl Sql {
+    fn comparator(&self, cmp: &Comparator) -> String {
+        match cmp.op {
+            Op::Exact | Op::Wildcard => self.exact(cmp),
+            Op::Greater => self.greater(cmp),
+            Op::GreaterEq => format!("{} OR {}", self.exact(cmp), self.greater(cmp)),
+            Op::Less => self.less(cmp),
+            Op::LessEq => format!("{} OR {}", self.exact(cmp), self.less(cmp)),
+            Op::Tilde => self.tilde(cmp),
+            Op::Caret => self.caret(cmp),
+            #[cfg(no_non_exhaustive)]
+            Op::__NonExhaustive => unreachable!(),
+        }
+    }
+
+    fn exact(&self, cmp: &Comparator) -> String {
+        let mut out = format!("({} = {}", self.major, cmp.major);
+        if let Some(minor) = cmp.minor {
+            let _ = write!(out, " AND {} = {}", self.minor, minor);
+        }
+        if let Some(patch) = cmp.patch {
+            let _ = write!(out, " AND {} = {}", self.patch, patch);
+        }
+        let _ = write!(out, " AND {} = '{}')", self.pre, cmp.pre);
+        out
+    }
+
+    fn greater(&self, cmp: &Comparator) -> String {
+        let pre = if cmp.pre.is_empty() {
+            // Nothing with the same major.minor.patch is greater than a
+            // release.
+            None
+        } else {
+            // The key of a release sorts after that of every pre-release.
+            Some(format!("{} > '{}'", self.pre_key, cmp.pre.to_sort_key()))
+        };
+        self.lexicographic(">", cmp, pre)
+    }
+
+    fn less(&self, cmp: &Comparator) -> String {
+        let pre = if cmp.pre.is_empty() {
+            format!("{} <> ''", self.pre)
+        } else {
+            format!("{} < '{}'", self.pre_key, cmp.pre.to_sort_key())
+        };
+        self.lexicographic("<", cmp, Some(pre))
+    }
+
+    // Strict comparison of (major, minor, patch, pre) against the comparator,
+    // where a missing minor or patch fails the comparison once every more
+    // significant component is equal. `pre` is the comparison of pre-release
+    // when major, minor and patch are all equal, or None if that is false.
+    fn lexicographic(&self, strict: &str, cmp: &Comparator, pre: Option<String>) -> String {
+        let mut tail = pre;
+        for &(column, value) in &[(&self.patch, cmp.patch), (&self.minor, cmp.minor)] {
+            tail = value.map(|value| strict_then(column, strict, value, tail));
+        }
+        strict_then(&self.major, strict, cmp.major, tail)
+    }
+
+    fn pre_greater_eq(&self, pre: &Prerelease) -> String {
+        if pre.is_empty() {
+            format!("{} = ''", self.pre)
+        } else {
+            format!("{} >= '{}'", self.pre_key, pre.to_sort_key())
+        }
+    }
+
+    fn tilde(&self, cmp: &Comparator) -> String {
+        let mut out = format!("{} = {}", self.major, cmp.major);
+        if let Some(minor) = cmp.minor {
+            let _ = write!(out, " AND {} = {}", self.minor, minor);
+        }
+        match cmp.patch {
+            Some(patch) => {
+                let pre = self.pre_greater_eq(&cmp.pre);
+                let _ = write!(
+                    out,
+                    " AND {}",
+                    strict_then(&self.patch, ">", patch, Some(pre))
+                );
+            }
+            None => {
+                let _ = write!(out, " AND {}", self.pre_greater_eq(&cmp.pre));
+            }
+        }
+        format!("({})", out)
+    }
+
+    fn caret(&self, cmp: &Comparator) -> String {
+        let mut out = format!("({} = {}", self.major, cmp.major);
+
+        let minor = match cmp.minor {
+            None => return out + ")",
+            Some(minor) => minor,
+        };
+
+        let patch = match cmp.patch {
+            None => {
+                let op = if cmp.major > 0 { ">=" } else { "=" };
+                let _ = write!(out, " AND {} {} {})", self.minor, op, minor);
+                return out;
+            }
+            Some(patch) => patch,
+        };
+
+        let pre = self.pre_greater_eq(&cmp.pre);
+        if cmp.major > 0 {
+            let patch = strict_then(&self.patch, ">", patch, Some(pre));
+            let _ = write!(
+                out,
+                " AND {}",
+                strict_then(&self.minor, ">", minor, Some(patch))
+            );
+        } else if minor > 0 {
+            let patch = strict_then(&self.patch, ">", patch, Some(pre));
+            let _ = write!(out, " AND {} = {} AND {}", self.minor, minor, patch);
+        } else {
+            let _ = write!(
+                out,
+                " AND {} = {} AND {} = {} AND {}",
+                self.minor, minor, self.patch, patch, pre,
+            );
+        }
+        out + ")"
+    }
+}
+
+fn strict_then(column: &str, strict: &str, value: u64, tail: Option<String>) -> String {
+    match tail {
+        Some(tail) => format!(
+            "({} {} {} OR ({} = {} AND {}))",
+            column, strict, value, column, value, tail,
+        ),
+        None => format!("{} {} {}", column, strict, value),
+    }
+}
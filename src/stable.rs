@@ -0,0 +1,249 @@
+use crate::backport::*;
+use crate::compiled::{write_varint, Reader};
+use crate::error::ErrorKind;
+use crate::{BuildMetadata, Comparator, Error, Op, Prerelease, Version, VersionReq};
+
+// First byte of every encoding. The layout of format 1 is frozen; a change of
+// any kind must use a new format byte, and decoding must keep accepting 1.
+const FORMAT: u8 = 1;
+
+const TAG_VERSION: u8 = 0;
+const TAG_REQ: u8 = 1;
+
+/// A [`Version`] or [`VersionReq`] with a byte encoding that is guaranteed
+/// never to change.
+///
+/// [`CompiledReq::to_bytes`][crate::CompiledReq::to_bytes] and the serde
+/// impls make no promise across releases of this crate. This encoding does:
+/// the same value encodes to the same bytes in every version of the crate, on
+/// every platform, so encoded requirements can be hashed and used as keys in a
+/// content-addressed store. Each value has exactly one encoding, and
+/// `from_bytes` rejects anything else, so decoding and encoding again gives
+/// back the same bytes.
+///
+/// # Format
+///
+/// Integers are unsigned LEB128 in the fewest bytes. A string is its length
+/// as an integer followed by its UTF-8 bytes.
+///
+/// - A format byte, `1`.
+///
+/// - A tag byte, `0` for a version or `1` for a requirement.
+///
+/// - For a version: major, minor and patch as integers, then the pre-release
+///   and build metadata as strings, empty if absent.
+///
+/// - For a requirement: the number of comparators as an integer, then for
+///   each comparator:
+///   - the operator as a byte, `0` through `7` for `=`, `>`, `>=`, `<`,
+///     `<=`, `~`, `^` and wildcard;
+///   - major as an integer;
+///   - a byte of `0`, `1` or `3`, with bit 0 set if minor is present and bit
+///     1 set if patch is present, which is never `3` for a wildcard;
+///   - minor and patch as integers, those that are present;
+///   - the pre-release as a string;
+///   - an empty string. Requirements do not keep build metadata, and
+///     `from_bytes` rejects anything else here.
+///
+/// The pre-release and build metadata of a version are encoded as written, so `1.0.0+a` and
+/// `1.0.0+b` have different encodings although they have equal precedence,
+/// just as they are not equal by [`Eq`].
+///
+/// # Example
+///
+/// ```
+/// use semver::{StableEncoding, Version, VersionReq};
+///
+/// let version = Version::parse("1.2.3-rc.1").unwrap();
+/// let bytes = StableEncoding(version.clone()).to_bytes();
+/// assert_eq!(bytes, b"\x01\x00\x01\x02\x03\x04rc.1\x00");
+/// assert_eq!(StableEncoding::<Version>::from_bytes(&bytes).unwrap().0, version);
+///
+/// let req = VersionReq::parse(">=1.2").unwrap();
+/// let bytes = StableEncoding(req).to_bytes();
+/// assert_eq!(bytes, b"\x01\x01\x01\x02\x01\x01\x02\x00\x00");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StableEncoding<T>(pub T);
+
+impl StableEncoding<Version> {
+    /// Encode the version.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let version = &self.0;
+        let mut out = Vec::new();
+        out.push(FORMAT);
+        out.push(TAG_VERSION);
+        write_varint(&mut out, version.major);
+        write_varint(&mut out, version.minor);
+        write_varint(&mut out, version.patch);
+        write_str(&mut out, version.pre.as_str());
+        write_str(&mut out, version.build.as_str());
+        out
+    }
+
+    /// Decode a version encoded by [`to_bytes`][Self::to_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Fails if `bytes` is not the encoding of a version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        decode(bytes, TAG_VERSION, decode_version).map(StableEncoding)
+    }
+}
+
+impl StableEncoding<VersionReq> {
+    /// Encode the requirement.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let comparators = &self.0.comparators;
+        let mut out = Vec::new();
+        out.push(FORMAT);
+        out.push(TAG_REQ);
+        write_varint(&mut out, comparators.len() as u64);
+        for cmp in comparators {
+            out.push(op_to_byte(cmp.op));
+            write_varint(&mut out, cmp.major);
+            out.push(cmp.minor.is_some() as u8 | (cmp.patch.is_some() as u8) << 1);
+            for n in cmp.minor.iter().chain(&cmp.patch) {
+                write_varint(&mut out, *n);
+            }
+            write_str(&mut out, cmp.pre.as_str());
+            write_str(&mut out, "");
+        }
+        out
+    }
+
+    /// Decode a requirement encoded by [`to_bytes`][Self::to_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Fails if `bytes` is not the encoding of a requirement.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        decode(bytes, TAG_REQ, decode_req).map(StableEncoding)
+    }
+}
+
+fn decode<T>(bytes: &[u8], tag: u8, f: fn(&mut Reader) -> Option<T>) -> Result<T, Error> {
+    let mut input = Reader { bytes };
+    let value = if input.byte() == Some(FORMAT) && input.byte() == Some(tag) {
+        f(&mut input).filter(|_| input.bytes.is_empty())
+    } else {
+        None
+    };
+    value.ok_or_else(|| Error::new(ErrorKind::InvalidStableEncoding))
+}
+
+fn decode_version(input: &mut Reader) -> Option<Version> {
+    Some(Version {
+        major: varint(input)?,
+        minor: varint(input)?,
+        patch: varint(input)?,
+        pre: pre(read_str(input)?)?,
+        build: build(read_str(input)?)?,
+    })
+}
+
+fn decode_req(input: &mut Reader) -> Option<VersionReq> {
+    let len = varint(input)?;
+    let mut comparators = Vec::new();
+    for _ in 0..len {
+        let op = op_from_byte(input.byte()?)?;
+        let major = varint(input)?;
+        let (minor, patch) = match input.byte()? {
+            0b00 => (None, None),
+            0b01 => (Some(varint(input)?), None),
+            0b11 => (Some(varint(input)?), Some(varint(input)?)),
+            _ => return None,
+        };
+        let pre = pre(read_str(input)?)?;
+        if !read_str(input)?.is_empty() || patch.is_none() && !pre.is_empty() {
+            return None;
+        }
+        // A wildcard is written in place of minor or patch, as in `1.2.*`.
+        if op == Op::Wildcard && patch.is_some() {
+            return None;
+        }
+        comparators.push(Comparator {
+            op,
+            major,
+            minor,
+            patch,
+            pre,
+        });
+    }
+    Some(VersionReq { comparators })
+}
+
+fn pre(text: &str) -> Option<Prerelease> {
+    if text.is_empty() {
+        Some(Prerelease::EMPTY)
+    } else {
+        Prerelease::new(text).ok()
+    }
+}
+
+fn build(text: &str) -> Option<BuildMetadata> {
+    if text.is_empty() {
+        Some(BuildMetadata::EMPTY)
+    } else {
+        BuildMetadata::new(text).ok()
+    }
+}
+
+fn write_str(out: &mut Vec<u8>, string: &str) {
+    write_varint(out, string.len() as u64);
+    out.extend_from_slice(string.as_bytes());
+}
+
+fn read_str<'a>(input: &mut Reader<'a>) -> Option<&'a str> {
+    let len = varint(input)?;
+    if len > input.bytes.len() as u64 {
+        return None;
+    }
+    let (string, rest) = input.bytes.split_at(len as usize);
+    input.bytes = rest;
+    core::str::from_utf8(string).ok()
+}
+
+// A varint in the fewest bytes, so that every value has one encoding.
+fn varint(input: &mut Reader) -> Option<u64> {
+    let before = input.bytes.len();
+    let n = input.varint()?;
+    let mut minimal = Vec::new();
+    write_varint(&mut minimal, n);
+    if before - input.bytes.len() == minimal.len() {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+// Part of the frozen format, independent of the numbering used by
+// CompiledReq.
+fn op_to_byte(op: Op) -> u8 {
+    match op {
+        Op::Exact => 0,
+        Op::Greater => 1,
+        Op::GreaterEq => 2,
+        Op::Less => 3,
+        Op::LessEq => 4,
+        Op::Tilde => 5,
+        Op::Caret => 6,
+        Op::Wildcard => 7,
+        #[cfg(no_non_exhaustive)]
+        Op::__NonExhaustive => unreachable!(),
+    }
+}
+
+fn op_from_byte(byte: u8) -> Option<Op> {
+    Some(match byte {
+        0 => Op::Exact,
+        1 => Op::Greater,
+        2 => Op::GreaterEq,
+        3 => Op::Less,
+        4 => Op::LessEq,
+        5 => Op::Tilde,
+        6 => Op::Caret,
+        7 => Op::Wildcard,
+        _ => return None,
+    })
+}
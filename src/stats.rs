@@ -0,0 +1,110 @@
+use crate::eval;
+use crate::{MatchOptions, Version, VersionReq};
+use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Counts of the work done by [`CompiledReq::matches`][crate::CompiledReq::matches],
+/// returned by [`CompiledReq::stats`][crate::CompiledReq::stats].
+///
+/// Release versions are matched by two comparisons against a precomputed
+/// range, whatever the requirement. Pre-release versions are where the cost
+/// lies: those that reach the original comparators evaluate them one by one
+/// until one fails, and then apply the pre-release rule.
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct MatchStats {
+    /// Number of calls to `matches`.
+    pub calls: u64,
+    /// Number of comparators evaluated for pre-release versions, counting
+    /// only those evaluated before the first one that fails.
+    pub comparator_evaluations: u64,
+    /// Number of pre-release versions rejected by the pre-release rule,
+    /// either immediately because no comparator has a pre-release, or after
+    /// every comparator matched but none has a pre-release on the same
+    /// major.minor.patch.
+    pub prerelease_gate: u64,
+}
+
+// The counts behind CompiledReq::stats. Cloning copies the current counts.
+#[derive(Default)]
+pub(crate) struct Counters {
+    calls: AtomicU64,
+    comparator_evaluations: AtomicU64,
+    prerelease_gate: AtomicU64,
+}
+
+impl Counters {
+    pub(crate) fn call(&self) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn prerelease_gate(&self) {
+        self.prerelease_gate.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // Same result as eval::matches_req, for a pre-release version.
+    pub(crate) fn matches_prerelease(&self, req: &VersionReq, version: &Version) -> bool {
+        let options = MatchOptions::new();
+        for cmp in &req.comparators {
+            self.comparator_evaluations.fetch_add(1, Ordering::Relaxed);
+            if !eval::matches_impl(cmp, version, &options) {
+                return false;
+            }
+        }
+        if req
+            .comparators
+            .iter()
+            .any(|cmp| eval::pre_is_compatible(cmp, version))
+        {
+            true
+        } else {
+            self.prerelease_gate();
+            false
+        }
+    }
+
+    pub(crate) fn get(&self) -> MatchStats {
+        MatchStats {
+            calls: self.calls.load(Ordering::Relaxed),
+            comparator_evaluations: self.comparator_evaluations.load(Ordering::Relaxed),
+            prerelease_gate: self.prerelease_gate.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        self.calls.store(0, Ordering::Relaxed);
+        self.comparator_evaluations.store(0, Ordering::Relaxed);
+        self.prerelease_gate.store(0, Ordering::Relaxed);
+    }
+}
+
+impl Clone for Counters {
+    fn clone(&self) -> Self {
+        let stats = self.get();
+        Counters {
+            calls: AtomicU64::new(stats.calls),
+            comparator_evaluations: AtomicU64::new(stats.comparator_evaluations),
+            prerelease_gate: AtomicU64::new(stats.prerelease_gate),
+        }
+    }
+}
+
+// The counts are not part of the value of a CompiledReq.
+impl PartialEq for Counters {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Counters {}
+
+impl Hash for Counters {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl Debug for Counters {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.get(), formatter)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::Version;
+use core::usize;
+
+/// Iterator over the versions between two anchors, produced by bumping one
+/// component at a time.
+///
+/// Produced by [`Version::iter_patches_to`] and [`Version::iter_minors_to`].
+#[derive(Clone, Debug)]
+pub struct Steps {
+    major: u64,
+    // For patch steps, the fixed minor number. Unused for minor steps.
+    minor: u64,
+    bump: Bump,
+    // The component being bumped, and its value in the last version to be
+    // produced. None once the iterator is exhausted.
+    range: Option<(u64, u64)>,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Bump {
+    Patch,
+    Minor,
+}
+
+pub(crate) fn patches(start: &Version, end: &Version) -> Steps {
+    let range = if start.same_minor_line(end) && start.patch <= end.patch {
+        Some((start.patch, end.patch))
+    } else {
+        None
+    };
+    Steps {
+        major: start.major,
+        minor: start.minor,
+        bump: Bump::Patch,
+        range,
+    }
+}
+
+pub(crate) fn minors(start: &Version, end: &Version) -> Steps {
+    let range = if start.same_major_line(end) && start.minor <= end.minor {
+        Some((start.minor, end.minor))
+    } else {
+        None
+    };
+    Steps {
+        major: start.major,
+        minor: start.minor,
+        bump: Bump::Minor,
+        range,
+    }
+}
+
+impl Iterator for Steps {
+    type Item = Version;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (next, last) = self.range?;
+        self.range = if next < last {
+            Some((next + 1, last))
+        } else {
+            None
+        };
+        Some(match self.bump {
+            Bump::Patch => Version::new(self.major, self.minor, next),
+            Bump::Minor => Version::new(self.major, next, 0),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.range {
+            None => (0, Some(0)),
+            Some((next, last)) => match (last - next).checked_add(1) {
+                Some(len) if len <= usize::MAX as u64 => (len as usize, Some(len as usize)),
+                _ => (usize::MAX, None),
+            },
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::backport::*;
+use crate::canonical::push_primitive;
+use crate::display::fmt_comparator;
+use crate::{Separator, VersionReq};
+use core::fmt::{self, Display};
+
+/// Formatting conventions for [`VersionReq::display_with`].
+///
+/// The default style renders exactly like `VersionReq`'s `Display` impl.
+///
+/// # Example
+///
+/// ```
+/// use semver::{DisplayStyle, Separator, VersionReq};
+///
+/// let req = VersionReq::parse(">=1.2.3, <2").unwrap();
+/// let npm = DisplayStyle::new().separator(Separator::Whitespace);
+/// assert_eq!(req.display_with(&npm).to_string(), ">=1.2.3 <2");
+///
+/// let spaced = DisplayStyle::new().space_after_op(true);
+/// assert_eq!(req.display_with(&spaced).to_string(), ">= 1.2.3, < 2");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DisplayStyle {
+    separator: Separator,
+    space_after_op: bool,
+    desugar: bool,
+}
+
+impl DisplayStyle {
+    /// The style of `VersionReq`'s `Display` impl.
+    pub const fn new() -> Self {
+        DisplayStyle {
+            separator: Separator::Comma,
+            space_after_op: false,
+            desugar: false,
+        }
+    }
+
+    /// What to write between comparators: `, ` for [`Separator::Comma`], a
+    /// single space for [`Separator::Whitespace`], or ` && ` for
+    /// [`Separator::DoubleAmpersand`]. The default is a comma.
+    #[must_use]
+    pub fn separator(mut self, separator: Separator) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Whether to write a space between each operator and its version, as in
+    /// `>= 1.2.3`. The default is false.
+    #[must_use]
+    pub fn space_after_op(mut self, space_after_op: bool) -> Self {
+        self.space_after_op = space_after_op;
+        self
+    }
+
+    /// Whether to write each comparator as the bounds it stands for, using
+    /// only the operators `=`, `>`, `>=`, `<` and `<=` with complete
+    /// versions, so `^1.2` becomes `>=1.2.0, <2.0.0-0`. Comparators keep
+    /// their order and nothing is merged; for a normalized form see
+    /// [`VersionReq::canonical_string`]. The default is false.
+    #[must_use]
+    pub fn desugar(mut self, desugar: bool) -> Self {
+        self.desugar = desugar;
+        self
+    }
+}
+
+impl Default for DisplayStyle {
+    fn default() -> Self {
+        DisplayStyle::new()
+    }
+}
+
+/// Helper for rendering a requirement in a given [`DisplayStyle`], returned
+/// by [`VersionReq::display_with`].
+#[derive(Copy, Clone, Debug)]
+pub struct StyledReq<'a> {
+    pub(crate) req: &'a VersionReq,
+    pub(crate) style: &'a DisplayStyle,
+}
+
+impl<'a> Display for StyledReq<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut desugared = Vec::new();
+        let comparators = if self.style.desugar {
+            for cmp in &self.req.comparators {
+                push_primitive(cmp, &mut desugared);
+            }
+            &desugared
+        } else {
+            &self.req.comparators
+        };
+
+        if comparators.is_empty() {
+            return formatter.write_str("*");
+        }
+        let separator = match self.style.separator {
+            Separator::Comma => ", ",
+            Separator::Whitespace => " ",
+            Separator::DoubleAmpersand => " && ",
+        };
+        for (i, cmp) in comparators.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(separator)?;
+            }
+            fmt_comparator(cmp, self.style.space_after_op, formatter)?;
+        }
+        Ok(())
+    }
+}
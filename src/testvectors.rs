@@ -0,0 +1,605 @@
+//! Test vectors for implementations of SemVer matching and ordering.
+//!
+//! These are the cases this crate's own behavior is tested against, shipped
+//! as data so that reimplementations and bindings in other languages can check
+//! that they agree with it. Each table is a slice of plain structs holding
+//! string literals, with no dependencies on the rest of the crate.
+//!
+//! ```
+//! use semver::testvectors::MATCH_CASES;
+//! use semver::{Version, VersionReq};
+//!
+//! for case in MATCH_CASES {
+//!     let req = VersionReq::parse(case.req).unwrap();
+//!     let version = Version::parse(case.version).unwrap();
+//!     assert_eq!(req.matches(&version), case.matches);
+//! }
+//! ```
+
+use core::cmp::Ordering::{self, Equal, Greater, Less};
+
+/// Whether a version satisfies a requirement.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MatchCase {
+    pub req: &'static str,
+    pub version: &'static str,
+    pub matches: bool,
+}
+
+/// How two versions compare.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OrderingCase {
+    pub lhs: &'static str,
+    pub rhs: &'static str,
+    /// The result of `Ord`, in which versions that differ only in build
+    /// metadata are ordered by the build metadata.
+    pub ordering: Ordering,
+    /// The result of [`Version::cmp_precedence`][crate::Version::cmp_precedence],
+    /// in which build metadata is ignored as the SemVer spec prescribes.
+    pub precedence: Ordering,
+}
+
+/// Requirements in Cargo's syntax and versions that [`VersionReq::matches`]
+/// does or does not match.
+///
+/// For each requirement there are the versions it matches and their
+/// neighbors in a fixed list of versions, covering both sides of each bound
+/// and the pre-release rule.
+///
+/// [`VersionReq::matches`]: crate::VersionReq::matches
+pub static MATCH_CASES: &[MatchCase] = &[
+    case("*", "0.0.1", true),
+    case("*", "0.0.2", true),
+    case("*", "0.1.0", true),
+    case("*", "0.1.0-beta2.a", false),
+    case("*", "0.1.2", true),
+    case("*", "0.1.3", true),
+    case("*", "0.2.0", true),
+    case("*", "0.5.1-alpha3", false),
+    case("*", "0.5.1-beta", false),
+    case("*", "0.5.1", true),
+    case("*", "0.5.2-alpha3", false),
+    case("*", "0.6.0", true),
+    case("*", "0.9.9", true),
+    case("*", "1.0.0-pre", false),
+    case("*", "1.0.0-beta", false),
+    case("*", "1.0.0", true),
+    case("*", "1.0.1", true),
+    case("*", "1.1.0", true),
+    case("*", "1.1.2", true),
+    case("*", "1.1.3", true),
+    case("*", "1.2.0", true),
+    case("*", "1.2.2", true),
+    case("*", "1.2.3-beta.2", false),
+    case("*", "1.2.3-beta.4", false),
+    case("*", "1.2.3", true),
+    case("*", "1.2.4-beta.2", false),
+    case("*", "1.3.0", true),
+    case("*", "2.0.0", true),
+    case("*", "2.1.0-alpha1", false),
+    case("*", "2.1.0-alpha2", false),
+    case("*", "2.1.0", true),
+    case("*", "2.5.3", true),
+    case("*", "2.5.4", true),
+    case("*", "3.0.0", true),
+    case("1.0.0", "1.0.0-beta", false),
+    case("1.0.0", "1.0.0", true),
+    case("1.0.0", "1.0.1", true),
+    case("1.0.0", "1.1.0", true),
+    case("1.0.0", "1.1.2", true),
+    case("1.0.0", "1.1.3", true),
+    case("1.0.0", "1.2.0", true),
+    case("1.0.0", "1.2.2", true),
+    case("1.0.0", "1.2.3-beta.2", false),
+    case("1.0.0", "1.2.3-beta.4", false),
+    case("1.0.0", "1.2.3", true),
+    case("1.0.0", "1.2.4-beta.2", false),
+    case("1.0.0", "1.3.0", true),
+    case("1.0.0", "2.0.0", false),
+    case("=1.0.0", "1.0.0-beta", false),
+    case("=1.0.0", "1.0.0", true),
+    case("=1.0.0", "1.0.1", false),
+    case("=0.1.0-beta2.a", "0.1.0", false),
+    case("=0.1.0-beta2.a", "0.1.0-beta2.a", true),
+    case("=0.1.0-beta2.a", "0.1.2", false),
+    case(">=1.0.0", "1.0.0-beta", false),
+    case(">=1.0.0", "1.0.0", true),
+    case(">=1.0.0", "1.0.1", true),
+    case(">=1.0.0", "1.1.0", true),
+    case(">=1.0.0", "1.1.2", true),
+    case(">=1.0.0", "1.1.3", true),
+    case(">=1.0.0", "1.2.0", true),
+    case(">=1.0.0", "1.2.2", true),
+    case(">=1.0.0", "1.2.3-beta.2", false),
+    case(">=1.0.0", "1.2.3-beta.4", false),
+    case(">=1.0.0", "1.2.3", true),
+    case(">=1.0.0", "1.2.4-beta.2", false),
+    case(">=1.0.0", "1.3.0", true),
+    case(">=1.0.0", "2.0.0", true),
+    case(">=1.0.0", "2.1.0-alpha1", false),
+    case(">=1.0.0", "2.1.0-alpha2", false),
+    case(">=1.0.0", "2.1.0", true),
+    case(">=1.0.0", "2.5.3", true),
+    case(">=1.0.0", "2.5.4", true),
+    case(">=1.0.0", "3.0.0", true),
+    case(">1.0.0-alpha, <1.0.0", "0.9.9", false),
+    case(">1.0.0-alpha, <1.0.0", "1.0.0-pre", true),
+    case(">1.0.0-alpha, <1.0.0", "1.0.0-beta", true),
+    case(">1.0.0-alpha, <1.0.0", "1.0.0", false),
+    case(">=2.1.0-alpha2", "2.1.0-alpha1", false),
+    case(">=2.1.0-alpha2", "2.1.0-alpha2", true),
+    case(">=2.1.0-alpha2", "2.1.0", true),
+    case(">=2.1.0-alpha2", "2.5.3", true),
+    case(">=2.1.0-alpha2", "2.5.4", true),
+    case(">=2.1.0-alpha2", "3.0.0", true),
+    case("<1.0.0", "0.0.1", true),
+    case("<1.0.0", "0.0.2", true),
+    case("<1.0.0", "0.1.0", true),
+    case("<1.0.0", "0.1.0-beta2.a", false),
+    case("<1.0.0", "0.1.2", true),
+    case("<1.0.0", "0.1.3", true),
+    case("<1.0.0", "0.2.0", true),
+    case("<1.0.0", "0.5.1-alpha3", false),
+    case("<1.0.0", "0.5.1-beta", false),
+    case("<1.0.0", "0.5.1", true),
+    case("<1.0.0", "0.5.2-alpha3", false),
+    case("<1.0.0", "0.6.0", true),
+    case("<1.0.0", "0.9.9", true),
+    case("<1.0.0", "1.0.0-pre", false),
+    case("<=2.1.0-alpha2", "0.0.1", true),
+    case("<=2.1.0-alpha2", "0.0.2", true),
+    case("<=2.1.0-alpha2", "0.1.0", true),
+    case("<=2.1.0-alpha2", "0.1.0-beta2.a", false),
+    case("<=2.1.0-alpha2", "0.1.2", true),
+    case("<=2.1.0-alpha2", "0.1.3", true),
+    case("<=2.1.0-alpha2", "0.2.0", true),
+    case("<=2.1.0-alpha2", "0.5.1-alpha3", false),
+    case("<=2.1.0-alpha2", "0.5.1-beta", false),
+    case("<=2.1.0-alpha2", "0.5.1", true),
+    case("<=2.1.0-alpha2", "0.5.2-alpha3", false),
+    case("<=2.1.0-alpha2", "0.6.0", true),
+    case("<=2.1.0-alpha2", "0.9.9", true),
+    case("<=2.1.0-alpha2", "1.0.0-pre", false),
+    case("<=2.1.0-alpha2", "1.0.0-beta", false),
+    case("<=2.1.0-alpha2", "1.0.0", true),
+    case("<=2.1.0-alpha2", "1.0.1", true),
+    case("<=2.1.0-alpha2", "1.1.0", true),
+    case("<=2.1.0-alpha2", "1.1.2", true),
+    case("<=2.1.0-alpha2", "1.1.3", true),
+    case("<=2.1.0-alpha2", "1.2.0", true),
+    case("<=2.1.0-alpha2", "1.2.2", true),
+    case("<=2.1.0-alpha2", "1.2.3-beta.2", false),
+    case("<=2.1.0-alpha2", "1.2.3-beta.4", false),
+    case("<=2.1.0-alpha2", "1.2.3", true),
+    case("<=2.1.0-alpha2", "1.2.4-beta.2", false),
+    case("<=2.1.0-alpha2", "1.3.0", true),
+    case("<=2.1.0-alpha2", "2.0.0", true),
+    case("<=2.1.0-alpha2", "2.1.0-alpha1", true),
+    case("<=2.1.0-alpha2", "2.1.0-alpha2", true),
+    case("<=2.1.0-alpha2", "2.1.0", false),
+    case(">0.0.9, <=2.5.3", "0.0.2", false),
+    case(">0.0.9, <=2.5.3", "0.1.0", true),
+    case(">0.0.9, <=2.5.3", "0.1.0-beta2.a", false),
+    case(">0.0.9, <=2.5.3", "0.1.2", true),
+    case(">0.0.9, <=2.5.3", "0.1.3", true),
+    case(">0.0.9, <=2.5.3", "0.2.0", true),
+    case(">0.0.9, <=2.5.3", "0.5.1-alpha3", false),
+    case(">0.0.9, <=2.5.3", "0.5.1-beta", false),
+    case(">0.0.9, <=2.5.3", "0.5.1", true),
+    case(">0.0.9, <=2.5.3", "0.5.2-alpha3", false),
+    case(">0.0.9, <=2.5.3", "0.6.0", true),
+    case(">0.0.9, <=2.5.3", "0.9.9", true),
+    case(">0.0.9, <=2.5.3", "1.0.0-pre", false),
+    case(">0.0.9, <=2.5.3", "1.0.0-beta", false),
+    case(">0.0.9, <=2.5.3", "1.0.0", true),
+    case(">0.0.9, <=2.5.3", "1.0.1", true),
+    case(">0.0.9, <=2.5.3", "1.1.0", true),
+    case(">0.0.9, <=2.5.3", "1.1.2", true),
+    case(">0.0.9, <=2.5.3", "1.1.3", true),
+    case(">0.0.9, <=2.5.3", "1.2.0", true),
+    case(">0.0.9, <=2.5.3", "1.2.2", true),
+    case(">0.0.9, <=2.5.3", "1.2.3-beta.2", false),
+    case(">0.0.9, <=2.5.3", "1.2.3-beta.4", false),
+    case(">0.0.9, <=2.5.3", "1.2.3", true),
+    case(">0.0.9, <=2.5.3", "1.2.4-beta.2", false),
+    case(">0.0.9, <=2.5.3", "1.3.0", true),
+    case(">0.0.9, <=2.5.3", "2.0.0", true),
+    case(">0.0.9, <=2.5.3", "2.1.0-alpha1", false),
+    case(">0.0.9, <=2.5.3", "2.1.0-alpha2", false),
+    case(">0.0.9, <=2.5.3", "2.1.0", true),
+    case(">0.0.9, <=2.5.3", "2.5.3", true),
+    case(">0.0.9, <=2.5.3", "2.5.4", false),
+    case(">=0.5.1-alpha3, <0.6", "0.2.0", false),
+    case(">=0.5.1-alpha3, <0.6", "0.5.1-alpha3", true),
+    case(">=0.5.1-alpha3, <0.6", "0.5.1-beta", true),
+    case(">=0.5.1-alpha3, <0.6", "0.5.1", true),
+    case(">=0.5.1-alpha3, <0.6", "0.5.2-alpha3", false),
+    case("~1", "1.0.0-beta", false),
+    case("~1", "1.0.0", true),
+    case("~1", "1.0.1", true),
+    case("~1", "1.1.0", true),
+    case("~1", "1.1.2", true),
+    case("~1", "1.1.3", true),
+    case("~1", "1.2.0", true),
+    case("~1", "1.2.2", true),
+    case("~1", "1.2.3-beta.2", false),
+    case("~1", "1.2.3-beta.4", false),
+    case("~1", "1.2.3", true),
+    case("~1", "1.2.4-beta.2", false),
+    case("~1", "1.3.0", true),
+    case("~1", "2.0.0", false),
+    case("~1.2", "1.1.3", false),
+    case("~1.2", "1.2.0", true),
+    case("~1.2", "1.2.2", true),
+    case("~1.2", "1.2.3-beta.2", false),
+    case("~1.2", "1.2.3-beta.4", false),
+    case("~1.2", "1.2.3", true),
+    case("~1.2", "1.2.4-beta.2", false),
+    case("~1.2.2", "1.2.0", false),
+    case("~1.2.2", "1.2.2", true),
+    case("~1.2.2", "1.2.3-beta.2", false),
+    case("~1.2.2", "1.2.3-beta.4", false),
+    case("~1.2.2", "1.2.3", true),
+    case("~1.2.2", "1.2.4-beta.2", false),
+    case("~1.2.3-beta.2", "1.2.2", false),
+    case("~1.2.3-beta.2", "1.2.3-beta.2", true),
+    case("~1.2.3-beta.2", "1.2.3-beta.4", true),
+    case("~1.2.3-beta.2", "1.2.3", true),
+    case("~1.2.3-beta.2", "1.2.4-beta.2", false),
+    case("^1", "1.0.0-beta", false),
+    case("^1", "1.0.0", true),
+    case("^1", "1.0.1", true),
+    case("^1", "1.1.0", true),
+    case("^1", "1.1.2", true),
+    case("^1", "1.1.3", true),
+    case("^1", "1.2.0", true),
+    case("^1", "1.2.2", true),
+    case("^1", "1.2.3-beta.2", false),
+    case("^1", "1.2.3-beta.4", false),
+    case("^1", "1.2.3", true),
+    case("^1", "1.2.4-beta.2", false),
+    case("^1", "1.3.0", true),
+    case("^1", "2.0.0", false),
+    case("^1.1", "1.0.1", false),
+    case("^1.1", "1.1.0", true),
+    case("^1.1", "1.1.2", true),
+    case("^1.1", "1.1.3", true),
+    case("^1.1", "1.2.0", true),
+    case("^1.1", "1.2.2", true),
+    case("^1.1", "1.2.3-beta.2", false),
+    case("^1.1", "1.2.3-beta.4", false),
+    case("^1.1", "1.2.3", true),
+    case("^1.1", "1.2.4-beta.2", false),
+    case("^1.1", "1.3.0", true),
+    case("^1.1", "2.0.0", false),
+    case("^1.1.2", "1.1.0", false),
+    case("^1.1.2", "1.1.2", true),
+    case("^1.1.2", "1.1.3", true),
+    case("^1.1.2", "1.2.0", true),
+    case("^1.1.2", "1.2.2", true),
+    case("^1.1.2", "1.2.3-beta.2", false),
+    case("^1.1.2", "1.2.3-beta.4", false),
+    case("^1.1.2", "1.2.3", true),
+    case("^1.1.2", "1.2.4-beta.2", false),
+    case("^1.1.2", "1.3.0", true),
+    case("^1.1.2", "2.0.0", false),
+    case("^0.1.2", "0.1.0-beta2.a", false),
+    case("^0.1.2", "0.1.2", true),
+    case("^0.1.2", "0.1.3", true),
+    case("^0.1.2", "0.2.0", false),
+    case("^0.5.1-alpha3", "0.2.0", false),
+    case("^0.5.1-alpha3", "0.5.1-alpha3", true),
+    case("^0.5.1-alpha3", "0.5.1-beta", true),
+    case("^0.5.1-alpha3", "0.5.1", true),
+    case("^0.5.1-alpha3", "0.5.2-alpha3", false),
+    case("^0.0", "0.0.1", true),
+    case("^0.0", "0.0.2", true),
+    case("^0.0", "0.1.0", false),
+    case("^0.0.1", "0.0.1", true),
+    case("^0.0.1", "0.0.2", false),
+    case("1.*", "1.0.0-beta", false),
+    case("1.*", "1.0.0", true),
+    case("1.*", "1.0.1", true),
+    case("1.*", "1.1.0", true),
+    case("1.*", "1.1.2", true),
+    case("1.*", "1.1.3", true),
+    case("1.*", "1.2.0", true),
+    case("1.*", "1.2.2", true),
+    case("1.*", "1.2.3-beta.2", false),
+    case("1.*", "1.2.3-beta.4", false),
+    case("1.*", "1.2.3", true),
+    case("1.*", "1.2.4-beta.2", false),
+    case("1.*", "1.3.0", true),
+    case("1.*", "2.0.0", false),
+    case("1.2.*", "1.1.3", false),
+    case("1.2.*", "1.2.0", true),
+    case("1.2.*", "1.2.2", true),
+    case("1.2.*", "1.2.3-beta.2", false),
+    case("1.2.*", "1.2.3-beta.4", false),
+    case("1.2.*", "1.2.3", true),
+    case("1.2.*", "1.2.4-beta.2", false),
+    case("=1.2", "1.1.3", false),
+    case("=1.2", "1.2.0", true),
+    case("=1.2", "1.2.2", true),
+    case("=1.2", "1.2.3-beta.2", false),
+    case("=1.2", "1.2.3-beta.4", false),
+    case("=1.2", "1.2.3", true),
+    case("=1.2", "1.2.4-beta.2", false),
+    case(">1.2", "1.2.4-beta.2", false),
+    case(">1.2", "1.3.0", true),
+    case(">1.2", "2.0.0", true),
+    case(">1.2", "2.1.0-alpha1", false),
+    case(">1.2", "2.1.0-alpha2", false),
+    case(">1.2", "2.1.0", true),
+    case(">1.2", "2.5.3", true),
+    case(">1.2", "2.5.4", true),
+    case(">1.2", "3.0.0", true),
+    case("<=1.2", "0.0.1", true),
+    case("<=1.2", "0.0.2", true),
+    case("<=1.2", "0.1.0", true),
+    case("<=1.2", "0.1.0-beta2.a", false),
+    case("<=1.2", "0.1.2", true),
+    case("<=1.2", "0.1.3", true),
+    case("<=1.2", "0.2.0", true),
+    case("<=1.2", "0.5.1-alpha3", false),
+    case("<=1.2", "0.5.1-beta", false),
+    case("<=1.2", "0.5.1", true),
+    case("<=1.2", "0.5.2-alpha3", false),
+    case("<=1.2", "0.6.0", true),
+    case("<=1.2", "0.9.9", true),
+    case("<=1.2", "1.0.0-pre", false),
+    case("<=1.2", "1.0.0-beta", false),
+    case("<=1.2", "1.0.0", true),
+    case("<=1.2", "1.0.1", true),
+    case("<=1.2", "1.1.0", true),
+    case("<=1.2", "1.1.2", true),
+    case("<=1.2", "1.1.3", true),
+    case("<=1.2", "1.2.0", true),
+    case("<=1.2", "1.2.2", true),
+    case("<=1.2", "1.2.3-beta.2", false),
+    case("<=1.2", "1.2.3-beta.4", false),
+    case("<=1.2", "1.2.3", true),
+    case("<=1.2", "1.2.4-beta.2", false),
+    case("0.3.0, 0.4.0", "0.0.1", false),
+    case("0.3.0, 0.4.0", "3.0.0", false),
+];
+
+/// Requirements in npm's syntax and whether node-semver's `satisfies` matches
+/// them, as of node-semver 7.6.2.
+///
+/// This crate does not parse npm's syntax, which separates comparators by
+/// spaces, joins alternatives with `||`, and has hyphen ranges like `1.2.3 -
+/// 2.3.4`. Note that npm reads a bare version like `1.0.0` as an exact
+/// requirement, where Cargo reads it as a caret requirement. These cases are
+/// included for bindings that model both ecosystems.
+pub static NPM_MATCH_CASES: &[MatchCase] = &[
+    case("1.0.0", "1.0.0-beta", false),
+    case("1.0.0", "1.0.0", true),
+    case("1.0.0", "1.0.1", false),
+    case("1.2.3 - 2.3.4", "1.2.3-beta.4", false),
+    case("1.2.3 - 2.3.4", "1.2.3", true),
+    case("1.2.3 - 2.3.4", "1.2.4-beta.2", false),
+    case("1.2.3 - 2.3.4", "1.3.0", true),
+    case("1.2.3 - 2.3.4", "2.0.0", true),
+    case("1.2.3 - 2.3.4", "2.1.0-alpha1", false),
+    case("1.2.3 - 2.3.4", "2.1.0-alpha2", false),
+    case("1.2.3 - 2.3.4", "2.1.0", true),
+    case("1.2.3 - 2.3.4", "2.5.3", false),
+    case("1.2 - 2", "1.1.3", false),
+    case("1.2 - 2", "1.2.0", true),
+    case("1.2 - 2", "1.2.2", true),
+    case("1.2 - 2", "1.2.3-beta.2", false),
+    case("1.2 - 2", "1.2.3-beta.4", false),
+    case("1.2 - 2", "1.2.3", true),
+    case("1.2 - 2", "1.2.4-beta.2", false),
+    case("1.2 - 2", "1.3.0", true),
+    case("1.2 - 2", "2.0.0", true),
+    case("1.2 - 2", "2.1.0-alpha1", false),
+    case("1.2 - 2", "2.1.0-alpha2", false),
+    case("1.2 - 2", "2.1.0", true),
+    case("1.2 - 2", "2.5.3", true),
+    case("1.2 - 2", "2.5.4", true),
+    case("1.2 - 2", "3.0.0", false),
+    case("^1.2.3", "1.2.3-beta.4", false),
+    case("^1.2.3", "1.2.3", true),
+    case("^1.2.3", "1.2.4-beta.2", false),
+    case("^1.2.3", "1.3.0", true),
+    case("^1.2.3", "2.0.0", false),
+    case("^0.1.2", "0.1.0-beta2.a", false),
+    case("^0.1.2", "0.1.2", true),
+    case("^0.1.2", "0.1.3", true),
+    case("^0.1.2", "0.2.0", false),
+    case("^0.0.1", "0.0.1", true),
+    case("^0.0.1", "0.0.2", false),
+    case("~1.2", "1.1.3", false),
+    case("~1.2", "1.2.0", true),
+    case("~1.2", "1.2.2", true),
+    case("~1.2", "1.2.3-beta.2", false),
+    case("~1.2", "1.2.3-beta.4", false),
+    case("~1.2", "1.2.3", true),
+    case("~1.2", "1.2.4-beta.2", false),
+    case("~1.2.3-beta.2", "1.2.2", false),
+    case("~1.2.3-beta.2", "1.2.3-beta.2", true),
+    case("~1.2.3-beta.2", "1.2.3-beta.4", true),
+    case("~1.2.3-beta.2", "1.2.3", true),
+    case("~1.2.3-beta.2", "1.2.4-beta.2", false),
+    case("1.x", "1.0.0-beta", false),
+    case("1.x", "1.0.0", true),
+    case("1.x", "1.0.1", true),
+    case("1.x", "1.1.0", true),
+    case("1.x", "1.1.2", true),
+    case("1.x", "1.1.3", true),
+    case("1.x", "1.2.0", true),
+    case("1.x", "1.2.2", true),
+    case("1.x", "1.2.3-beta.2", false),
+    case("1.x", "1.2.3-beta.4", false),
+    case("1.x", "1.2.3", true),
+    case("1.x", "1.2.4-beta.2", false),
+    case("1.x", "1.3.0", true),
+    case("1.x", "2.0.0", false),
+    case("1.2.x", "1.1.3", false),
+    case("1.2.x", "1.2.0", true),
+    case("1.2.x", "1.2.2", true),
+    case("1.2.x", "1.2.3-beta.2", false),
+    case("1.2.x", "1.2.3-beta.4", false),
+    case("1.2.x", "1.2.3", true),
+    case("1.2.x", "1.2.4-beta.2", false),
+    case("*", "0.0.1", true),
+    case("*", "0.0.2", true),
+    case("*", "0.1.0", true),
+    case("*", "0.1.0-beta2.a", false),
+    case("*", "0.1.2", true),
+    case("*", "0.1.3", true),
+    case("*", "0.2.0", true),
+    case("*", "0.5.1-alpha3", false),
+    case("*", "0.5.1-beta", false),
+    case("*", "0.5.1", true),
+    case("*", "0.5.2-alpha3", false),
+    case("*", "0.6.0", true),
+    case("*", "0.9.9", true),
+    case("*", "1.0.0-pre", false),
+    case("*", "1.0.0-beta", false),
+    case("*", "1.0.0", true),
+    case("*", "1.0.1", true),
+    case("*", "1.1.0", true),
+    case("*", "1.1.2", true),
+    case("*", "1.1.3", true),
+    case("*", "1.2.0", true),
+    case("*", "1.2.2", true),
+    case("*", "1.2.3-beta.2", false),
+    case("*", "1.2.3-beta.4", false),
+    case("*", "1.2.3", true),
+    case("*", "1.2.4-beta.2", false),
+    case("*", "1.3.0", true),
+    case("*", "2.0.0", true),
+    case("*", "2.1.0-alpha1", false),
+    case("*", "2.1.0-alpha2", false),
+    case("*", "2.1.0", true),
+    case("*", "2.5.3", true),
+    case("*", "2.5.4", true),
+    case("*", "3.0.0", true),
+    case(">=1.2.3 <2", "1.2.3-beta.4", false),
+    case(">=1.2.3 <2", "1.2.3", true),
+    case(">=1.2.3 <2", "1.2.4-beta.2", false),
+    case(">=1.2.3 <2", "1.3.0", true),
+    case(">=1.2.3 <2", "2.0.0", false),
+    case(">1.0.0-alpha <1.0.0", "0.9.9", false),
+    case(">1.0.0-alpha <1.0.0", "1.0.0-pre", true),
+    case(">1.0.0-alpha <1.0.0", "1.0.0-beta", true),
+    case(">1.0.0-alpha <1.0.0", "1.0.0", false),
+    case("<=2.1.0-alpha2", "0.0.1", true),
+    case("<=2.1.0-alpha2", "0.0.2", true),
+    case("<=2.1.0-alpha2", "0.1.0", true),
+    case("<=2.1.0-alpha2", "0.1.0-beta2.a", false),
+    case("<=2.1.0-alpha2", "0.1.2", true),
+    case("<=2.1.0-alpha2", "0.1.3", true),
+    case("<=2.1.0-alpha2", "0.2.0", true),
+    case("<=2.1.0-alpha2", "0.5.1-alpha3", false),
+    case("<=2.1.0-alpha2", "0.5.1-beta", false),
+    case("<=2.1.0-alpha2", "0.5.1", true),
+    case("<=2.1.0-alpha2", "0.5.2-alpha3", false),
+    case("<=2.1.0-alpha2", "0.6.0", true),
+    case("<=2.1.0-alpha2", "0.9.9", true),
+    case("<=2.1.0-alpha2", "1.0.0-pre", false),
+    case("<=2.1.0-alpha2", "1.0.0-beta", false),
+    case("<=2.1.0-alpha2", "1.0.0", true),
+    case("<=2.1.0-alpha2", "1.0.1", true),
+    case("<=2.1.0-alpha2", "1.1.0", true),
+    case("<=2.1.0-alpha2", "1.1.2", true),
+    case("<=2.1.0-alpha2", "1.1.3", true),
+    case("<=2.1.0-alpha2", "1.2.0", true),
+    case("<=2.1.0-alpha2", "1.2.2", true),
+    case("<=2.1.0-alpha2", "1.2.3-beta.2", false),
+    case("<=2.1.0-alpha2", "1.2.3-beta.4", false),
+    case("<=2.1.0-alpha2", "1.2.3", true),
+    case("<=2.1.0-alpha2", "1.2.4-beta.2", false),
+    case("<=2.1.0-alpha2", "1.3.0", true),
+    case("<=2.1.0-alpha2", "2.0.0", true),
+    case("<=2.1.0-alpha2", "2.1.0-alpha1", true),
+    case("<=2.1.0-alpha2", "2.1.0-alpha2", true),
+    case("<=2.1.0-alpha2", "2.1.0", false),
+    case("0.1.x || 1.1.x", "0.0.2", false),
+    case("0.1.x || 1.1.x", "0.1.0", true),
+    case("0.1.x || 1.1.x", "0.1.0-beta2.a", false),
+    case("0.1.x || 1.1.x", "0.1.2", true),
+    case("0.1.x || 1.1.x", "0.1.3", true),
+    case("0.1.x || 1.1.x", "0.2.0", false),
+    case("0.1.x || 1.1.x", "1.0.1", false),
+    case("0.1.x || 1.1.x", "1.1.0", true),
+    case("0.1.x || 1.1.x", "1.1.2", true),
+    case("0.1.x || 1.1.x", "1.1.3", true),
+    case("0.1.x || 1.1.x", "1.2.0", false),
+    case("^0.5.1-alpha3 || >=2.5.3", "0.2.0", false),
+    case("^0.5.1-alpha3 || >=2.5.3", "0.5.1-alpha3", true),
+    case("^0.5.1-alpha3 || >=2.5.3", "0.5.1-beta", true),
+    case("^0.5.1-alpha3 || >=2.5.3", "0.5.1", true),
+    case("^0.5.1-alpha3 || >=2.5.3", "0.5.2-alpha3", false),
+    case("^0.5.1-alpha3 || >=2.5.3", "2.1.0", false),
+    case("^0.5.1-alpha3 || >=2.5.3", "2.5.3", true),
+    case("^0.5.1-alpha3 || >=2.5.3", "2.5.4", true),
+    case("^0.5.1-alpha3 || >=2.5.3", "3.0.0", true),
+    case("v1.2.3", "1.2.3-beta.4", false),
+    case("v1.2.3", "1.2.3", true),
+    case("v1.2.3", "1.2.4-beta.2", false),
+    case("=1.1.2", "1.1.0", false),
+    case("=1.1.2", "1.1.2", true),
+    case("=1.1.2", "1.1.3", false),
+];
+
+/// Pairs of versions and how they compare, including the examples of
+/// precedence in the SemVer spec.
+pub static ORDERING_CASES: &[OrderingCase] = &[
+    ordering("1.0.0", "2.0.0", Less, Less),
+    ordering("2.0.0", "2.1.0", Less, Less),
+    ordering("2.1.0", "2.1.1", Less, Less),
+    ordering("1.5.0", "1.19.0", Less, Less),
+    ordering("1.0.0-alpha", "1.0.0", Less, Less),
+    ordering("1.0.0-alpha", "1.0.0-alpha.1", Less, Less),
+    ordering("1.0.0-alpha.1", "1.0.0-alpha.beta", Less, Less),
+    ordering("1.0.0-alpha.beta", "1.0.0-beta", Less, Less),
+    ordering("1.0.0-beta", "1.0.0-beta.2", Less, Less),
+    ordering("1.0.0-beta.2", "1.0.0-beta.11", Less, Less),
+    ordering("1.0.0-beta.11", "1.0.0-rc.1", Less, Less),
+    ordering("1.0.0-rc.1", "1.0.0", Less, Less),
+    ordering("1.0.0-pre.8", "1.0.0-pre.12", Less, Less),
+    ordering("1.0.0-pre12", "1.0.0-pre8", Less, Less),
+    ordering("1.0.0-pre.1", "1.0.0-pre.x", Less, Less),
+    ordering("1.0.0-alpha.0a", "1.0.0-alpha.1a0", Less, Less),
+    ordering("1.0.0-alpha.200", "1.0.0-alpha.0a", Less, Less),
+    ordering("1.0.0-0", "1.0.0-A", Less, Less),
+    ordering("1.0.0-A", "1.0.0-a", Less, Less),
+    ordering("1.0.0-alpha.1", "1.0.0-alpha.01a", Less, Less),
+    ordering("0.9.9", "1.0.0-0", Less, Less),
+    ordering("1.0.0", "1.0.0", Equal, Equal),
+    ordering("1.0.0-rc.1", "1.0.0-rc.1", Equal, Equal),
+    ordering("1.0.0", "1.0.0+build", Less, Equal),
+    ordering("1.0.0+build", "1.0.0+build.1", Less, Equal),
+    ordering("1.0.0+build.2", "1.0.0+build.10", Less, Equal),
+    ordering("1.0.0+b", "1.0.0+a.1", Greater, Equal),
+    ordering("1.0.0-rc.1+build.5", "1.0.0-rc.1", Greater, Equal),
+    ordering("1.0.0+0", "1.0.0+00", Less, Equal),
+    ordering("2.0.0", "1.99.99", Greater, Greater),
+    ordering("1.0.0-beta", "1.0.0-alpha", Greater, Greater),
+];
+
+const fn case(req: &'static str, version: &'static str, matches: bool) -> MatchCase {
+    MatchCase {
+        req,
+        version,
+        matches,
+    }
+}
+
+const fn ordering(
+    lhs: &'static str,
+    rhs: &'static str,
+    ordering: Ordering,
+    precedence: Ordering,
+) -> OrderingCase {
+    OrderingCase {
+        lhs,
+        rhs,
+        ordering,
+        precedence,
+    }
+}
@@ -0,0 +1,164 @@
+use crate::parser::Span;
+
+/// What a region of a version or requirement string is, as classified by
+/// [`tokenize`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TokenKind {
+    /// A comparison operator: `=`, `>`, `>=`, `<`, `<=`, `~`, `^`.
+    Operator,
+    /// A major, minor or patch number.
+    Number,
+    /// A `.` between numbers or between the identifiers of a pre-release or
+    /// build metadata.
+    Dot,
+    /// A wildcard in place of a number: `*`, `x`, `X`.
+    Wildcard,
+    /// The `-` introducing a pre-release.
+    Hyphen,
+    /// One dot-separated identifier of a pre-release, like `rc` or `1` in
+    /// `rc.1`.
+    Prerelease,
+    /// The `+` introducing build metadata.
+    Plus,
+    /// One dot-separated identifier of build metadata.
+    Build,
+    /// The `,` between comparators.
+    Separator,
+    /// A run of spaces.
+    Whitespace,
+    /// A character that has no place in a version or requirement.
+    Invalid,
+}
+
+/// A classified region of the input to [`tokenize`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SpannedToken {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+/// Iterator over the tokens of a version or requirement string.
+///
+/// Produced by [`tokenize`].
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+    input: &'a str,
+    pos: usize,
+    state: State,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum State {
+    Core,
+    Pre,
+    Build,
+}
+
+/// Split a version or requirement string into classified tokens, for syntax
+/// highlighting.
+///
+/// Tokenizing never fails. The tokens cover the whole input without gaps, and
+/// anything out of place, or that cannot be part of a version or requirement
+/// at all, is classified as well as it can be or as
+/// [`TokenKind::Invalid`]. This makes it suitable for highlighting input that
+/// is still being typed. Whether the input is valid is up to
+/// [`Version::parse`][crate::Version::parse] or
+/// [`VersionReq::parse`][crate::VersionReq::parse] to decide.
+///
+/// # Example
+///
+/// ```
+/// use semver::TokenKind;
+///
+/// let input = ">=1.2.0-rc.1, <2";
+/// let kinds: Vec<TokenKind> = semver::tokenize(input).map(|token| token.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     [
+///         TokenKind::Operator,
+///         TokenKind::Number,
+///         TokenKind::Dot,
+///         TokenKind::Number,
+///         TokenKind::Dot,
+///         TokenKind::Number,
+///         TokenKind::Hyphen,
+///         TokenKind::Prerelease,
+///         TokenKind::Dot,
+///         TokenKind::Prerelease,
+///         TokenKind::Separator,
+///         TokenKind::Whitespace,
+///         TokenKind::Operator,
+///         TokenKind::Number,
+///     ],
+/// );
+/// ```
+pub fn tokenize(input: &str) -> Tokens<'_> {
+    Tokens {
+        input,
+        pos: 0,
+        state: State::Core,
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = SpannedToken;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.pos..];
+        let first = *rest.as_bytes().first()?;
+        let identifier_len = rest
+            .bytes()
+            .take_while(|&b| b.is_ascii_alphanumeric() || b == b'-')
+            .count();
+
+        let (kind, len) = match (self.state, first) {
+            (_, b' ') => {
+                self.state = State::Core;
+                (
+                    TokenKind::Whitespace,
+                    rest.bytes().take_while(|&b| b == b' ').count(),
+                )
+            }
+            (_, b',') => {
+                self.state = State::Core;
+                (TokenKind::Separator, 1)
+            }
+            (_, b'.') => (TokenKind::Dot, 1),
+            (State::Core, b'>') | (State::Core, b'<') if rest[1..].starts_with('=') => {
+                (TokenKind::Operator, 2)
+            }
+            (State::Core, b'=') | (State::Core, b'>') | (State::Core, b'<') => {
+                (TokenKind::Operator, 1)
+            }
+            (State::Core, b'~') | (State::Core, b'^') => (TokenKind::Operator, 1),
+            (State::Core, b'0'..=b'9') => {
+                let len = rest.bytes().take_while(u8::is_ascii_digit).count();
+                (TokenKind::Number, len)
+            }
+            (State::Core, b'*') | (State::Core, b'x') | (State::Core, b'X') => {
+                (TokenKind::Wildcard, 1)
+            }
+            (State::Core, b'-') => {
+                self.state = State::Pre;
+                (TokenKind::Hyphen, 1)
+            }
+            (State::Core, b'+') | (State::Pre, b'+') => {
+                self.state = State::Build;
+                (TokenKind::Plus, 1)
+            }
+            (State::Pre, _) if identifier_len > 0 => (TokenKind::Prerelease, identifier_len),
+            (State::Build, _) if identifier_len > 0 => (TokenKind::Build, identifier_len),
+            _ => (TokenKind::Invalid, rest.chars().next().unwrap().len_utf8()),
+        };
+
+        let start = self.pos;
+        self.pos += len;
+        Some(SpannedToken {
+            kind,
+            span: Span {
+                start,
+                end: self.pos,
+            },
+        })
+    }
+}
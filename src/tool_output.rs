@@ -0,0 +1,104 @@
+#[allow(unused_imports)]
+use crate::backport::*;
+use crate::{BuildMetadata, Prerelease, Version};
+
+/// Find the version in the output of a tool's `--version` flag.
+///
+/// The first token that looks like a version number with at least a major and
+/// minor component is taken, scanning line by line. Around it:
+///
+/// - a letter prefix is stripped, as in `v20.11.1` (node) or `go1.22.0`;
+///
+/// - missing components are filled in with zero, so `3.28` becomes `3.28.0`,
+///   and components beyond the third are dropped, so `2.45.1.windows.1` from
+///   git for Windows becomes `2.45.1`;
+///
+/// - a pre-release and build metadata are kept if they are valid SemVer, as
+///   in `1.2.3-rc.1+b5`, and a pre-release written directly after the patch
+///   number, as in `3.13.0rc1` (python), becomes `3.13.0-rc1`.
+///
+/// Returns `None` if no line contains anything resembling a version.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let found = semver::from_tool_output("git version 2.39.3 (Apple Git-145)\n");
+/// assert_eq!(found, Some(Version::new(2, 39, 3)));
+///
+/// let found = semver::from_tool_output("Python 3.13.0rc1");
+/// assert_eq!(found, Some(Version::parse("3.13.0-rc1").unwrap()));
+/// ```
+pub fn from_tool_output(output: &str) -> Option<Version> {
+    output
+        .lines()
+        .flat_map(str::split_whitespace)
+        .find_map(coerce)
+}
+
+fn coerce(token: &str) -> Option<Version> {
+    let token = token.trim_matches(|ch| match ch {
+        '(' | ')' | '[' | ']' | ',' | ';' | ':' | '"' | '\'' => true,
+        _ => false,
+    });
+    let token = token.trim_start_matches(|ch: char| ch.is_ascii_alphabetic());
+
+    let mut numbers = [0u64; 3];
+    let mut count = 0;
+    let mut rest = token;
+    while count < 3 {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            break;
+        }
+        numbers[count] = rest[..digits].parse().ok()?;
+        count += 1;
+        rest = &rest[digits..];
+        if count < 3 {
+            match rest.strip_prefix('.') {
+                Some(after_dot) if after_dot.starts_with(|ch: char| ch.is_ascii_digit()) => {
+                    rest = after_dot;
+                }
+                _ => break,
+            }
+        }
+    }
+    if count < 2 {
+        return None;
+    }
+
+    let mut version = Version::new(numbers[0], numbers[1], numbers[2]);
+    if count == 3 {
+        let (pre, build) = suffix(rest);
+        version.pre = pre;
+        version.build = build;
+    }
+    Some(version)
+}
+
+fn suffix(rest: &str) -> (Prerelease, BuildMetadata) {
+    let len = rest
+        .bytes()
+        .take_while(|&b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-' || b == b'+')
+        .count();
+    let rest = &rest[..len];
+
+    let (pre, build) = match rest.find('+') {
+        Some(plus) => (&rest[..plus], &rest[plus + 1..]),
+        None => (rest, ""),
+    };
+    let pre = match pre.strip_prefix('-') {
+        Some(pre) => Prerelease::new(pre).ok(),
+        None if pre.starts_with(|ch: char| ch.is_ascii_alphabetic()) => Prerelease::new(pre).ok(),
+        None if pre.is_empty() => Some(Prerelease::EMPTY),
+        None => None,
+    };
+    match pre {
+        Some(pre) => {
+            let build = BuildMetadata::new(build).unwrap_or(BuildMetadata::EMPTY);
+            (pre, build)
+        }
+        None => (Prerelease::EMPTY, BuildMetadata::EMPTY),
+    }
+}
@@ -0,0 +1,139 @@
+use crate::backport::*;
+use crate::error::ErrorKind;
+use crate::{Error, Version};
+
+/// Release channel of a Rust toolchain.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RustChannel {
+    Stable,
+    Beta,
+    Nightly,
+    /// A compiler built from source outside of the release process, whose
+    /// version has a `-dev` pre-release.
+    Dev,
+}
+
+/// A Rust toolchain as described by `rustc --version` or
+/// `rustc --version --verbose`.
+///
+/// Produced by [`rust_toolchain`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RustToolchain {
+    /// The compiler version, such as `1.78.0-nightly`.
+    pub version: Version,
+    pub channel: RustChannel,
+    /// The commit the compiler was built from. This is the full hash if the
+    /// verbose output was given, and otherwise the abbreviated one from the
+    /// first line.
+    pub commit_hash: Option<String>,
+    /// The date of that commit, as `YYYY-MM-DD`.
+    pub commit_date: Option<String>,
+    /// The host target triple, available only from the verbose output.
+    pub host: Option<String>,
+}
+
+/// Parse the output of `rustc --version` or `rustc --version --verbose`.
+///
+/// The leading `rustc ` is optional, so the version string alone, as in
+/// `1.78.0-nightly (9c3ad802d 2024-03-07)`, is accepted too. Distributors'
+/// annotations after the commit information, such as `(Homebrew)`, are
+/// ignored. Lines of the verbose output other than `commit-hash`,
+/// `commit-date` and `host` are ignored.
+///
+/// # Errors
+///
+/// The version must be a valid SemVer version, and its pre-release must be
+/// empty or name one of the Rust release channels: `beta`, `beta.N`,
+/// `nightly`, or `dev`.
+///
+/// # Example
+///
+/// ```
+/// use semver::RustChannel;
+///
+/// let toolchain = semver::rust_toolchain("rustc 1.78.0-nightly (9c3ad802d 2024-03-07)").unwrap();
+/// assert_eq!(toolchain.version.to_string(), "1.78.0-nightly");
+/// assert_eq!(toolchain.channel, RustChannel::Nightly);
+/// assert_eq!(toolchain.commit_hash.as_ref().map(String::as_str), Some("9c3ad802d"));
+/// assert_eq!(toolchain.commit_date.as_ref().map(String::as_str), Some("2024-03-07"));
+/// ```
+pub fn rust_toolchain(text: &str) -> Result<RustToolchain, Error> {
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or("").trim();
+    let first = first.strip_prefix("rustc ").unwrap_or(first).trim_start();
+
+    let (version, rest) = match first.find(' ') {
+        Some(space) => (&first[..space], first[space..].trim_start()),
+        None => (first, ""),
+    };
+    let version = Version::parse(version)?;
+    let channel = channel(&version)?;
+
+    let mut commit_hash = None;
+    let mut commit_date = None;
+    if let Some(rest) = rest.strip_prefix('(') {
+        if let Some(end) = rest.find(')') {
+            let mut words = rest[..end].split(' ');
+            if let (Some(hash), Some(date), None) = (words.next(), words.next(), words.next()) {
+                if is_commit_hash(hash) && is_date(date) {
+                    commit_hash = Some(String::from(hash));
+                    commit_date = Some(String::from(date));
+                }
+            }
+        }
+    }
+
+    let mut host = None;
+    for line in lines {
+        let mut parts = line.splitn(2, ':');
+        let key = parts.next().unwrap_or("").trim();
+        let value = parts.next().unwrap_or("").trim();
+        match key {
+            "commit-hash" if is_commit_hash(value) => commit_hash = Some(String::from(value)),
+            "commit-date" if is_date(value) => commit_date = Some(String::from(value)),
+            "host" if !value.is_empty() => host = Some(String::from(value)),
+            _ => {}
+        }
+    }
+
+    Ok(RustToolchain {
+        version,
+        channel,
+        commit_hash,
+        commit_date,
+        host,
+    })
+}
+
+fn channel(version: &Version) -> Result<RustChannel, Error> {
+    let pre = version.pre.as_str();
+    if pre.is_empty() {
+        return Ok(RustChannel::Stable);
+    }
+    if pre == "beta" {
+        return Ok(RustChannel::Beta);
+    }
+    if let Some(n) = pre.strip_prefix("beta.") {
+        if !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(RustChannel::Beta);
+        }
+    }
+    match pre {
+        "nightly" => Ok(RustChannel::Nightly),
+        "dev" => Ok(RustChannel::Dev),
+        _ => Err(Error::new(ErrorKind::UnknownRustChannel)),
+    }
+}
+
+fn is_commit_hash(string: &str) -> bool {
+    string.len() >= 7 && string.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn is_date(string: &str) -> bool {
+    let bytes = string.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
@@ -0,0 +1,49 @@
+use crate::alloc::borrow::Cow;
+use crate::{Version, VersionReq};
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+// The regular expression recommended by the SemVer 2.0.0 specification.
+const VERSION_PATTERN: &str = r"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-((?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$";
+
+/// A version such as `1.4.2-rc.1`, as an OpenAPI string schema with format
+/// `semver`.
+impl PartialSchema for Version {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::Custom("semver".into())))
+            .pattern(Some(VERSION_PATTERN))
+            .description(Some("A semantic version, as in https://semver.org"))
+            .examples(["1.4.2", "1.0.0-rc.1", "0.3.0+build.5"])
+            .into()
+    }
+}
+
+impl ToSchema for Version {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Version")
+    }
+}
+
+/// A version requirement such as `>=1.2.3, <2`, as an OpenAPI string schema
+/// with format `semver-req`.
+impl PartialSchema for VersionReq {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .format(Some(SchemaFormat::Custom("semver-req".into())))
+            .description(Some(
+                "A version requirement in Cargo's syntax, such as `^1.2` or `>=1.2.3, <2`",
+            ))
+            .examples(["^1.2", ">=1.2.3, <2", "~0.4.1", "*"])
+            .into()
+    }
+}
+
+impl ToSchema for VersionReq {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("VersionReq")
+    }
+}
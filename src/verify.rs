@@ -0,0 +1,73 @@
+use crate::range_set::{self, Bound};
+use crate::{Comparator, Op, Version, VersionReq};
+use core::cmp::Ordering;
+
+// Panic unless the reference evaluation agrees with `result`, the outcome of
+// VersionReq::matches.
+pub(crate) fn check(req: &VersionReq, version: &Version, result: bool) {
+    let expected = reference(req, version);
+    if result != expected {
+        panic!(
+            "semver verify: `{}` {} {}, but the reference evaluation says it {}",
+            req,
+            if result { "matches" } else { "does not match" },
+            version,
+            if expected { "does" } else { "does not" },
+        );
+    }
+}
+
+// The slow, obviously correct evaluation, written independently of eval.rs: a
+// version matches if it lies within the interval of every comparator, and a
+// pre-release only if some comparator names a pre-release of the same
+// major.minor.patch.
+fn reference(req: &VersionReq, version: &Version) -> bool {
+    if !req.comparators.iter().all(|cmp| within(cmp, version)) {
+        return false;
+    }
+    version.pre.is_empty()
+        || req.comparators.iter().any(|cmp| {
+            !cmp.pre.is_empty()
+                && (cmp.major, cmp.minor, cmp.patch)
+                    == (version.major, Some(version.minor), Some(version.patch))
+        })
+}
+
+fn within(cmp: &Comparator, version: &Version) -> bool {
+    // A comparator without a patch version only looks at the components it
+    // has. A pre-release agreeing with all of them is matched by a caret and
+    // by nothing else, since `=1.2`, `>=1.2` and the like are written as if
+    // the missing components were those of a release. Any other pre-release
+    // is on the same side of each bound as its release.
+    if cmp.patch.is_none() && !version.pre.is_empty() {
+        if cmp.major == version.major && cmp.minor.map_or(true, |minor| minor == version.minor) {
+            return cmp.op == Op::Caret;
+        }
+        let release = Version::new(version.major, version.minor, version.patch);
+        return within(cmp, &release);
+    }
+    let range = range_set::comparator_range(cmp);
+    above(version, &range.lower) && below(version, &range.upper)
+}
+
+fn above(version: &Version, lower: &Option<Bound>) -> bool {
+    match lower {
+        None => true,
+        Some(bound) => match version.cmp_precedence(&bound.version) {
+            Ordering::Greater => true,
+            Ordering::Equal => bound.inclusive,
+            Ordering::Less => false,
+        },
+    }
+}
+
+fn below(version: &Version, upper: &Option<Bound>) -> bool {
+    match upper {
+        None => true,
+        Some(bound) => match version.cmp_precedence(&bound.version) {
+            Ordering::Less => true,
+            Ordering::Equal => bound.inclusive,
+            Ordering::Greater => false,
+        },
+    }
+}
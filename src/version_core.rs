@@ -0,0 +1,63 @@
+use crate::{Comparator, Version};
+
+/// The major, minor and patch numbers of a version, without pre-release or
+/// build metadata.
+///
+/// Unlike [`Version`], this is `Copy` and never allocates, which makes it a
+/// lightweight key for maps and for messages in protocols where pre-release
+/// and build metadata never appear. It is ordered by major, then minor, then
+/// patch, which is the same as the precedence of the corresponding release
+/// versions.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Comparator, Version, VersionCore};
+///
+/// let version = Version::parse("1.2.3-rc.1+g8e6aa2a").unwrap();
+/// let core = VersionCore::from(&version);
+/// assert_eq!(core.to_string(), "1.2.3");
+///
+/// assert!(core.matches(&Comparator::parse("^1.2").unwrap()));
+/// assert!(core > VersionCore::new(1, 2, 2));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug)]
+pub struct VersionCore {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl VersionCore {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        VersionCore {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Evaluate whether the release version with these major, minor and patch
+    /// numbers satisfies the comparator.
+    pub fn matches(&self, cmp: &Comparator) -> bool {
+        cmp.matches(&Version::from(*self))
+    }
+}
+
+impl From<&Version> for VersionCore {
+    fn from(version: &Version) -> Self {
+        VersionCore::new(version.major, version.minor, version.patch)
+    }
+}
+
+impl From<Version> for VersionCore {
+    fn from(version: Version) -> Self {
+        VersionCore::from(&version)
+    }
+}
+
+impl From<VersionCore> for Version {
+    fn from(core: VersionCore) -> Self {
+        Version::new(core.major, core.minor, core.patch)
+    }
+}
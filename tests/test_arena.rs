@@ -0,0 +1,58 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::ArenaVersion;
+use std::cell::RefCell;
+
+#[test]
+fn test_parse() {
+    let arena = RefCell::new(Vec::new());
+    let mut alloc = |s: &str| -> &'static str {
+        arena.borrow_mut().push(s.to_owned());
+        Box::leak(Box::from(s))
+    };
+
+    for text in &[
+        "1.2.3",
+        "1.2.3-alpha.1",
+        "1.2.3+build.5",
+        "1.2.3-rc.1+g8e6aa2a",
+    ] {
+        let parsed = ArenaVersion::parse(text, &mut alloc).unwrap();
+        assert_eq!(parsed.to_version(), version(text));
+        assert_eq!(parsed.to_string(), *text);
+    }
+    assert_eq!(*arena.borrow(), ["alpha.1", "build.5", "rc.1", "g8e6aa2a"]);
+
+    let parsed = ArenaVersion::parse("0.1.0-beta", &mut alloc).unwrap();
+    assert_eq!(parsed.major, 0);
+    assert_eq!(parsed.minor, 1);
+    assert_eq!(parsed.patch, 0);
+    assert_eq!(parsed.pre(), "beta");
+    assert_eq!(parsed.build(), "");
+}
+
+#[test]
+fn test_errors() {
+    let alloc = |_: &str| -> &'static str { unreachable!() };
+    let err = ArenaVersion::parse("1.2.3-01", alloc).unwrap_err();
+    assert_to_string(err, "invalid leading zero in pre-release identifier");
+
+    let err = ArenaVersion::parse("1.2", alloc).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected end of input while parsing minor version number",
+    );
+}
+
+#[test]
+fn test_arena_mismatch() {
+    let alloc = |_: &str| -> &'static str { "other" };
+    let err = ArenaVersion::parse("1.2.3-rc.1", alloc).unwrap_err();
+    assert_to_string(err, "arena returned a different string than it was given");
+
+    let parsed = ArenaVersion::parse("1.2.3", alloc).unwrap();
+    assert_eq!(parsed.pre(), "");
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "arrow")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use arrow::array::{Array, ArrayRef, StringArray, StructArray, UInt64Array};
+use arrow::compute::{lexsort_to_indices, SortColumn, SortOptions};
+use arrow::datatypes::{DataType, Field};
+use semver::{arrow_fields, versions_from_arrow, versions_to_arrow};
+use std::sync::Arc;
+
+#[test]
+fn test_roundtrip() {
+    let versions = vec![
+        version("1.0.0"),
+        version("1.0.0-rc.1"),
+        version("0.9.0+build.5"),
+        version("2.0.0-alpha+sha.abc"),
+    ];
+    let array = versions_to_arrow(&versions);
+    assert_eq!(array.fields(), &arrow_fields());
+
+    let pre = array.column_by_name("pre").unwrap();
+    assert_eq!(pre.null_count(), 2);
+    assert!(pre.is_null(0));
+
+    assert_eq!(versions_from_arrow(&array).unwrap(), versions);
+}
+
+#[test]
+fn test_sort() {
+    let versions = vec![
+        version("1.0.0"),
+        version("1.0.0-rc.1"),
+        version("0.9.0"),
+        version("1.0.0-beta"),
+    ];
+    let array = versions_to_arrow(&versions);
+    let columns = ["major", "minor", "patch", "pre"]
+        .iter()
+        .map(|name| SortColumn {
+            values: array.column_by_name(name).unwrap().clone(),
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: false,
+            }),
+        })
+        .collect::<Vec<_>>();
+    let indices = lexsort_to_indices(&columns, None).unwrap();
+    assert_eq!(indices.values(), &[2, 3, 1, 0]);
+}
+
+#[test]
+fn test_invalid() {
+    let major: ArrayRef = Arc::new(UInt64Array::from(vec![1]));
+    let pre: ArrayRef = Arc::new(StringArray::from(vec![Some("rc..1")]));
+    let array = StructArray::from(vec![
+        (
+            Arc::new(Field::new("major", DataType::UInt64, false)),
+            major.clone(),
+        ),
+        (
+            Arc::new(Field::new("minor", DataType::UInt64, false)),
+            major.clone(),
+        ),
+        (
+            Arc::new(Field::new("patch", DataType::UInt64, false)),
+            major.clone(),
+        ),
+        (
+            Arc::new(Field::new("pre", DataType::Utf8, true)),
+            pre.clone(),
+        ),
+        (
+            Arc::new(Field::new("build", DataType::Utf8, true)),
+            pre.clone(),
+        ),
+    ]);
+    let err = versions_from_arrow(&array).unwrap_err();
+    assert_to_string(err, "empty identifier segment in pre-release identifier");
+
+    let array = StructArray::from(vec![
+        (
+            Arc::new(Field::new("major", DataType::UInt64, false)),
+            major.clone(),
+        ),
+        (
+            Arc::new(Field::new("minor", DataType::Utf8, true)),
+            pre.clone(),
+        ),
+    ]);
+    let err = versions_from_arrow(&array).unwrap_err();
+    assert_to_string(
+        err,
+        "Arrow array does not have the layout of a version column",
+    );
+}
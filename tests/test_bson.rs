@@ -0,0 +1,92 @@
+#![cfg(feature = "bson")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use bson::{doc, Bson};
+use semver::Version;
+use std::convert::TryFrom;
+
+#[test]
+fn test_string() {
+    let bson = Bson::from(version("1.4.2-rc.1+build.5"));
+    assert_eq!(bson, Bson::String("1.4.2-rc.1+build.5".to_owned()));
+    assert_eq!(
+        Version::try_from(bson).unwrap(),
+        version("1.4.2-rc.1+build.5"),
+    );
+
+    let err = Version::try_from(Bson::String("1.4".to_owned())).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected end of input while parsing minor version number",
+    );
+}
+
+#[test]
+fn test_document() {
+    let bson = Bson::Document(doc! { "major": 1, "minor": 4_i64, "patch": 2 });
+    assert_eq!(Version::try_from(&bson).unwrap(), version("1.4.2"));
+
+    let bson = Bson::Document(doc! {
+        "major": 1,
+        "minor": 4,
+        "patch": 2,
+        "pre": "rc.1",
+        "build": "build.5",
+    });
+    assert_eq!(
+        Version::try_from(&bson).unwrap(),
+        version("1.4.2-rc.1+build.5")
+    );
+
+    let bson = Bson::Document(doc! { "major": 1, "minor": 4, "patch": 2, "pre": Bson::Null });
+    assert_eq!(Version::try_from(&bson).unwrap(), version("1.4.2"));
+
+    let bson = Bson::Document(doc! { "major": 1, "minor": 4, "patch": 2, "pre": "rc..1" });
+    let err = Version::try_from(&bson).unwrap_err();
+    assert_to_string(err, "empty identifier segment in pre-release identifier");
+
+    for bson in [
+        Bson::Document(doc! { "major": 1, "minor": 4 }),
+        Bson::Document(doc! { "major": 1, "minor": -4, "patch": 2 }),
+        Bson::Document(doc! { "major": 1, "minor": "4", "patch": 2 }),
+        Bson::Document(doc! { "major": 1, "minor": 4, "patch": 2, "pre": 1 }),
+        Bson::Int32(1),
+    ] {
+        let err = Version::try_from(&bson).unwrap_err();
+        assert_to_string(
+            err,
+            "expected a version string, or a document with integer major, minor and patch",
+        );
+    }
+}
+
+#[test]
+fn test_sort_key() {
+    let versions = [
+        "0.9.0",
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.1",
+        "1.10.0",
+        "10.0.0",
+    ];
+    for pair in versions.windows(2) {
+        let lo = version(pair[0]).bson_sort_key();
+        let hi = version(pair[1]).bson_sort_key();
+        assert!(lo < hi, "{} < {}", lo, hi);
+    }
+
+    assert_eq!(
+        version("1.0.0+a").bson_sort_key(),
+        version("1.0.0+b").bson_sort_key(),
+    );
+}
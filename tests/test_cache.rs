@@ -0,0 +1,52 @@
+#![cfg(feature = "cache")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::MatchCache;
+
+#[test]
+fn test_hits() {
+    let mut cache = MatchCache::new(8);
+    let req = req(">=1.2.3-beta, <2");
+    for _ in 0..3 {
+        assert!(cache.matches(&req, &version("1.2.3-rc.1")));
+        assert!(!cache.matches(&req, &version("1.3.0-rc.1")));
+        assert!(cache.matches(&req, &version("1.9.0")));
+    }
+    assert_eq!(cache.hits(), 6);
+    assert_eq!(cache.misses(), 3);
+    assert_eq!(cache.len(), 3);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert!(cache.matches(&req, &version("1.9.0")));
+    assert_eq!(cache.misses(), 4);
+}
+
+#[test]
+fn test_eviction() {
+    let mut cache = MatchCache::new(2);
+    let a = req("^1");
+    let b = req("^2");
+    let v = version("1.0.0");
+
+    assert!(cache.matches(&a, &v));
+    assert!(!cache.matches(&b, &v));
+    assert!(cache.matches(&a, &version("1.1.0")));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.misses(), 3);
+
+    // The oldest entry, (a, v), was evicted.
+    assert!(cache.matches(&a, &v));
+    assert_eq!(cache.misses(), 4);
+    assert!(!cache.matches(&b, &version("1.1.0")));
+    assert_eq!(cache.misses(), 5);
+    assert_eq!(cache.hits(), 0);
+
+    let mut cache = MatchCache::new(0);
+    assert!(cache.matches(&a, &v));
+    assert!(cache.matches(&a, &v));
+    assert_eq!((cache.hits(), cache.misses(), cache.len()), (0, 2, 0));
+}
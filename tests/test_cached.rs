@@ -0,0 +1,47 @@
+#![allow(clippy::mutable_key_type, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::CachedVersion;
+use std::collections::BTreeSet;
+
+#[test]
+fn test_ord() {
+    let vs = [
+        "0.9.0",
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0",
+        "1.0.0+a",
+        "1.0.0+b",
+        "1.10.0",
+    ];
+    for a in &vs {
+        for b in &vs {
+            let (a, b) = (version(a), version(b));
+            let (ca, cb) = (CachedVersion::new(a.clone()), CachedVersion::new(b.clone()));
+            assert_eq!(ca.cmp(&cb), a.cmp(&b), "{} vs {}", a, b);
+            assert_eq!(ca == cb, a == b);
+        }
+    }
+}
+
+#[test]
+fn test_set() {
+    let set: BTreeSet<CachedVersion> = ["1.0.0-beta.11", "1.0.0-beta.2", "1.0.0-beta.2"]
+        .iter()
+        .map(|string| CachedVersion::from(version(string)))
+        .collect();
+    let strings: Vec<String> = set.iter().map(CachedVersion::to_string).collect();
+    assert_eq!(strings, ["1.0.0-beta.2", "1.0.0-beta.11"]);
+
+    let first = set.into_iter().next().unwrap();
+    assert_eq!(
+        *first.precedence_key(),
+        version("1.0.0-beta.2").precedence_key()
+    );
+    assert_eq!(first.into_inner(), version("1.0.0-beta.2"));
+}
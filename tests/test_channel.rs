@@ -0,0 +1,54 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{ChannelRule, Channels};
+
+#[test]
+fn test_standard() {
+    let all = versions(&[
+        "1.0.0",
+        "1.1.0-beta.1",
+        "1.1.0-beta.10",
+        "1.1.0-beta.2",
+        "1.1.0-betamax",
+        "1.2.0-nightly.20240601",
+        "0.9.0",
+    ]);
+    let channels = Channels::standard();
+    assert_eq!(channels.resolve("latest", &all), Some(&version("1.0.0")));
+    assert_eq!(
+        channels.resolve("beta", &all),
+        Some(&version("1.1.0-beta.10"))
+    );
+    assert_eq!(
+        channels.resolve("nightly", &all),
+        Some(&version("1.2.0-nightly.20240601")),
+    );
+    assert_eq!(channels.resolve("rc", &all), None);
+    assert_eq!(
+        channels.names().collect::<Vec<_>>(),
+        ["latest", "beta", "nightly"],
+    );
+}
+
+#[test]
+fn test_insert_remove() {
+    let all = versions(&["1.0.0", "2.0.0-rc.1"]);
+    let mut channels = Channels::new();
+    assert_eq!(channels.resolve("latest", &all), None);
+
+    assert_eq!(channels.insert("latest", ChannelRule::Stable), None);
+    assert_eq!(channels.resolve("latest", &all), Some(&version("1.0.0")));
+
+    let previous = channels.insert("latest", ChannelRule::Any);
+    assert_eq!(previous, Some(ChannelRule::Stable));
+    assert_eq!(
+        channels.resolve("latest", &all),
+        Some(&version("2.0.0-rc.1"))
+    );
+
+    assert_eq!(channels.remove("latest"), Some(ChannelRule::Any));
+    assert_eq!(channels.rule("latest"), None);
+}
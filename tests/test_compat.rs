@@ -0,0 +1,48 @@
+#![cfg(feature = "compat")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, Op, Version, VersionReq};
+use std::convert::TryFrom;
+
+#[test]
+fn test_version() {
+    let ours = version("1.4.2-rc.1+build.5");
+    let theirs = upstream::Version::from(ours.clone());
+    assert_eq!(
+        theirs,
+        upstream::Version::parse("1.4.2-rc.1+build.5").unwrap()
+    );
+    assert_eq!(Version::from(theirs), ours);
+
+    let theirs: upstream::Version = version("0.3.0").into();
+    assert!(theirs.pre.is_empty());
+    assert!(theirs.build.is_empty());
+}
+
+#[test]
+fn test_version_req() {
+    let ours = req(">=1.2.3, <2, ~0.4.0-beta.2, 1.*");
+    let theirs = upstream::VersionReq::from(ours.clone());
+    assert_eq!(theirs.to_string(), ours.to_string());
+    assert_eq!(theirs.comparators[3].op, upstream::Op::Wildcard);
+    assert_eq!(VersionReq::try_from(theirs).unwrap(), ours);
+
+    let theirs = upstream::VersionReq::STAR;
+    assert_eq!(VersionReq::try_from(theirs).unwrap(), VersionReq::default());
+}
+
+#[test]
+fn test_comparator() {
+    let ours = comparator("=1.2.3-rc.1+build.5");
+    let theirs = upstream::Comparator::from(ours.clone());
+    assert_eq!(theirs.op, upstream::Op::Exact);
+    assert_eq!(theirs.to_string(), "=1.2.3-rc.1");
+
+    let back = Comparator::try_from(theirs).unwrap();
+    assert_eq!(back.op, Op::Exact);
+    assert_eq!(back.pre, ours.pre);
+    assert_eq!(back, ours);
+}
@@ -0,0 +1,140 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::CompiledReq;
+
+const REQS: &[&str] = &[
+    "*",
+    "1.*",
+    "1.2.*",
+    "1",
+    "1.2",
+    "1.2.3",
+    "=1.2",
+    "=1.2.3-beta.1",
+    ">1",
+    ">1.2",
+    ">1.2.3",
+    ">1.2.3-alpha",
+    ">=1.2",
+    ">=1.2.3-beta",
+    "<1",
+    "<1.2",
+    "<1.2.3",
+    "<1.2.3-beta",
+    "<=1.2",
+    "<=1.2.3",
+    "<=1.2.3-beta",
+    "~1",
+    "~1.2",
+    "~1.2.3",
+    "~1.2.3-beta",
+    "^0",
+    "^0.0",
+    "^0.0.3",
+    "^0.2",
+    "^0.2.3",
+    "^1.2.3-beta",
+    ">=1.2.3, <1.8.0",
+    ">=1.2.3-alpha, <1.2.3",
+    "~1.2, >=1.2.5-alpha",
+    ">1.2, <1.3",
+    "<0.0.0",
+    ">18446744073709551615.18446744073709551615.18446744073709551615",
+];
+
+const VERSIONS: &[&str] = &[
+    "0.0.0",
+    "0.0.3",
+    "0.0.4",
+    "0.2.3",
+    "0.2.9",
+    "0.3.0",
+    "1.0.0",
+    "1.1.9",
+    "1.2.0",
+    "1.2.2",
+    "1.2.3-alpha",
+    "1.2.3-beta",
+    "1.2.3-beta.1",
+    "1.2.3-rc.1",
+    "1.2.3",
+    "1.2.3+build",
+    "1.2.4",
+    "1.2.5-beta",
+    "1.3.0-alpha",
+    "1.3.0",
+    "1.8.0",
+    "2.0.0",
+    "18446744073709551615.18446744073709551615.18446744073709551615",
+];
+
+#[test]
+fn test_same_as_matches() {
+    for text in REQS {
+        let req = req(text);
+        let compiled = req.compile();
+        let loaded = CompiledReq::from_bytes(&compiled.to_bytes()).unwrap();
+        assert_eq!(loaded, compiled);
+        for v in VERSIONS {
+            let v = version(v);
+            let expected = req.matches(&v);
+            assert_eq!(compiled.matches(&v), expected, "{} vs {}", req, v);
+            assert_eq!(loaded.matches(&v), expected, "{} vs {}", req, v);
+        }
+    }
+}
+
+#[test]
+fn test_compact() {
+    let bytes = req(">=1.2.3, <1.8.0").compile().to_bytes();
+    assert_eq!(
+        bytes,
+        [1, 1, 1, 2, 3, 1, 7, 255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 0]
+    );
+}
+
+#[test]
+fn test_invalid_bytes() {
+    let bytes = req(">=1.2.3-beta").compile().to_bytes();
+
+    for len in 0..bytes.len() {
+        let err = CompiledReq::from_bytes(&bytes[..len]).unwrap_err();
+        assert_to_string(err, "invalid or incompatible compiled version requirement");
+    }
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(CompiledReq::from_bytes(&trailing).is_err());
+
+    let mut format = bytes;
+    format[0] = 0;
+    assert!(CompiledReq::from_bytes(&format).is_err());
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn test_stats() {
+    let compiled = req(">=1.2.0-beta, <2, ^1").compile();
+    for text in &["1.4.0", "3.0.0", "1.2.0-rc.1", "1.3.0-rc.1", "0.9.0-rc.1"] {
+        compiled.matches(&version(text));
+    }
+    let stats = compiled.stats();
+    assert_eq!(stats.calls, 5);
+    // 3 for 1.2.0-rc.1, 3 for 1.3.0-rc.1, 1 for 0.9.0-rc.1.
+    assert_eq!(stats.comparator_evaluations, 7);
+    assert_eq!(stats.prerelease_gate, 1);
+
+    let clone = compiled.clone();
+    assert_eq!(clone, compiled);
+    compiled.reset_stats();
+    assert_eq!(compiled.stats(), semver::MatchStats::default());
+    assert_eq!(clone.stats(), stats);
+
+    let compiled = req("^1").compile();
+    compiled.matches(&version("1.2.0-rc.1"));
+    assert_eq!(compiled.stats().comparator_evaluations, 0);
+    assert_eq!(compiled.stats().prerelease_gate, 1);
+}
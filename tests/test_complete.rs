@@ -0,0 +1,68 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::Version;
+
+fn known() -> Vec<Version> {
+    [
+        "1.2.3",
+        "1.4.0+build.1",
+        "1.4.1",
+        "1.5.0-rc.1",
+        "2.0.0",
+        "10.1.0",
+    ]
+    .iter()
+    .map(|text| version(text))
+    .collect()
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(
+        semver::complete("", &known()),
+        ["^", "~", "=", ">=", ">", "<=", "<", "10.1.0", "2.0.0", "1.4.1", "1.4.0", "1.2.3",],
+    );
+    assert_eq!(
+        semver::complete("1.0, ", &[version("1.2.3")]),
+        [
+            "1.0, ^",
+            "1.0, ~",
+            "1.0, =",
+            "1.0, >=",
+            "1.0, >",
+            "1.0, <=",
+            "1.0, <",
+            "1.0, 1.2.3",
+        ],
+    );
+    assert_eq!(semver::complete("", &[]).len(), 7);
+}
+
+#[test]
+fn test_operator() {
+    assert_eq!(
+        semver::complete(">", &known()),
+        [">=", ">10.1.0", ">2.0.0", ">1.4.1", ">1.4.0", ">1.2.3"],
+    );
+    assert_eq!(semver::complete("~ ", &[version("1.2.3")]), ["~ 1.2.3"],);
+}
+
+#[test]
+fn test_partial_version() {
+    assert_eq!(
+        semver::complete("1", &known()),
+        ["10.1", "1.4", "1.2", "10.1.0", "1.4.1", "1.4.0", "1.2.3"],
+    );
+    assert_eq!(semver::complete("^1.4", &known()), ["^1.4.1", "^1.4.0"]);
+    assert_eq!(semver::complete("^1.4.1", &known()), Vec::<String>::new());
+    assert_eq!(semver::complete("=3", &known()), Vec::<String>::new());
+}
+
+#[test]
+fn test_prerelease() {
+    assert_eq!(semver::complete("1.5", &known()), Vec::<String>::new());
+    assert_eq!(semver::complete("1.5.0-", &known()), ["1.5.0-rc.1"]);
+}
@@ -0,0 +1,127 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, VersionReq};
+
+const VERSIONS: &[&str] = &[
+    "0.0.0-0",
+    "0.0.0",
+    "0.0.1-alpha",
+    "0.0.1",
+    "0.0.2-0",
+    "0.0.2",
+    "0.1.0-0",
+    "0.1.0",
+    "0.2.0-alpha",
+    "0.2.0",
+    "0.2.3-alpha",
+    "0.2.3-beta",
+    "0.2.3",
+    "0.2.4",
+    "0.3.0-0",
+    "0.3.0-alpha",
+    "0.3.0",
+    "1.0.0-0",
+    "1.0.0",
+    "1.2.0-rc.1",
+    "1.2.0",
+    "1.2.3-alpha",
+    "1.2.3-beta",
+    "1.2.3",
+    "1.2.9",
+    "1.3.0-0",
+    "1.3.0-alpha",
+    "1.3.0",
+    "1.9.9",
+    "2.0.0-0",
+    "2.0.0-alpha",
+    "2.0.0",
+    "3.0.0",
+];
+
+#[cfg_attr(not(no_track_caller), track_caller)]
+fn check(text: &str, desugar: fn(&Comparator) -> (Comparator, Option<Comparator>), expected: &str) {
+    let cmp = comparator(text);
+    let (lower, upper) = desugar(&cmp);
+    let desugared = VersionReq {
+        comparators: Some(lower).into_iter().chain(upper).collect(),
+    };
+    assert_to_string(&desugared, expected);
+
+    let original = VersionReq {
+        comparators: vec![cmp],
+    };
+    for string in VERSIONS {
+        let version = version(string);
+        assert_eq!(
+            original.matches(&version),
+            desugared.matches(&version),
+            "{} vs {} disagree on {}",
+            original,
+            desugared,
+            version,
+        );
+    }
+}
+
+#[test]
+fn test_caret() {
+    check("^1.2.3", semver::desugar_caret, ">=1.2.3, <2.0.0-0");
+    check(
+        "^1.2.3-alpha",
+        semver::desugar_caret,
+        ">=1.2.3-alpha, <2.0.0-0",
+    );
+    check("^1.2", semver::desugar_caret, ">=1.2.0, <2.0.0-0");
+    check("^1", semver::desugar_caret, ">=1.0.0, <2.0.0-0");
+    check("^0.2.3", semver::desugar_caret, ">=0.2.3, <0.3.0-0");
+    check(
+        "^0.2.3-alpha",
+        semver::desugar_caret,
+        ">=0.2.3-alpha, <0.3.0-0",
+    );
+    check("^0.2", semver::desugar_caret, ">=0.2.0, <0.3.0-0");
+    check("^0.0.1", semver::desugar_caret, ">=0.0.1, <0.0.2-0");
+    check("^0.0", semver::desugar_caret, ">=0.0.0, <0.1.0-0");
+    check("^0", semver::desugar_caret, ">=0.0.0, <1.0.0-0");
+}
+
+#[test]
+fn test_tilde() {
+    check("~1.2.3", semver::desugar_tilde, ">=1.2.3, <1.3.0-0");
+    check(
+        "~1.2.3-beta",
+        semver::desugar_tilde,
+        ">=1.2.3-beta, <1.3.0-0",
+    );
+    check("~1.2", semver::desugar_tilde, ">=1.2.0, <1.3.0-0");
+    check("~1", semver::desugar_tilde, ">=1.0.0, <2.0.0-0");
+    check("~0.2.3", semver::desugar_tilde, ">=0.2.3, <0.3.0-0");
+    check("~0", semver::desugar_tilde, ">=0.0.0, <1.0.0-0");
+}
+
+#[test]
+fn test_wildcard() {
+    check("1.2.*", semver::desugar_wildcard, ">=1.2.0, <1.3.0-0");
+    check("1.*", semver::desugar_wildcard, ">=1.0.0, <2.0.0-0");
+    check("0.0.*", semver::desugar_wildcard, ">=0.0.0, <0.1.0-0");
+}
+
+#[test]
+fn test_overflow() {
+    let max = std::u64::MAX;
+
+    let (_, upper) = semver::desugar_caret(&comparator(&format!("^{}", max)));
+    assert_eq!(upper, None);
+
+    let (_, upper) = semver::desugar_caret(&comparator(&format!("^0.{}", max)));
+    assert_to_string(upper.unwrap(), "<1.0.0-0");
+
+    let (_, upper) = semver::desugar_caret(&comparator(&format!("^0.0.{}", max)));
+    assert_to_string(upper.unwrap(), "<0.1.0-0");
+
+    let (_, upper) = semver::desugar_tilde(&comparator(&format!("~{}.{}.0", max, max)));
+    assert_eq!(upper, None);
+}
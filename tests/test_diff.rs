@@ -0,0 +1,50 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::ReqChange;
+
+fn diff(old: &str, new: &str) -> String {
+    req(new).diff_display(&req(old)).to_string()
+}
+
+#[test]
+fn test_unchanged() {
+    assert_eq!(diff("^1.2", "^1.2"), "unchanged");
+    assert_eq!(diff(">=1.2, <2", "<2, >=1.2"), "unchanged");
+    assert!(req("*").diff_display(&req("*")).is_empty());
+}
+
+#[test]
+fn test_bound() {
+    assert_eq!(diff("^1.2", "^1.4"), "raised `^1.2` to `^1.4`");
+    assert_eq!(diff("<2", "<1.8"), "lowered `<2` to `<1.8`");
+    assert_eq!(
+        diff("=1.0.0-rc.2", "=1.0.0"),
+        "raised `=1.0.0-rc.2` to `=1.0.0`"
+    );
+    assert_eq!(diff("^1.2", "^1.2.0"), "changed `^1.2` to `^1.2.0`");
+}
+
+#[test]
+fn test_operator() {
+    assert_eq!(diff("~1.2", "^1.2"), "changed `~1.2` to `^1.2`");
+    assert_eq!(diff(">1.2", ">=1.3"), "changed `>1.2` to `>=1.3`");
+    assert_eq!(diff(">=1.2, <2", "<=1.9, >=1.2"), "changed `<2` to `<=1.9`",);
+}
+
+#[test]
+fn test_added_removed() {
+    assert_eq!(diff("*", ">=1.2"), "added `>=1.2`");
+    assert_eq!(diff(">=1.2, <2", ">=1.2"), "removed `<2`");
+    assert_eq!(
+        diff(">=1.2", "<2, ^1.4"),
+        "added `<2`, added `^1.4`, removed `>=1.2`",
+    );
+
+    let changes = req("<2").diff_display(&req(">1")).changes;
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0], ReqChange::Added(comparator("<2")));
+    assert_eq!(changes[1], ReqChange::Removed(comparator(">1")));
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use std::env;
+use std::error::Error;
+
+#[test]
+fn test_from_env() {
+    env::set_var("SEMVER_TEST_FROM_ENV", "1.4.0-rc.1");
+    assert_eq!(
+        semver::from_env("SEMVER_TEST_FROM_ENV").unwrap(),
+        version("1.4.0-rc.1"),
+    );
+
+    env::set_var("SEMVER_TEST_FROM_ENV", "v1.4");
+    let err = semver::from_env("SEMVER_TEST_FROM_ENV").unwrap_err();
+    assert_eq!(err.var(), "SEMVER_TEST_FROM_ENV");
+    assert!(!err.is_missing());
+    assert_eq!(err.value(), Some("v1.4"));
+    assert!(err.parse_error().is_some());
+    assert!(err.source().is_some());
+    assert_to_string(
+        err,
+        "environment variable SEMVER_TEST_FROM_ENV is not a valid version: \"v1.4\": unexpected character 'v' while parsing major version number",
+    );
+}
+
+#[test]
+fn test_req_from_env() {
+    env::set_var("SEMVER_TEST_REQ_FROM_ENV", ">=1.2, <2");
+    assert_eq!(
+        semver::req_from_env("SEMVER_TEST_REQ_FROM_ENV").unwrap(),
+        req(">=1.2, <2"),
+    );
+
+    env::set_var("SEMVER_TEST_REQ_FROM_ENV", ">=1.2 <2");
+    let err = semver::req_from_env("SEMVER_TEST_REQ_FROM_ENV").unwrap_err();
+    assert_to_string(
+        err,
+        "environment variable SEMVER_TEST_REQ_FROM_ENV is not a valid version requirement: \">=1.2 <2\": expected comma after minor version number, found '<'",
+    );
+}
+
+#[test]
+fn test_missing() {
+    let err = semver::from_env("SEMVER_TEST_MISSING").unwrap_err();
+    assert!(err.is_missing());
+    assert_eq!(err.value(), None);
+    assert!(err.source().is_none());
+    assert_to_string(err, "environment variable SEMVER_TEST_MISSING is not set");
+}
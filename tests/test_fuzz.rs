@@ -0,0 +1,62 @@
+#![cfg(feature = "testing")]
+
+#[test]
+fn test_roundtrip_version() {
+    for input in [
+        "1.2.3",
+        "1.2.3-rc.1+build.5",
+        "0.0.0-0.a.b-c+001",
+        "18446744073709551615.0.0",
+        "18446744073709551616.0.0",
+        "1.2",
+        " 1.2.3",
+    ]
+    .iter()
+    {
+        semver::fuzz_roundtrip_version(input.as_bytes());
+    }
+    semver::fuzz_roundtrip_version(b"1.2.3-\xff");
+}
+
+#[test]
+fn test_eval_consistency() {
+    let reqs = [
+        "*",
+        "^1.2",
+        "~1.2.3",
+        "=1.2",
+        ">1.2",
+        ">=1.2.3-rc.1",
+        "<1.2.3",
+        "<=1.2",
+        "1.*",
+        "^0.0.3",
+        "^0",
+        ">1.2.3, <2.0.0-alpha",
+        "~1.2.3-beta.2",
+    ];
+    let versions = [
+        "0.0.0",
+        "0.0.3",
+        "0.1.0",
+        "1.0.0",
+        "1.2.0",
+        "1.2.3-rc.1",
+        "1.2.3-rc.2",
+        "1.2.3",
+        "1.2.4",
+        "1.2.99+build",
+        "1.3.0-0",
+        "1.3.0",
+        "2.0.0-alpha",
+        "2.0.0",
+    ];
+    for req in reqs.iter() {
+        for version in versions.iter() {
+            let input = format!("{}\n{}", req, version);
+            semver::fuzz_eval_consistency(input.as_bytes());
+        }
+    }
+    semver::fuzz_eval_consistency(b"^1.2");
+    semver::fuzz_eval_consistency(b"^1.2\nnot a version");
+}
@@ -0,0 +1,97 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::GitDescribe;
+
+#[test]
+fn test_parse() {
+    let describe = GitDescribe::parse("v1.2.3-4-gabcdef1").unwrap();
+    assert_eq!(
+        describe,
+        GitDescribe {
+            base: version("1.2.3"),
+            commits_since: 4,
+            hash: Some("abcdef1".to_owned()),
+            dirty: false,
+        },
+    );
+
+    let describe = GitDescribe::parse("1.2.3-rc.1-12-g0123abcd-dirty").unwrap();
+    assert_eq!(describe.base, version("1.2.3-rc.1"));
+    assert_eq!(describe.commits_since, 12);
+    assert_eq!(describe.hash.as_ref().map(String::as_str), Some("0123abcd"));
+    assert!(describe.dirty);
+
+    let describe = GitDescribe::parse("v1.2.3").unwrap();
+    assert_eq!(describe.base, version("1.2.3"));
+    assert_eq!(describe.commits_since, 0);
+    assert_eq!(describe.hash, None);
+    assert!(!describe.dirty);
+
+    let describe = GitDescribe::parse("v1.2.3-dirty").unwrap();
+    assert_eq!(describe.base, version("1.2.3"));
+    assert!(describe.dirty);
+
+    let err = GitDescribe::parse("release-5-gabcdef1").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'r' while parsing major version number",
+    );
+}
+
+#[test]
+fn test_to_prerelease_version() {
+    for &(describe, expected) in &[
+        ("v1.2.3", "1.2.3"),
+        ("v1.2.3-0-gabcdef1", "1.2.3"),
+        ("v1.2.3-4-gabcdef1", "1.2.4-dev.4+gabcdef1"),
+        ("v1.2.3-4-gabcdef1-dirty", "1.2.4-dev.4+gabcdef1.dirty"),
+        ("v1.2.3-dirty", "1.2.4-dev.0+dirty"),
+        ("v1.2.3-rc.1-4-gabcdef1", "1.2.3-rc.1.dev.4+gabcdef1"),
+        ("v1.2.3+build.5-4-gabcdef1", "1.2.4-dev.4+gabcdef1"),
+        (
+            "v1.2.18446744073709551615-4-gabcdef1",
+            "1.3.0-dev.4+gabcdef1",
+        ),
+        (
+            "v1.18446744073709551615.18446744073709551615-4-gabcdef1",
+            "2.0.0-dev.4+gabcdef1",
+        ),
+    ] {
+        let version = GitDescribe::parse(describe)
+            .unwrap()
+            .to_prerelease_version();
+        assert_eq!(version, self::version(expected), "{}", describe);
+        let base = GitDescribe::parse(describe).unwrap().base;
+        assert!(version >= base, "{}", describe);
+    }
+}
+
+#[test]
+fn test_past_highest_release() {
+    let max = "18446744073709551615.18446744073709551615.18446744073709551615";
+    let describe = GitDescribe::parse(max).unwrap();
+    assert_eq!(describe.to_prerelease_version().to_string(), max);
+
+    for suffix in &["-4-gabcdef1", "-dirty"] {
+        let err = GitDescribe::parse(&format!("v{}{}", max, suffix)).unwrap_err();
+        assert_to_string(
+            err,
+            "no release after the highest possible version to number commits with",
+        );
+    }
+
+    let describe = GitDescribe::parse(&format!("v{}-rc.1-4-gabcdef1", max)).unwrap();
+    assert_eq!(
+        describe.to_prerelease_version().to_string(),
+        format!("{}-rc.1.dev.4+gabcdef1", max),
+    );
+
+    let describe = GitDescribe::parse("v18446744073709551615.2.3-3-gabcdef1").unwrap();
+    assert_eq!(
+        describe.to_prerelease_version().to_string(),
+        "18446744073709551615.2.4-dev.3+gabcdef1",
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "async-graphql")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use async_graphql::{InputType, OutputType, Pos, ScalarType, Value};
+use semver::{Version, VersionReq};
+
+fn parse_err<T: InputType>(value: Value) -> String {
+    match T::parse(Some(value)) {
+        Ok(_) => panic!("expected error"),
+        Err(err) => err.into_server_error(Pos::default()).message,
+    }
+}
+
+#[test]
+fn test_version() {
+    let value = Value::String("1.4.2-rc.1".to_owned());
+    let parsed = <Version as ScalarType>::parse(value).unwrap();
+    assert_eq!(parsed, version("1.4.2-rc.1"));
+    assert_eq!(
+        ScalarType::to_value(&parsed),
+        Value::String("1.4.2-rc.1".to_owned())
+    );
+    assert_eq!(<Version as OutputType>::type_name(), "Version");
+
+    assert_eq!(
+        parse_err::<Version>(Value::String("1.4".to_owned())),
+        "Failed to parse \"Version\": unexpected end of input while parsing minor version number",
+    );
+    assert_eq!(
+        parse_err::<Version>(Value::Boolean(true)),
+        "Expected input type \"Version\", found true.",
+    );
+}
+
+#[test]
+fn test_version_req() {
+    let value = Value::String(">=1.2.3, <2".to_owned());
+    let parsed = <VersionReq as ScalarType>::parse(value).unwrap();
+    assert_eq!(parsed, req(">=1.2.3, <2"));
+    assert_eq!(
+        ScalarType::to_value(&parsed),
+        Value::String(">=1.2.3, <2".to_owned())
+    );
+    assert_eq!(<VersionReq as InputType>::type_name(), "VersionReq");
+
+    assert_eq!(
+        parse_err::<VersionReq>(Value::String(">=1.2.3,".to_owned())),
+        "Failed to parse \"VersionReq\": unexpected end of input while parsing major version number",
+    );
+    assert!(<VersionReq as ScalarType>::is_valid(&Value::String(
+        "*".to_owned()
+    )));
+    assert!(!<VersionReq as ScalarType>::is_valid(&Value::Number(
+        1.into()
+    )));
+}
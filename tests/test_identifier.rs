@@ -8,7 +8,8 @@
 mod util;
 
 use crate::util::*;
-use semver::Prerelease;
+use semver::{BuildMetadata, Prerelease};
+use std::cmp::Ordering;
 
 #[test]
 fn test_new() {
@@ -49,3 +50,172 @@ fn test_prerelease() {
     let err = prerelease_err("1.b\0");
     assert_to_string(err, "unexpected character in pre-release identifier");
 }
+
+#[test]
+fn test_prerelease_push_pop() {
+    let mut pre = Prerelease::EMPTY;
+    assert_eq!(pre.pop_identifier(), None);
+    pre.push_identifier("beta").unwrap();
+    pre.push_identifier("3").unwrap();
+    assert_eq!(pre.as_str(), "beta.3");
+
+    assert_to_string(
+        pre.push_identifier("a.b").unwrap_err(),
+        "unexpected character in pre-release identifier",
+    );
+    assert_to_string(
+        pre.push_identifier("").unwrap_err(),
+        "empty identifier segment in pre-release identifier",
+    );
+    assert_to_string(
+        pre.push_identifier("007").unwrap_err(),
+        "invalid leading zero in pre-release identifier",
+    );
+    assert_eq!(pre.as_str(), "beta.3");
+
+    assert_eq!(pre.pop_identifier().unwrap(), "3");
+    assert_eq!(pre.pop_identifier().unwrap(), "beta");
+    assert!(pre.is_empty());
+    assert_eq!(pre, Prerelease::EMPTY);
+}
+
+#[test]
+fn test_prerelease_numeric_suffix() {
+    let pre = Prerelease::EMPTY.with_numeric_suffix(7);
+    assert_eq!(pre.as_str(), "7");
+    assert_eq!(pre.with_numeric_suffix(8).as_str(), "8");
+
+    let mut pre = prerelease("beta.3.nightly.20240601");
+    assert_eq!(pre.strip_numeric_suffix(), Some(20240601));
+    assert_eq!(pre.strip_numeric_suffix(), None);
+    assert_eq!(pre.as_str(), "beta.3.nightly");
+    assert_eq!(pre.with_numeric_suffix(2).as_str(), "beta.3.nightly.2");
+
+    let mut pre = prerelease("rc.99999999999999999999");
+    assert_eq!(pre.strip_numeric_suffix(), None);
+    assert_eq!(
+        pre.with_numeric_suffix(1).as_str(),
+        "rc.99999999999999999999.1"
+    );
+    let mut pre = prerelease("x-1");
+    assert_eq!(pre.strip_numeric_suffix(), None);
+}
+
+#[test]
+fn test_prerelease_cmp_identifiers() {
+    let ordered = [
+        "",
+        "1",
+        "2",
+        "11",
+        "alpha",
+        "alpha.1",
+        "alpha.beta",
+        "beta",
+        "beta.2",
+        "beta.11",
+        "rc.1",
+    ];
+    for (i, lhs) in ordered.iter().enumerate() {
+        for (j, rhs) in ordered.iter().enumerate() {
+            let expected = i.cmp(&j);
+            assert_eq!(semver::cmp_prerelease_str(lhs, rhs), expected);
+            assert_eq!(prerelease(lhs).cmp_identifiers(&prerelease(rhs)), expected,);
+            if i > 0 && j > 0 {
+                assert_eq!(prerelease(lhs).cmp(&prerelease(rhs)), expected);
+            }
+        }
+    }
+
+    // Not validated, for reuse by other schemes.
+    assert_eq!(semver::cmp_prerelease_str("dev_1", "dev_2"), Ordering::Less);
+}
+
+#[test]
+fn test_prerelease_sort_key() {
+    let ordered = [
+        "0",
+        "1",
+        "9",
+        "10",
+        "99",
+        "100",
+        "1234567890",
+        "12345678901",
+        "-",
+        "0a",
+        "A",
+        "Z",
+        "a",
+        "a.0",
+        "a.a",
+        "a-b",
+        "aa",
+        "alpha",
+        "alpha.1",
+        "alpha.1.1",
+        "alpha.2",
+        "alpha.beta",
+        "beta",
+        "beta.2",
+        "beta.11",
+        "rc.1",
+        "",
+    ];
+    for (i, lhs) in ordered.iter().enumerate() {
+        for (j, rhs) in ordered.iter().enumerate() {
+            let lhs = prerelease(lhs);
+            let rhs = prerelease(rhs);
+            assert_eq!(lhs.cmp(&rhs), i.cmp(&j), "{} {}", lhs, rhs);
+            assert_eq!(
+                lhs.to_sort_key().cmp(&rhs.to_sort_key()),
+                i.cmp(&j),
+                "{} {}",
+                lhs,
+                rhs,
+            );
+        }
+    }
+
+    assert_eq!(prerelease("beta.11").to_sort_key(), "2beta!112110");
+}
+
+#[test]
+fn test_build_metadata_helpers() {
+    assert_eq!(
+        BuildMetadata::from_parts(&[]).unwrap(),
+        BuildMetadata::EMPTY
+    );
+    let mut build = BuildMetadata::from_parts(&["ci", "0042"]).unwrap();
+    assert_eq!(build.as_str(), "ci.0042");
+    build.append("dirty").unwrap();
+    assert_eq!(build.as_str(), "ci.0042.dirty");
+
+    assert_to_string(
+        build.append("a.b").unwrap_err(),
+        "unexpected character in build metadata",
+    );
+    assert_to_string(
+        BuildMetadata::from_parts(&["ci", ""]).unwrap_err(),
+        "empty identifier segment in build metadata",
+    );
+    assert_eq!(build.as_str(), "ci.0042.dirty");
+
+    let sha = "0123456789ABCDEFabcdef0123456789abcdef01";
+    assert_eq!(
+        BuildMetadata::from_git_sha(sha).unwrap().as_str(),
+        "0123456789abcdefabcdef0123456789abcdef01",
+    );
+    assert!(BuildMetadata::from_git_sha("").is_err());
+    assert!(BuildMetadata::from_git_sha("g8e6aa2a").is_err());
+
+    assert_eq!(BuildMetadata::from_timestamp(0).as_str(), "19700101000000");
+    assert_eq!(
+        BuildMetadata::from_timestamp(951_825_599).as_str(),
+        "20000229115959",
+    );
+    assert_eq!(
+        BuildMetadata::from_timestamp(4_107_542_400).as_str(),
+        "21000301000000",
+    );
+}
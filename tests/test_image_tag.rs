@@ -0,0 +1,64 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_from_image_tag() {
+    for &(reference, expected, variant) in &[
+        ("redis:7.2.4-alpine", "7.2.4", "alpine"),
+        ("redis:7.2.4", "7.2.4", ""),
+        (
+            "docker.io/library/redis:7.2-alpine3.19",
+            "7.2.0",
+            "alpine3.19",
+        ),
+        ("localhost:5000/team/app:v2.10.0", "2.10.0", ""),
+        (
+            "python:3.12.2-slim-bookworm@sha256:0123abcd",
+            "3.12.2",
+            "slim-bookworm",
+        ),
+        ("ghcr.io/org/app:1.0.0-rc.2-alpine", "1.0.0-rc.2", "alpine"),
+    ] {
+        assert_eq!(
+            semver::from_image_tag(reference),
+            Some((version(expected), variant)),
+            "{}",
+            reference,
+        );
+    }
+
+    for reference in &[
+        "redis",
+        "localhost:5000/redis",
+        "redis:latest",
+        "redis@sha256:0123abcd",
+    ] {
+        assert_eq!(semver::from_image_tag(reference), None, "{}", reference);
+    }
+}
+
+#[test]
+fn test_split_image_tag() {
+    for &(tag, expected, variant) in &[
+        ("7", "7.0.0", ""),
+        ("20.11.1-alpine3.19", "20.11.1", "alpine3.19"),
+        ("1.0.0-rc1", "1.0.0-rc1", ""),
+        ("1.0.0-beta.3", "1.0.0-beta.3", ""),
+        ("1.0.0-betamax", "1.0.0", "betamax"),
+        ("1.25.3-bookworm", "1.25.3", "bookworm"),
+    ] {
+        assert_eq!(
+            semver::split_image_tag(tag),
+            Some((version(expected), variant)),
+            "{}",
+            tag,
+        );
+    }
+
+    for tag in &["latest", "alpine3.19", "7.2.4.1", "7.2.4_alpine", "-alpine"] {
+        assert_eq!(semver::split_image_tag(tag), None, "{}", tag);
+    }
+}
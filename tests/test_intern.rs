@@ -0,0 +1,41 @@
+#![cfg(feature = "intern")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_shared() {
+    let mut a = version("1.0.0-nightly.20240601+x86-64-linux");
+    let mut b = version("1.2.0-nightly.20240601+x86-64-linux");
+    assert_ne!(a.pre.as_str().as_ptr(), b.pre.as_str().as_ptr());
+
+    a.intern();
+    b.intern();
+    assert_eq!(a.pre.as_str().as_ptr(), b.pre.as_str().as_ptr());
+    assert_eq!(a.build.as_str().as_ptr(), b.build.as_str().as_ptr());
+
+    let c = b.clone();
+    drop(b);
+    assert_eq!(a.pre.as_str().as_ptr(), c.pre.as_str().as_ptr());
+    assert_eq!(c, version("1.2.0-nightly.20240601+x86-64-linux"));
+    assert_eq!(c.pre, prerelease("nightly.20240601"));
+    assert_eq!(c.to_string(), "1.2.0-nightly.20240601+x86-64-linux");
+}
+
+#[test]
+fn test_inline() {
+    let mut a = version("1.0.0-rc.1+g8e6aa2a");
+    a.intern();
+    assert_eq!(a, version("1.0.0-rc.1+g8e6aa2a"));
+
+    let mut pre = prerelease("");
+    pre.intern();
+    assert!(pre.is_empty());
+
+    let mut build = build_metadata("20240601.x86-64");
+    build.intern();
+    build.intern();
+    assert_eq!(build.as_str(), "20240601.x86-64");
+}
@@ -0,0 +1,38 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_parse_kubernetes() {
+    for &(text, expected) in &[
+        ("v1.29.1", "1.29.1"),
+        ("1.29.1", "1.29.1"),
+        ("v1.29.1+k3s1", "1.29.1+k3s1"),
+        ("v1.29.1-eks-abc", "1.29.1+eks-abc"),
+        ("v1.28.3-eks-4f4795d", "1.28.3+eks-4f4795d"),
+        ("v1.27.3-gke.1200", "1.27.3+gke.1200"),
+        ("v1.28.5-rke2r1+build.9", "1.28.5+rke2r1.build.9"),
+        ("v1.30.0-alpha.1", "1.30.0-alpha.1"),
+        ("v1.30.0-beta.0", "1.30.0-beta.0"),
+        ("v1.30.0-rc.1+k3s1", "1.30.0-rc.1+k3s1"),
+    ] {
+        assert_eq!(
+            semver::parse_kubernetes(text).unwrap(),
+            version(expected),
+            "{}",
+            text
+        );
+    }
+
+    let release = semver::parse_kubernetes("v1.29.1").unwrap();
+    let eks = semver::parse_kubernetes("v1.29.1-eks-abc").unwrap();
+    assert_eq!(eks.cmp_precedence(&release), std::cmp::Ordering::Equal);
+
+    let err = semver::parse_kubernetes("v1.29").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected end of input while parsing minor version number",
+    );
+}
@@ -0,0 +1,78 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::Version;
+
+fn lenient(text: &str) -> String {
+    Version::parse_lenient(text).unwrap().to_string()
+}
+
+#[test]
+fn test_parse_lenient() {
+    assert_eq!(lenient("1.2.3-rc.1+build.01"), "1.2.3-rc.1+build.01");
+    assert_eq!(lenient("v1.2.3"), "1.2.3");
+    assert_eq!(lenient("V1.2.3"), "1.2.3");
+    assert_eq!(lenient("=1.2.3"), "1.2.3");
+    assert_eq!(lenient(" 1.2.3\n"), "1.2.3");
+    assert_eq!(lenient("1"), "1.0.0");
+    assert_eq!(lenient("1.2"), "1.2.0");
+    assert_eq!(lenient("1.2-beta"), "1.2.0-beta");
+    assert_eq!(lenient("1+build"), "1.0.0+build");
+    assert_eq!(lenient("01.002.000"), "1.2.0");
+    assert_eq!(lenient("1.2.3-rc.007.0a.00"), "1.2.3-rc.7.0a.0");
+}
+
+#[test]
+fn test_parse_lenient_errors() {
+    let err = Version::parse_lenient("").unwrap_err();
+    assert_to_string(err, "empty string, expected a semver version");
+
+    let err = Version::parse_lenient("v").unwrap_err();
+    assert_to_string(err, "empty string, expected a semver version");
+
+    let err = Version::parse_lenient("1.x").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'x' while parsing minor version number",
+    );
+
+    let err = Version::parse_lenient("1.2.3.4").unwrap_err();
+    assert_to_string(err, "unexpected character '.' after patch version number");
+
+    let err = Version::parse_lenient("vv1.2.3").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'v' while parsing major version number",
+    );
+}
+
+#[cfg(feature = "report")]
+#[test]
+fn test_report() {
+    use semver::Normalization;
+
+    let report = Version::parse_lenient_with_report("1.2.3").unwrap();
+    assert!(report.normalizations().is_empty());
+
+    let report = Version::parse_lenient_with_report("  v01.2-rc.01+b ").unwrap();
+    assert_eq!(report.version(), &version("1.2.0-rc.1+b"));
+    assert_eq!(
+        report.normalizations(),
+        [
+            Normalization::TrimmedWhitespace,
+            Normalization::StrippedPrefix('v'),
+            Normalization::StrippedLeadingZeros { offset: 3 },
+            Normalization::FilledPatch,
+            Normalization::StrippedLeadingZeros { offset: 11 },
+        ],
+    );
+    assert_eq!(report.into_version(), version("1.2.0-rc.1+b"));
+
+    let report = Version::parse_lenient_with_report("7").unwrap();
+    assert_eq!(
+        report.normalizations(),
+        [Normalization::FilledMinor, Normalization::FilledPatch],
+    );
+}
@@ -0,0 +1,38 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_parse_lines() {
+    let dump = "1.0.0\r\n  1.0.1  \n\n1.1\n2.0.0-rc.1\nv3.0.0";
+    let (versions, errors) = semver::parse_lines(dump);
+    assert_eq!(
+        versions,
+        [version("1.0.0"), version("1.0.1"), version("2.0.0-rc.1")],
+    );
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].0, 3);
+    assert_to_string(
+        &errors[0].1,
+        "unexpected end of input while parsing minor version number",
+    );
+    assert_eq!(errors[1].0, 5);
+    assert_to_string(
+        &errors[1].1,
+        "unexpected character 'v' while parsing major version number",
+    );
+}
+
+#[test]
+fn test_parse_req_lines() {
+    let (reqs, errors) = semver::parse_req_lines("^1.2\n\n>=1, <2\n1.2.3.4\n");
+    assert_eq!(reqs, [req("^1.2"), req(">=1, <2")]);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 3);
+
+    let (reqs, errors) = semver::parse_req_lines("");
+    assert!(reqs.is_empty());
+    assert!(errors.is_empty());
+}
@@ -0,0 +1,35 @@
+use semver::cmp_loose;
+use std::cmp::Ordering;
+
+#[test]
+fn test_cmp_loose() {
+    let sorted = [
+        "0.9.0",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.0+build",
+        "1.10.0",
+        "",
+        "1.2",
+        "1.10",
+        "latest",
+        "r9",
+        "r010",
+        "r10",
+        "r10a",
+        "release",
+        "v1.2.3",
+    ];
+    for (i, a) in sorted.iter().enumerate() {
+        for (j, b) in sorted.iter().enumerate() {
+            assert_eq!(cmp_loose(a, b), i.cmp(&j), "{:?} vs {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn test_total() {
+    assert_eq!(cmp_loose("1.0.0+a", "1.0.0+b"), Ordering::Less);
+    assert_eq!(cmp_loose("r01", "r1"), Ordering::Less);
+    assert_eq!(cmp_loose("nightly", "nightly"), Ordering::Equal);
+}
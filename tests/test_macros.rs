@@ -0,0 +1,112 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_crate_version() {
+    let version = semver::crate_version!();
+    assert_eq!(version, self::version(env!("CARGO_PKG_VERSION")));
+
+    let req = semver::pkg_version_req!();
+    assert_eq!(req, self::req(&format!("^{}", env!("CARGO_PKG_VERSION"))));
+    assert!(req.matches(&version));
+}
+
+#[cfg(not(no_const_panic))]
+#[test]
+fn test_const_parse() {
+    use semver::__private::ConstVersion;
+
+    for text in &[
+        "0.0.0",
+        "1.2.3",
+        "18446744073709551615.0.0",
+        "1.2.3-alpha.1",
+        "1.2.3-0.a-b.1",
+        "1.2.3+build.001",
+        "1.2.3-rc.1+g8e6aa2a",
+        "1.2.3-a-very-long-prerelease+and-a-long-build",
+    ] {
+        assert_eq!(ConstVersion::parse(text).to_version(), version(text));
+    }
+
+    for text in &[
+        "",
+        "1.2",
+        "01.2.3",
+        "1.2.3-",
+        "1.2.3-01",
+        "1.2.3-a..b",
+        "1.2.3+",
+        "1.2.3-rc_1",
+        "1.2.3x",
+        "18446744073709551616.0.0",
+    ] {
+        assert!(
+            std::panic::catch_unwind(|| ConstVersion::parse(text)).is_err(),
+            "{}",
+            text
+        );
+    }
+}
+
+#[cfg(not(no_const_panic))]
+#[test]
+fn test_const_check_req() {
+    use semver::__private::check_req;
+
+    for text in &[
+        "",
+        " ",
+        "*",
+        " x ",
+        "X",
+        "*, 1",
+        "* 1",
+        "1.0, *",
+        "1",
+        "1.2",
+        "1.2.3",
+        "=1.2.3-rc.1+build.5",
+        ">= 1.2, < 2",
+        " ~1.2 ,^1.2.3-beta ",
+        "1.*",
+        "1.2.x",
+        "1.*.*",
+        "1.*.3",
+        "1.2.3-",
+        "1.2.3-01",
+        "1.2.3-a..b",
+        "1.2-beta",
+        "1.2.3+",
+        "01.2",
+        ">=1.0 <2.0",
+        ">=1.0,",
+        "> =1",
+        "@1",
+        "1.2.3 foo",
+        "18446744073709551616",
+    ] {
+        let expected = semver::VersionReq::parse(text).is_ok();
+        let checked = std::panic::catch_unwind(|| check_req(text)).is_ok();
+        assert_eq!(checked, expected, "{:?}", text);
+    }
+
+    let many = vec!["1"; 32].join(",");
+    assert!(std::panic::catch_unwind(|| check_req(&many)).is_ok());
+    let too_many = vec!["1"; 33].join(",");
+    assert!(std::panic::catch_unwind(|| check_req(&too_many)).is_err());
+}
+
+#[cfg(all(feature = "std", not(no_lazy_lock)))]
+#[test]
+fn test_lazy_req() {
+    use semver::VersionReq;
+    use std::sync::LazyLock;
+
+    static REQ: LazyLock<VersionReq> = semver::lazy_req!("^1.2");
+    assert_eq!(*REQ, req("^1.2"));
+    assert!(REQ.matches(&version("1.9.0")));
+}
@@ -0,0 +1,209 @@
+#![allow(clippy::toplevel_ref_arg, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::eval::matches_all;
+use semver::{MatchOptions, PreReleasePolicy, VersionReq};
+
+#[cfg_attr(not(no_track_caller), track_caller)]
+fn assert_match_all(req: &VersionReq, options: &MatchOptions, versions: &[&str]) {
+    for string in versions {
+        let parsed = version(string);
+        assert!(
+            req.matches_with(&parsed, options),
+            "did not match {}",
+            string
+        );
+    }
+}
+
+#[cfg_attr(not(no_track_caller), track_caller)]
+fn assert_match_none(req: &VersionReq, options: &MatchOptions, versions: &[&str]) {
+    for string in versions {
+        let parsed = version(string);
+        assert!(!req.matches_with(&parsed, options), "matched {}", string);
+    }
+}
+
+#[test]
+fn test_default() {
+    let ref options = MatchOptions::new();
+    assert_eq!(*options, MatchOptions::default());
+
+    let ref r = req(">=1.2.3-alpha.1, <2");
+    assert_match_all(
+        r,
+        options,
+        &["1.2.3-alpha.1", "1.2.3-beta", "1.2.3", "1.9.0"],
+    );
+    assert_match_none(r, options, &["1.2.3-alpha.0", "1.2.4-alpha.2", "2.0.0"]);
+}
+
+#[test]
+fn test_channel() {
+    let ref beta = MatchOptions::new().channel(Some("beta"));
+
+    let ref r = req(">=2.0.0-alpha.1, <3");
+    assert_match_all(
+        r,
+        beta,
+        &["2.0.0-beta", "2.0.0-beta.2.nightly", "2.0.0", "2.5.0"],
+    );
+    assert_match_none(
+        r,
+        beta,
+        &["2.0.0-alpha.2", "2.0.0-rc.1", "2.0.0-betamax", "3.0.0"],
+    );
+
+    let ref r = req("^2");
+    assert_match_all(r, beta, &["2.0.0", "2.3.1"]);
+    assert_match_none(r, beta, &["2.1.0-beta.1", "1.0.0"]);
+
+    let ref any = MatchOptions::new().channel(None);
+    let ref r = req(">=2.0.0-alpha.1");
+    assert_match_all(r, any, &["2.0.0-alpha.2", "2.0.0-rc.1"]);
+}
+
+#[test]
+fn test_prerelease_policy() {
+    let ref excluded = MatchOptions::new().policy(PreReleasePolicy::Excluded);
+    let ref same_triple = MatchOptions::new().policy(PreReleasePolicy::SameTriple);
+    let ref allowed = MatchOptions::new().policy(PreReleasePolicy::Allowed);
+    let ref include = MatchOptions::new().include_prerelease(true);
+    assert_eq!(*same_triple, MatchOptions::new());
+
+    let ref r = req(">=1.2.3-beta, <2");
+    assert_match_all(
+        r,
+        same_triple,
+        &["1.2.3-beta", "1.2.3-rc.1", "1.2.3", "1.4.0"],
+    );
+    assert_match_none(
+        r,
+        same_triple,
+        &["1.2.4-alpha", "1.9.0-rc.1", "1.2.3-alpha"],
+    );
+    assert_match_all(r, allowed, &["1.2.3-rc.1", "1.2.4-alpha", "1.9.0-rc.1"]);
+    assert_match_none(r, allowed, &["1.2.3-alpha", "1.2.2", "2.0.0-rc.1"]);
+    assert_match_all(r, excluded, &["1.2.3", "1.4.0"]);
+    assert_match_none(r, excluded, &["1.2.3-beta", "1.2.3-rc.1", "1.2.4-alpha"]);
+
+    let ref excluded_included = excluded.include_prerelease(true);
+    for options in &[include, excluded_included] {
+        assert_match_all(r, options, &["1.2.3-rc.1", "1.2.4-alpha", "1.9.0-rc.1"]);
+    }
+
+    let cmp = comparator("^1.2.3");
+    assert!(!cmp.matches(&version("1.3.0-beta")));
+    assert!(cmp.matches_with(&version("1.3.0-beta"), allowed));
+    assert!(!cmp.matches_with(&version("1.2.3-beta"), allowed));
+    assert!(cmp.matches_with(&version("1.3.0"), &excluded.channel(Some("beta"))));
+}
+
+#[test]
+fn test_compare_build() {
+    let ref build = MatchOptions::new().compare_build(true);
+
+    let ref r = req("=1.2.3");
+    assert_match_all(r, &MatchOptions::new(), &["1.2.3", "1.2.3+g8e6aa2a"]);
+    assert_match_all(r, build, &["1.2.3"]);
+    assert_match_none(r, build, &["1.2.3+g8e6aa2a"]);
+
+    let ref r = req(">1.2.3");
+    assert_match_none(r, &MatchOptions::new(), &["1.2.3+g8e6aa2a"]);
+    assert_match_all(r, build, &["1.2.3+g8e6aa2a", "1.2.4"]);
+
+    let ref r = req("<=1.2.3");
+    assert_match_all(r, build, &["1.2.3", "1.2.2+g8e6aa2a"]);
+    assert_match_none(r, build, &["1.2.3+g8e6aa2a"]);
+
+    let ref r = req("=1.2");
+    assert_match_all(r, build, &["1.2.3+g8e6aa2a"]);
+
+    // Build metadata in the requirement is ignored.
+    let ref r = req("=1.2.3+g8e6aa2a");
+    assert_match_all(r, build, &["1.2.3"]);
+    assert_match_none(r, build, &["1.2.3+g8e6aa2a"]);
+}
+
+#[test]
+fn test_plain_caret() {
+    let ref cargo = MatchOptions::new();
+    let ref plain = MatchOptions::new().plain_caret(true);
+
+    let ref r = req("^0.2.3");
+    assert_match_all(r, cargo, &["0.2.3", "0.2.9"]);
+    assert_match_none(r, cargo, &["0.3.0", "0.9.0"]);
+    assert_match_all(r, plain, &["0.2.3", "0.3.0", "0.9.0"]);
+    assert_match_none(r, plain, &["0.2.2", "1.0.0"]);
+
+    let ref r = req("^0.0.3");
+    assert_match_all(r, cargo, &["0.0.3"]);
+    assert_match_none(r, cargo, &["0.0.4", "0.1.0"]);
+    assert_match_all(r, plain, &["0.0.3", "0.0.4", "0.1.0"]);
+    assert_match_none(r, plain, &["0.0.2", "1.0.0"]);
+
+    let ref r = req("^0.0");
+    assert_match_all(r, cargo, &["0.0.0", "0.0.7"]);
+    assert_match_none(r, cargo, &["0.1.0"]);
+    assert_match_all(r, plain, &["0.0.0", "0.1.0", "0.9.9"]);
+    assert_match_none(r, plain, &["1.0.0"]);
+
+    let ref r = req("^0");
+    assert_match_all(r, cargo, &["0.0.0", "0.9.9"]);
+    assert_match_all(r, plain, &["0.0.0", "0.9.9"]);
+    assert_match_none(r, plain, &["1.0.0"]);
+
+    let ref r = req("^1.2.3");
+    assert_match_all(r, plain, &["1.2.3", "1.9.0"]);
+    assert_match_none(r, plain, &["1.2.2", "2.0.0"]);
+
+    let ref r = req("^0.2.3-beta");
+    assert_match_all(r, plain, &["0.2.3-beta.2", "0.5.0"]);
+    assert_match_none(r, plain, &["0.5.0-rc.1", "0.2.3-alpha"]);
+}
+
+#[test]
+fn test_matches_stable() {
+    let ref include = MatchOptions::new().include_prerelease(true);
+    for text in &["*", "^1.2.3", ">=1.2.3-alpha.1, <2", "~1.2", "=1.2.3", "<1"] {
+        let r = req(text);
+        for v in &["0.9.0", "1.2.3", "1.2.3+build", "1.3.0", "2.0.0"] {
+            let v = version(v);
+            assert_eq!(r.matches_stable(&v), r.matches(&v), "{} vs {}", r, v);
+        }
+        for v in &["1.2.3-alpha.2", "1.3.0-beta", "2.0.0-rc.1"] {
+            let v = version(v);
+            assert_eq!(r.matches_stable(&v), r.matches_with(&v, include));
+        }
+    }
+}
+
+#[test]
+fn test_matches_all() {
+    let ref default = MatchOptions::new();
+    let ref beta = MatchOptions::new().channel(Some("beta"));
+    let ref allowed = MatchOptions::new().policy(PreReleasePolicy::Allowed);
+    for text in &["*", "^1.2.3", ">=1.2.3-alpha.1, <2", "~1.2", "=1.2.3", "<1"] {
+        let r = req(text);
+        for v in &["0.9.0", "1.2.3", "1.2.3-alpha.2", "1.3.0-beta", "2.0.0"] {
+            let v = version(v);
+            for options in &[default, beta, allowed] {
+                assert_eq!(
+                    matches_all(&r.comparators, &v, options),
+                    r.matches_with(&v, options),
+                    "{} vs {}",
+                    r,
+                    v,
+                );
+            }
+        }
+    }
+
+    // The pre-release rule looks at the whole slice.
+    let r = req(">=1.2.3-alpha.1, <2");
+    let v = version("1.2.3-alpha.2");
+    assert!(matches_all(&r.comparators, &v, default));
+    assert!(!matches_all(&r.comparators[1..], &v, default));
+}
@@ -0,0 +1,44 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+fn minimum(reqs: &[&str]) -> Option<String> {
+    let reqs = reqs.iter().map(|text| req(text)).collect::<Vec<_>>();
+    semver::minimum_supported(&reqs).map(|version| version.to_string())
+}
+
+#[test]
+fn test_bounded() {
+    assert_eq!(minimum(&[]).unwrap(), "0.0.0");
+    assert_eq!(minimum(&["^1.56"]).unwrap(), "1.56.0");
+    assert_eq!(minimum(&["^1.56", ">=1.60", "<2"]).unwrap(), "1.60.0");
+    assert_eq!(minimum(&[">1.2.3", "~1.2"]).unwrap(), "1.2.4");
+    assert_eq!(minimum(&[">1.2", "*"]).unwrap(), "1.3.0");
+    assert_eq!(minimum(&["<1.0.0"]).unwrap(), "0.0.0");
+}
+
+#[test]
+fn test_conflicting() {
+    assert_eq!(minimum(&["^1.56", "<1.50"]), None);
+    assert_eq!(minimum(&["^0.2", "^0.3"]), None);
+    assert_eq!(minimum(&["<0.0.0"]), None);
+    assert_eq!(minimum(&["=1.0.0", "=1.0.0-rc.1"]), None);
+}
+
+#[test]
+fn test_prerelease() {
+    assert_eq!(minimum(&[">=1.0.0-alpha"]).unwrap(), "1.0.0-alpha");
+    assert_eq!(
+        minimum(&[">1.0.0-alpha", "<1.0.0-beta"]).unwrap(),
+        "1.0.0-alpha.0"
+    );
+    assert_eq!(minimum(&[">1.0.0-alpha", "<1.0.0"]), None);
+    assert_eq!(
+        minimum(&[">=1.0.0-alpha", ">=1.0.0-beta"]).unwrap(),
+        "1.0.0-beta",
+    );
+    // Only one of the requirements admits pre-releases of 1.0.0.
+    assert_eq!(minimum(&[">=1.0.0-alpha", ">=0.9"]).unwrap(), "1.0.0");
+}
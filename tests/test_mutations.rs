@@ -0,0 +1,82 @@
+#![cfg(feature = "testing")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Version, VersionReq};
+
+#[test]
+fn test_version_mutations() {
+    let mutations: Vec<String> = version("1.0.3-beta.2+linux").mutations().collect();
+    assert_eq!(
+        mutations,
+        [
+            "0.0.3-beta.2+linux",
+            "2.0.3-beta.2+linux",
+            "1.1.3-beta.2+linux",
+            "1.0.2-beta.2+linux",
+            "1.0.4-beta.2+linux",
+            "1.0.3+linux",
+            "1.0.3-2.beta+linux",
+            "1.0.3-beta.2",
+            "10.3-beta.2+linux",
+            "1.03-beta.2+linux",
+            "01.0.3-beta.2+linux",
+            "v1.0.3-beta.2+linux",
+            "1.0.3.-beta.2+linux",
+            "1.0.3-beta.2+linux+",
+        ],
+    );
+
+    for text in &mutations[..8] {
+        assert!(Version::parse(text).is_ok(), "{}", text);
+    }
+    for text in &mutations[8..] {
+        assert!(Version::parse(text).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn test_version_mutations_plain() {
+    let mutations: Vec<String> = version("0.0.0").mutations().collect();
+    assert_eq!(
+        mutations,
+        [
+            "1.0.0", "0.1.0", "0.0.1", "0.0.0-0", "0.0.0+0", "00.0", "0.00", "00.0.0", "v0.0.0",
+            "0.0.0.", "0.0.0-", "0.0.0+",
+        ],
+    );
+}
+
+#[test]
+fn test_req_mutations() {
+    let mutations: Vec<String> = req(">=1.2, <2").mutations().collect();
+    assert_eq!(
+        mutations,
+        [
+            ">=0.2, <2",
+            ">=2.2, <2",
+            ">=1.1, <2",
+            ">=1.3, <2",
+            ">1.2, <2",
+            ">=1.2, <1",
+            ">=1.2, <3",
+            ">=1.2, <=2",
+            "<2, >=1.2",
+            ">=12, <2",
+            ">=1.2., <2",
+            ">=1.2, <2.",
+            ">=1.2 <2",
+            ">=1.2, <2,",
+            ">=1.2, <2, *",
+        ],
+    );
+
+    for text in &mutations[..10] {
+        assert!(VersionReq::parse(text).is_ok(), "{}", text);
+    }
+    for text in &mutations[10..] {
+        assert!(VersionReq::parse(text).is_err(), "{}", text);
+    }
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{ByPrecedence, NewestFirst, OldestFirst, Version};
+use std::collections::{BTreeSet, BinaryHeap, HashSet};
+
+const VERSIONS: &[&str] = &["1.0.0+b", "0.9.0", "1.0.0-rc.1", "1.0.0+a", "1.1.0"];
+
+fn versions() -> Vec<Version> {
+    VERSIONS.iter().map(|text| version(text)).collect()
+}
+
+#[test]
+fn test_oldest_newest() {
+    let oldest: Vec<String> = versions()
+        .into_iter()
+        .map(OldestFirst)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|v| v.0.to_string())
+        .collect();
+    assert_eq!(
+        oldest,
+        ["0.9.0", "1.0.0-rc.1", "1.0.0+a", "1.0.0+b", "1.1.0"]
+    );
+
+    let newest: Vec<String> = versions()
+        .into_iter()
+        .map(NewestFirst)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|v| v.0.to_string())
+        .collect();
+    assert_eq!(
+        newest,
+        ["1.1.0", "1.0.0+b", "1.0.0+a", "1.0.0-rc.1", "0.9.0"]
+    );
+
+    let all = versions();
+    let mut heap: BinaryHeap<NewestFirst<&Version>> = all.iter().map(NewestFirst).collect();
+    assert_to_string(heap.pop().unwrap().0, "0.9.0");
+}
+
+#[test]
+fn test_by_precedence() {
+    let all = versions();
+    let set: BTreeSet<ByPrecedence<&Version>> = all.iter().map(ByPrecedence).collect();
+    assert_eq!(set.len(), 4);
+    let hashed: HashSet<ByPrecedence<&Version>> = all.iter().map(ByPrecedence).collect();
+    assert_eq!(hashed.len(), 4);
+
+    assert!(ByPrecedence(version("1.0.0+a")) == ByPrecedence(version("1.0.0+b")));
+    assert!(ByPrecedence(version("1.0.0-rc.1")) < ByPrecedence(version("1.0.0+a")));
+}
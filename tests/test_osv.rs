@@ -0,0 +1,65 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{osv_reqs, OsvEvent, VersionRangeSet};
+
+#[test]
+fn test_osv_reqs() {
+    let reqs = osv_reqs(&[
+        OsvEvent::Fixed("2.0.0"),
+        OsvEvent::Introduced("1.0.0-rc.1"),
+        OsvEvent::Introduced("3.0.0"),
+        OsvEvent::Fixed("1.2.1"),
+    ])
+    .unwrap();
+    assert_eq!(reqs.len(), 2);
+    assert_to_string(&reqs[0], ">=1.0.0-rc.1, <1.2.1");
+    assert_to_string(&reqs[1], ">=3.0.0");
+
+    let reqs = osv_reqs(&[OsvEvent::Introduced("0")]).unwrap();
+    assert_to_string(&reqs[0], "*");
+
+    let reqs = osv_reqs(&[OsvEvent::Introduced("1.0.0"), OsvEvent::Fixed("1.0.0")]).unwrap();
+    assert!(reqs.is_empty());
+
+    let reqs = osv_reqs(&[OsvEvent::Fixed("1.0.0")]).unwrap();
+    assert!(reqs.is_empty());
+
+    let err = osv_reqs(&[OsvEvent::Introduced("v1.0")]).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'v' while parsing major version number",
+    );
+    assert!(osv_reqs(&[OsvEvent::Fixed("0")]).is_err());
+}
+
+#[test]
+fn test_from_osv() {
+    let first = [
+        OsvEvent::Introduced("0"),
+        OsvEvent::Fixed("0.4.8"),
+        OsvEvent::Introduced("1.0.0"),
+        OsvEvent::LastAffected("1.3.0"),
+    ];
+    let second = [OsvEvent::Introduced("1.2.0"), OsvEvent::Fixed("1.5.0")];
+    let set = VersionRangeSet::from_osv(vec![&first[..], &second[..]]).unwrap();
+
+    for text in &[
+        "0.0.0",
+        "0.4.7",
+        "0.4.8-rc",
+        "1.0.0",
+        "1.3.0",
+        "1.3.1-beta",
+        "1.4.9",
+    ] {
+        assert!(set.contains(&version(text)), "{}", text);
+    }
+    for text in &["0.4.8", "0.9.0", "1.0.0-rc", "1.5.0", "2.0.0"] {
+        assert!(!set.contains(&version(text)), "{}", text);
+    }
+
+    assert!(VersionRangeSet::from_osv(vec![&[OsvEvent::Fixed("x")][..]]).is_err());
+}
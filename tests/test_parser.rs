@@ -0,0 +1,184 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::parser::{self, Span};
+
+fn span(start: usize, end: usize) -> Span {
+    Span { start, end }
+}
+
+#[test]
+fn test_numeric_identifier() {
+    let parsed = parser::parse_numeric_identifier("1024.5").unwrap();
+    assert_eq!(parsed.value, 1024);
+    assert_eq!(parsed.span, span(0, 4));
+    assert_eq!(parsed.rest, ".5");
+
+    let err = parser::parse_numeric_identifier("0012").unwrap_err();
+    assert_to_string(&err, "invalid leading zero in numeric identifier");
+    assert_eq!(err.span(), span(0, 4));
+
+    let err = parser::parse_numeric_identifier("99999999999999999999").unwrap_err();
+    assert_to_string(&err, "value of numeric identifier exceeds u64::MAX");
+    assert_eq!(err.span(), span(0, 20));
+
+    let err = parser::parse_numeric_identifier("x").unwrap_err();
+    assert_eq!(err.span(), span(0, 1));
+
+    let err = parser::parse_numeric_identifier("").unwrap_err();
+    assert_to_string(
+        &err,
+        "unexpected end of input while parsing numeric identifier",
+    );
+    assert_eq!(err.span(), span(0, 0));
+}
+
+#[test]
+fn test_prerelease_and_build() {
+    let parsed = parser::parse_prerelease("rc.1+build.5").unwrap();
+    assert_eq!(parsed.value, prerelease("rc.1"));
+    assert_eq!(parsed.span, span(0, 4));
+    assert_eq!(parsed.rest, "+build.5");
+
+    let parsed = parser::parse_prerelease("+build").unwrap();
+    assert!(parsed.value.is_empty());
+    assert_eq!(parsed.span, span(0, 0));
+
+    let err = parser::parse_prerelease("alpha.01.2").unwrap_err();
+    assert_to_string(&err, "invalid leading zero in pre-release identifier");
+    assert_eq!(err.span(), span(6, 8));
+
+    let parsed = parser::parse_build("build.01 rest").unwrap();
+    assert_eq!(parsed.value, build_metadata("build.01"));
+    assert_eq!(parsed.span, span(0, 8));
+    assert_eq!(parsed.rest, " rest");
+
+    let err = parser::parse_build("a..b").unwrap_err();
+    assert_to_string(&err, "empty identifier segment in build metadata");
+    assert_eq!(err.span(), span(2, 2));
+}
+
+#[test]
+fn test_comparator() {
+    let parsed = parser::parse_comparator("~ 1.2.3-beta  , <2").unwrap();
+    assert_eq!(parsed.value, comparator("~1.2.3-beta"));
+    assert_eq!(parsed.span, span(0, 12));
+    assert_eq!(parsed.rest, ", <2");
+
+    let err = parser::parse_comparator(">=1.02").unwrap_err();
+    assert_to_string(&err, "invalid leading zero in minor version number");
+    assert_eq!(err.span(), span(4, 6));
+
+    let err = parser::parse_comparator("^1.").unwrap_err();
+    assert_eq!(err.span(), span(3, 3));
+
+    let err = parser::parse_comparator("1.*.3").unwrap_err();
+    assert_to_string(&err, "unexpected character after wildcard in version req");
+    assert_eq!(err.span(), span(4, 5));
+
+    let err = parser::parse_comparator("=1.2.3-").unwrap_err();
+    assert_eq!(err.span(), span(7, 7));
+}
+
+#[test]
+fn test_req_tolerant() {
+    // Agrees with VersionReq::parse on whether the input is valid, on the
+    // comparators if it is, and on the first error if it is not.
+    for input in &[
+        "",
+        " ",
+        "*",
+        " * ",
+        "x",
+        "1.2.3",
+        ">=1.2, <2",
+        " ~1.2 ,  ^1.2.3-beta.1 ",
+        "=1.2.3+build",
+        "1.*",
+        "1.2.x",
+        "*, 1.0",
+        "1.0, *",
+        "* 1.0",
+        ">=1.0 <2.0",
+        "1.0,",
+        "1.0, ",
+        ",1.0",
+        ">=",
+        ">=1.",
+        "1.2.3-",
+        "01.2",
+        "1.2.3-beta..1",
+        "a.b.c",
+        "1.2.3 foo",
+    ] {
+        let tolerant = parser::parse_req_tolerant(input);
+        match semver::VersionReq::parse(input) {
+            Ok(req) => {
+                assert!(tolerant.is_valid(), "{:?}: {:?}", input, tolerant);
+                assert_eq!(tolerant.to_req(), req, "{:?}", input);
+            }
+            Err(err) => {
+                let first = tolerant.diagnostics.first().expect(input);
+                assert_eq!(first.to_string(), err.to_string(), "{:?}", input);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_req_tolerant_recovery() {
+    use semver::parser::ReqNode;
+
+    let summary = |input: &str| {
+        let tolerant = parser::parse_req_tolerant(input);
+        let nodes = tolerant
+            .nodes
+            .iter()
+            .map(|node| match node {
+                ReqNode::Comparator { value, span } => (value.to_string(), *span),
+                ReqNode::Error { span } => ("!".to_owned(), *span),
+            })
+            .collect::<Vec<_>>();
+        let diagnostics = tolerant
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.span())
+            .collect::<Vec<_>>();
+        (nodes, diagnostics)
+    };
+
+    assert_eq!(
+        summary(">=1.2 <2, ^1.4"),
+        (
+            vec![
+                (">=1.2".to_owned(), span(0, 5)),
+                ("<2".to_owned(), span(6, 8)),
+                ("^1.4".to_owned(), span(10, 14)),
+            ],
+            vec![span(6, 7)],
+        ),
+    );
+    assert_eq!(
+        summary("1.0, >=, ~1.2."),
+        (
+            vec![
+                ("^1.0".to_owned(), span(0, 3)),
+                ("!".to_owned(), span(5, 7)),
+                ("!".to_owned(), span(9, 14)),
+            ],
+            vec![span(7, 8), span(14, 14)],
+        ),
+    );
+    assert_eq!(
+        summary("1.0, "),
+        (
+            vec![
+                ("^1.0".to_owned(), span(0, 3)),
+                ("!".to_owned(), span(5, 5))
+            ],
+            vec![span(5, 5)],
+        ),
+    );
+}
@@ -0,0 +1,73 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{PartialVersion, Precision};
+
+fn partial(text: &str) -> PartialVersion {
+    PartialVersion::parse(text).unwrap()
+}
+
+#[test]
+fn test_truncated() {
+    let v = version("1.2.9-beta+x");
+    assert_eq!(v.truncated(Precision::Major), version("1.0.0"));
+    assert_eq!(v.truncated(Precision::Minor), version("1.2.0"));
+    assert_eq!(v.truncated(Precision::Patch), version("1.2.9"));
+
+    let v = version("0.0.0-0");
+    assert_eq!(v.truncated(Precision::Patch), version("0.0.0"));
+}
+
+#[test]
+fn test_matches_prefix() {
+    let v = version("1.2.9-beta+x");
+    assert!(v.matches_prefix(&partial("1")));
+    assert!(v.matches_prefix(&partial("1.2")));
+    assert!(v.matches_prefix(&partial("1.2.9")));
+    assert!(!v.matches_prefix(&partial("1.20")));
+    assert!(!v.matches_prefix(&partial("1.2.8")));
+    assert!(!v.matches_prefix(&partial("2")));
+
+    // A patch without a minor is disregarded.
+    assert!(v.matches_prefix(&PartialVersion::new(1, None, Some(0))));
+}
+
+#[test]
+fn test_parse() {
+    for text in ["1", "1.2", "1.2.3", "0.0.0", "18446744073709551615.0"].iter() {
+        assert_to_string(partial(text), text);
+    }
+
+    let p = partial("1.2");
+    assert_eq!((p.major, p.minor, p.patch), (1, Some(2), None));
+    assert_eq!(p.precision(), Precision::Minor);
+    assert_eq!(partial("1").precision(), Precision::Major);
+    assert_eq!(partial("1.2.3").precision(), Precision::Patch);
+
+    let err = |text: &str| PartialVersion::parse(text).unwrap_err();
+    assert_to_string(err(""), "empty string, expected a semver version");
+    assert_to_string(
+        err("1.2.*"),
+        "unexpected character '*' while parsing patch version number",
+    );
+    assert_to_string(
+        err("1.2.3.4"),
+        "unexpected character '.' after patch version number",
+    );
+    assert_to_string(
+        err("1.2.3-rc.1"),
+        "unexpected character '-' after patch version number",
+    );
+    assert_to_string(err("1.02"), "invalid leading zero in minor version number");
+}
+
+#[test]
+fn test_of() {
+    let v = version("1.2.9-beta+x");
+    assert_to_string(PartialVersion::of(&v, Precision::Minor), "1.2");
+    assert_to_string(PartialVersion::of(&v, Precision::Major), "1");
+    assert_eq!(PartialVersion::from(&v), partial("1.2.9"));
+    assert_eq!(format!("{:>6}", partial("1.2")), "   1.2");
+}
@@ -0,0 +1,36 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_partition_between() {
+    let all = versions(&[
+        "2.1.0",
+        "1.2.3",
+        "1.2.5",
+        "1.2.4",
+        "1.10.0",
+        "1.3.0",
+        "2.0.0-rc.1",
+        "2.0.0",
+        "0.9.0",
+    ]);
+    let partitioned = semver::partition_between(&version("1.2.3"), &version("2.0.0"), &all);
+    assert_eq!(partitioned.patch, versions(&["1.2.4", "1.2.5"]));
+    assert_eq!(partitioned.minor, versions(&["1.3.0", "1.10.0"]));
+    assert_eq!(partitioned.major, versions(&["2.0.0"]));
+    assert_eq!(partitioned.prerelease, versions(&["2.0.0-rc.1"]));
+}
+
+#[test]
+fn test_partition_precedence() {
+    // Build metadata does not make a version newer than the anchor.
+    let all = versions(&["1.0.0+build", "1.0.1+build"]);
+    let partitioned = semver::partition_between(&version("1.0.0"), &version("1.0.1"), &all);
+    assert_eq!(partitioned.patch, versions(&["1.0.1+build"]));
+
+    let partitioned = semver::partition_between(&version("2.0.0"), &version("1.0.0"), &all);
+    assert!(partitioned.patch.is_empty());
+}
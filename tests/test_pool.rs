@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::ReqPool;
+
+#[test]
+fn test_intern() {
+    let mut pool = ReqPool::new();
+    assert!(pool.is_empty());
+
+    let a = pool.intern(req("^1.2"));
+    let b = pool.intern(req(">=1.2.0, <2.0.0-0"));
+    let c = pool.intern(req("^1.2"));
+    let d = pool.intern(req("^1.2.0"));
+    assert_eq!(a, c);
+    assert_ne!(a, b);
+    assert_ne!(a, d);
+    assert_eq!((a.index(), b.index(), d.index()), (0, 1, 2));
+    assert_eq!(pool.len(), 3);
+
+    assert_eq!(pool.id(&req(">=1.2.0, <2.0.0-0")), Some(b));
+    assert_eq!(pool.id(&req("<2.0.0-0, >=1.2.0")), None);
+}
+
+#[test]
+fn test_lookup() {
+    let mut pool = ReqPool::new();
+    let id = pool.intern(req(">=1.0.0-rc.1, <1.5"));
+    assert_eq!(pool.get(id).to_string(), ">=1.0.0-rc.1, <1.5");
+    assert_eq!(pool.compiled(id), &req(">=1.0.0-rc.1, <1.5").compile());
+
+    for text in ["1.0.0-rc.2", "1.0.0", "1.4.9", "1.5.0", "1.2.0-rc.1"].iter() {
+        let v = version(text);
+        assert_eq!(pool.matches(id, &v), pool.get(id).matches(&v), "{}", text);
+    }
+}
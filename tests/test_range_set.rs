@@ -0,0 +1,132 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{VersionRangeSet, VersionReq};
+
+const REQS: &[&str] = &[
+    ">=1.0.0, <1.4.2",
+    "=2.0.0-rc.1",
+    ">=2.3.0, <2.3.5",
+    "~0.4",
+    "^0.0.3",
+    "1.4.2",
+    ">3.1.4-beta.2, <=3.1.4",
+    "=5.2",
+    ">6",
+    "<0.2",
+    ">=4.0.0, <3.0.0",
+    "<=2.3.5, >2.3.5",
+];
+
+const VERSIONS: &[&str] = &[
+    "0.0.0",
+    "0.1.9",
+    "0.2.0",
+    "0.4.0",
+    "0.4.7",
+    "0.5.0",
+    "0.0.3",
+    "0.0.4",
+    "0.9.9",
+    "1.0.0",
+    "1.0.0-alpha",
+    "1.4.1",
+    "1.4.1+build",
+    "1.4.2",
+    "1.4.2-rc",
+    "1.9.0",
+    "2.0.0-rc.1",
+    "2.0.0-rc.2",
+    "2.0.0",
+    "2.3.0",
+    "2.3.4",
+    "2.3.5",
+    "3.1.3",
+    "3.1.4-beta.2",
+    "3.1.4-beta.3",
+    "3.1.4",
+    "3.1.5",
+    "3.5.0",
+    "5.2.0",
+    "5.2.9",
+    "5.3.0",
+    "6.9.9",
+    "7.0.0-alpha",
+    "7.0.0",
+    "99.0.0",
+];
+
+#[test]
+fn test_contains() {
+    let reqs: Vec<VersionReq> = REQS.iter().map(|text| req(text)).collect();
+    let set = VersionRangeSet::from_reqs(&reqs);
+    for text in VERSIONS {
+        let version = version(text);
+        let expected = reqs.iter().any(|req| req.matches(&version));
+        assert_eq!(set.contains(&version), expected, "{}", text);
+    }
+}
+
+#[test]
+fn test_merged() {
+    let set: VersionRangeSet = ["<1.0.0", ">=1.0.0, <2", "=2.9", ">=3.0.0"]
+        .iter()
+        .map(|text| req(text))
+        .collect();
+    for text in &["0.0.0", "1.0.0", "1.9.9", "2.9.5", "3.0.0", "1000.0.0"] {
+        assert!(set.contains(&version(text)), "{}", text);
+    }
+    assert!(!set.contains(&version("2.0.0")));
+    assert!(!set.contains(&version("2.10.0")));
+
+    let set: VersionRangeSet = vec![req("*")].into_iter().collect();
+    assert!(set.contains(&version("0.0.0")));
+    assert!(!set.contains(&version("1.0.0-rc")));
+}
+
+#[test]
+fn test_empty() {
+    assert!(VersionRangeSet::new().is_empty());
+    assert!(!VersionRangeSet::new().contains(&version("1.0.0")));
+
+    let set: VersionRangeSet = vec![req(">2, <1")].into_iter().collect();
+    assert!(set.is_empty());
+    assert!(!set.contains(&version("1.5.0")));
+}
+
+#[test]
+fn test_covers() {
+    let set = [">=1.0.0, <1.4.2", ">=1.4.2", "=1.4.2-rc.1"]
+        .iter()
+        .map(|text| req(text))
+        .collect::<VersionRangeSet>();
+    let published = [
+        "0.1.0",
+        "1.0.0",
+        "1.4.2-rc.1",
+        "1.4.2-rc.2",
+        "1.4.2",
+        "3.0.0",
+    ]
+    .iter()
+    .map(|text| version(text))
+    .collect::<Vec<_>>();
+
+    let report = set.covers(&published);
+    assert!(!report.is_complete());
+    assert_eq!(
+        report.covered,
+        [
+            version("1.0.0"),
+            version("1.4.2-rc.1"),
+            version("1.4.2"),
+            version("3.0.0")
+        ],
+    );
+    assert_eq!(report.uncovered, [version("0.1.0"), version("1.4.2-rc.2")]);
+
+    let report = VersionRangeSet::new().covers(&[]);
+    assert!(report.is_complete());
+}
@@ -0,0 +1,36 @@
+#![allow(clippy::toplevel_ref_arg, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::raw;
+
+#[test]
+fn test_ignores_op() {
+    let ref cmp = comparator("<1.2.3");
+    assert!(raw::matches_exact(cmp, &version("1.2.3")));
+    assert!(raw::matches_greater(cmp, &version("1.2.4")));
+    assert!(raw::matches_caret(cmp, &version("1.9.0")));
+    assert!(raw::matches_tilde(cmp, &version("1.2.9")));
+    assert!(!raw::matches_tilde(cmp, &version("1.3.0")));
+    assert!(!raw::matches_less(cmp, &version("1.2.3")));
+}
+
+#[test]
+fn test_no_prerelease_rule() {
+    let ref cmp = comparator(">=1.0.0");
+    let ref version = version("1.5.0-rc.1");
+    assert!(!cmp.matches(version));
+    assert!(raw::matches_greater(cmp, version));
+    assert!(raw::matches_caret(cmp, version));
+
+    let ref cmp = comparator("=1.2.3-rc.1");
+    assert!(raw::matches_exact(
+        cmp,
+        &semver::Version::parse("1.2.3-rc.1").unwrap()
+    ));
+    assert!(raw::matches_less(
+        cmp,
+        &semver::Version::parse("1.2.3-beta").unwrap()
+    ));
+}
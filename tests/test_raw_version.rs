@@ -0,0 +1,50 @@
+#![cfg(feature = "zerocopy")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::RawVersion;
+use std::mem;
+use zerocopy::{AsBytes, FromBytes};
+
+#[test]
+fn test_roundtrip() {
+    let mut strings = Vec::new();
+    let records = [
+        "1.0.0",
+        "1.1.0-beta.1",
+        "2.0.0+build.5",
+        "3.0.0-rc.1+sha.abc",
+    ]
+    .iter()
+    .map(|v| RawVersion::encode(&version(v), &mut strings).unwrap())
+    .collect::<Vec<_>>();
+    assert_eq!(strings, b"beta.1build.5rc.1sha.abc");
+    assert_eq!(mem::size_of::<RawVersion>(), 40);
+
+    let bytes = records.as_bytes().to_vec();
+    let table = RawVersion::slice_from(&bytes).unwrap();
+    assert_eq!(table, &records[..]);
+
+    assert_eq!(table[0].pre(&strings), Some(""));
+    assert_eq!(table[3].pre(&strings), Some("rc.1"));
+    assert_eq!(table[3].build(&strings), Some("sha.abc"));
+    let decoded = table[2].decode(&strings).unwrap();
+    assert_eq!(decoded, version("2.0.0+build.5"));
+}
+
+#[test]
+fn test_decode_invalid() {
+    let mut strings = Vec::new();
+    let record = RawVersion::encode(&version("1.0.0-rc.1"), &mut strings).unwrap();
+
+    let err = record.decode(b"rc").unwrap_err();
+    assert_to_string(err, "string table offset out of bounds or not UTF-8");
+
+    let err = record.decode(b"rc..").unwrap_err();
+    assert_to_string(err, "empty identifier segment in pre-release identifier");
+
+    let err = record.decode(b"rc\xFF1").unwrap_err();
+    assert_to_string(err, "string table offset out of bounds or not UTF-8");
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "redis")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use redis::{FromRedisValue, ToRedisArgs, Value};
+use semver::{Version, VersionReq};
+
+#[test]
+fn test_version() {
+    let args = version("1.4.2-rc.1+build.5").to_redis_args();
+    assert_eq!(args, vec![b"1.4.2-rc.1+build.5".to_vec()]);
+
+    let value = Value::BulkString(b"1.4.2-rc.1".to_vec());
+    let parsed = Version::from_redis_value(value).unwrap();
+    assert_eq!(parsed, version("1.4.2-rc.1"));
+
+    let value = Value::SimpleString("1.4".to_owned());
+    let err = Version::from_redis_value(value).unwrap_err();
+    assert_to_string(
+        err,
+        "Incompatible type - invalid version \"1.4\": unexpected end of input while parsing minor version number",
+    );
+}
+
+#[test]
+fn test_version_req() {
+    let args = req(">=1.2.3, <2").to_redis_args();
+    assert_eq!(args, vec![b">=1.2.3, <2".to_vec()]);
+
+    let value = Value::BulkString(b"^1.2".to_vec());
+    let parsed = VersionReq::from_redis_value(value).unwrap();
+    assert_eq!(parsed, req("^1.2"));
+}
+
+#[test]
+fn test_score() {
+    let versions = [
+        "0.0.0-alpha",
+        "0.0.0",
+        "0.0.1",
+        "0.1.0",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.1048575",
+        "1.65535.0",
+        "65535.65535.1048575",
+    ];
+    for pair in versions.windows(2) {
+        let lo = version(pair[0]).redis_score().unwrap();
+        let hi = version(pair[1]).redis_score().unwrap();
+        assert!(lo < hi, "{} < {}", pair[0], pair[1]);
+    }
+
+    assert_eq!(version("0.0.0-alpha").redis_score(), Some(0.0));
+    assert_eq!(
+        version("65535.65535.1048575").redis_score(),
+        Some(9007199254740991.0),
+    );
+    assert_eq!(
+        version("1.0.0-alpha").redis_score(),
+        version("1.0.0-beta").redis_score(),
+    );
+    assert_eq!(version("65536.0.0").redis_score(), None);
+    assert_eq!(version("0.65536.0").redis_score(), None);
+    assert_eq!(version("0.0.1048576").redis_score(), None);
+}
@@ -0,0 +1,38 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_compatible() {
+    let req = req("^1.4");
+    for string in &["1.4.0", "1.9.3+build"] {
+        semver::require_compatible("plugin", &version(string), &req).unwrap();
+    }
+}
+
+#[test]
+fn test_out_of_range() {
+    let req = req(">=1.4.2, <2");
+    let err = semver::require_compatible("plugin", &version("1.3.9"), &req).unwrap_err();
+    assert_eq!(err.component(), "plugin");
+    assert_eq!(*err.found(), version("1.3.9"));
+    assert_eq!(*err.req(), req);
+    assert_eq!(err.unsatisfied(), [comparator(">=1.4.2")]);
+    assert_to_string(
+        err,
+        "plugin version 1.3.9 is not compatible with the required >=1.4.2, <2: version does not satisfy >=1.4.2",
+    );
+}
+
+#[test]
+fn test_prerelease() {
+    let req = req("^1.4");
+    let err = semver::require_compatible("server", &version("1.5.0-rc.1"), &req).unwrap_err();
+    assert!(err.unsatisfied().is_empty());
+    assert_to_string(
+        err,
+        "server version 1.5.0-rc.1 is not compatible with the required ^1.4: pre-release versions are only compatible with a requirement that names a pre-release of 1.5.0",
+    );
+}
@@ -0,0 +1,52 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{
+    select, ClosestToCurrent, FewestBreakingChanges, HighestAny, HighestStable, SelectionContext,
+    SelectionPolicy, Version,
+};
+
+fn best<P: SelectionPolicy>(all: &[Version], policy: P, current: Option<&str>) -> String {
+    let current = current.map(version);
+    let ctx = SelectionContext {
+        current: current.as_ref(),
+        req: None,
+    };
+    select(all, &policy, &ctx).unwrap().to_string()
+}
+
+#[test]
+fn test_highest() {
+    let all = &versions(&["1.0.0", "1.2.0", "1.3.0-rc.1", "0.9.0"]);
+    assert_eq!(best(all, HighestStable, None), "1.2.0");
+    assert_eq!(best(all, HighestAny, None), "1.3.0-rc.1");
+
+    let req = &req("<1.2");
+    let ctx = SelectionContext {
+        current: None,
+        req: Some(req),
+    };
+    assert_eq!(select(all, &HighestAny, &ctx).unwrap().to_string(), "1.0.0");
+
+    let pre = &versions(&["1.0.0-alpha"]);
+    assert!(select(pre, &HighestStable, &SelectionContext::new()).is_none());
+    assert!(select(&[], &HighestAny, &SelectionContext::new()).is_none());
+}
+
+#[test]
+fn test_relative_to_current() {
+    let all = &versions(&[
+        "0.3.1", "0.3.4", "0.3.9-rc", "0.4.0", "1.0.0", "1.5.0", "2.0.0",
+    ]);
+
+    assert_eq!(best(all, FewestBreakingChanges, Some("0.3.1")), "0.3.4");
+    assert_eq!(best(all, ClosestToCurrent, Some("0.3.1")), "0.3.1");
+
+    assert_eq!(best(all, FewestBreakingChanges, Some("1.1.0")), "1.5.0");
+    assert_eq!(best(all, ClosestToCurrent, Some("1.1.0")), "1.0.0");
+
+    assert_eq!(best(all, FewestBreakingChanges, None), "2.0.0");
+    assert_eq!(best(all, ClosestToCurrent, None), "2.0.0");
+}
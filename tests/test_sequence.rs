@@ -0,0 +1,69 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::SequenceIssue;
+
+#[test]
+fn test_clean() {
+    let published = versions(&[
+        "0.1.0",
+        "0.1.1",
+        "0.2.0-alpha",
+        "0.2.0",
+        "1.0.0-rc.1",
+        "1.0.0-rc.2",
+        "1.0.0",
+        "2.0.0",
+        "1.0.1",
+        "0.2.1",
+    ]);
+    assert_eq!(semver::validate_sequence(&published), []);
+}
+
+#[test]
+fn test_issues() {
+    let published = versions(&[
+        "0.1.5",
+        "0.1.2",
+        "1.0.0+a",
+        "1.0.0+b",
+        "1.0.0",
+        "1.0.0-rc.1",
+        "1.2.0",
+        "1.1.9",
+        "1.1.9",
+    ]);
+    let issues = semver::validate_sequence(&published);
+    assert_eq!(
+        issues,
+        [
+            SequenceIssue::NotMonotonic {
+                index: 1,
+                previous: 0,
+            },
+            SequenceIssue::Reused {
+                index: 3,
+                earlier: 2,
+            },
+            SequenceIssue::Reused {
+                index: 4,
+                earlier: 2,
+            },
+            SequenceIssue::PrereleaseAfterStable {
+                index: 5,
+                release: 2,
+            },
+            SequenceIssue::NotMonotonic {
+                index: 7,
+                previous: 6,
+            },
+            SequenceIssue::Reused {
+                index: 8,
+                earlier: 7,
+            },
+        ],
+    );
+    assert_eq!(issues[2].index(), 4);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_req_map() {
+    let json = r#"{"serde": "^1.0.100", "rand": ">=0.8, <0.10"}"#;
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let map = semver::req_map::deserialize(&mut deserializer).unwrap();
+
+    let mut expected = BTreeMap::new();
+    expected.insert("rand".to_owned(), req(">=0.8, <0.10"));
+    expected.insert("serde".to_owned(), req("^1.0.100"));
+    assert_eq!(map, expected);
+
+    let mut serializer = serde_json::Serializer::new(Vec::new());
+    semver::req_map::serialize(&map, &mut serializer).unwrap();
+    let json = String::from_utf8(serializer.into_inner()).unwrap();
+    assert_eq!(json, r#"{"rand":">=0.8, <0.10","serde":"^1.0.100"}"#);
+}
+
+#[test]
+fn test_req_map_errors() {
+    let json = "{\n  \"serde\": \"^1.0\",\n  \"rand\": \"latest\"\n}";
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let err = semver::req_map::deserialize(&mut deserializer).unwrap_err();
+    assert_to_string(
+        err,
+        "invalid version requirement for \"rand\": unexpected character 'l' while parsing major version number at line 3 column 18",
+    );
+
+    let json = r#"{"serde": 1}"#;
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let err = semver::req_map::deserialize(&mut deserializer).unwrap_err();
+    assert_to_string(
+        err,
+        "invalid type: integer `1`, expected semver version requirement for \"serde\" at line 1 column 11",
+    );
+}
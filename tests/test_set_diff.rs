@@ -0,0 +1,54 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::diff_version_sets;
+
+#[test]
+fn test_diff_version_sets() {
+    let old = versions(&["1.0.0", "1.1.0+b1", "1.2.0-rc.1", "0.9.0"]);
+    let new = versions(&["2.0.0-alpha", "1.1.0+b2", "1.0.0", "1.2.0", "1.0.0"]);
+
+    let diff = diff_version_sets(&old, &new);
+    assert_eq!(diff.added, versions(&["1.2.0", "2.0.0-alpha"]));
+    assert_eq!(diff.removed, versions(&["0.9.0", "1.2.0-rc.1"]));
+    assert_eq!(diff.metadata_changed.len(), 1);
+    assert_eq!(diff.metadata_changed[0].old, version("1.1.0+b1"));
+    assert_eq!(diff.metadata_changed[0].new, version("1.1.0+b2"));
+    assert!(!diff.is_empty());
+
+    let diff = diff_version_sets(&new, &old);
+    assert_eq!(diff.added, versions(&["0.9.0", "1.2.0-rc.1"]));
+    assert_eq!(diff.metadata_changed[0].old, version("1.1.0+b2"));
+
+    assert!(diff_version_sets(&old, &old).is_empty());
+    assert!(diff_version_sets(&[], &[]).is_empty());
+    assert_eq!(diff_version_sets(&[], &old).added.len(), 4);
+}
+
+#[test]
+fn test_build_metadata_variants() {
+    let old = versions(&["1.0.0+a", "1.0.0+b", "1.0.0+c"]);
+    let new = versions(&["1.0.0+b", "1.0.0+d", "1.0.0"]);
+
+    let diff = diff_version_sets(&old, &new);
+    let changes: Vec<(String, String)> = diff
+        .metadata_changed
+        .iter()
+        .map(|change| (change.old.to_string(), change.new.to_string()))
+        .collect();
+    assert_eq!(
+        changes,
+        [
+            ("1.0.0+a".to_owned(), "1.0.0".to_owned()),
+            ("1.0.0+c".to_owned(), "1.0.0+d".to_owned()),
+        ],
+    );
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+
+    let diff = diff_version_sets(&old, &versions(&["1.0.0+b"]));
+    assert_eq!(diff.removed, versions(&["1.0.0+a", "1.0.0+c"]));
+    assert!(diff.metadata_changed.is_empty());
+}
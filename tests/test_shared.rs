@@ -0,0 +1,54 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::SharedVersion;
+use std::collections::{BTreeSet, HashMap};
+
+#[test]
+fn test_delegates() {
+    let a = SharedVersion::new(version("1.0.0-beta.11"));
+    let b = SharedVersion::from(version("1.0.0-beta.2"));
+    let c = SharedVersion::new(version("1.0.0-beta.2"));
+
+    assert_eq!(b, c);
+    assert!(!SharedVersion::ptr_eq(&b, &c));
+    assert!(b < a);
+    assert_eq!(a.to_string(), "1.0.0-beta.11");
+    assert_eq!(
+        format!("{:?}", a),
+        format!("{:?}", version("1.0.0-beta.11"))
+    );
+
+    let set: BTreeSet<SharedVersion> = vec![a.clone(), b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+
+    let mut map = HashMap::new();
+    map.insert(a.clone(), "latest");
+    assert_eq!(map.get(&version("1.0.0-beta.11")), Some(&"latest"));
+}
+
+#[test]
+fn test_into_inner() {
+    let a = SharedVersion::new(version("1.2.3+build"));
+    let b = a.clone();
+    assert!(SharedVersion::ptr_eq(&a, &b));
+    assert_eq!(a.into_inner(), version("1.2.3+build"));
+    assert_eq!(b.into_inner(), version("1.2.3+build"));
+}
+
+#[cfg(feature = "intern")]
+#[test]
+fn test_pooled() {
+    let a = SharedVersion::pooled(version("9.8.7-pooled"));
+    let b = SharedVersion::pooled(version("9.8.7-pooled"));
+    let c = SharedVersion::pooled(version("9.8.7-pooled+build"));
+    assert!(SharedVersion::ptr_eq(&a, &b));
+    assert!(!SharedVersion::ptr_eq(&a, &c));
+
+    drop(c);
+    SharedVersion::trim_pool();
+    let d = SharedVersion::pooled(version("9.8.7-pooled"));
+    assert!(SharedVersion::ptr_eq(&a, &d));
+}
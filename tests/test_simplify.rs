@@ -0,0 +1,72 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+fn simplify(reqs: &[&str]) -> Vec<String> {
+    let reqs = reqs.iter().map(|text| req(text)).collect::<Vec<_>>();
+    semver::simplify_union(&reqs)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+#[test]
+fn test_subsumed() {
+    assert_eq!(simplify(&["^1.2", "^1.4"]), ["^1.2"]);
+    assert_eq!(simplify(&["^1.4", "^1.2"]), ["^1.2"]);
+    assert_eq!(
+        simplify(&["~1.2.3", ">=1.0.0, <2", "=1.9.9"]),
+        [">=1.0.0, <2"]
+    );
+    assert_eq!(simplify(&["^0.2", "^0.3", "0.2.*"]), ["^0.2", "^0.3"]);
+    assert_eq!(simplify(&["*", "^1", ">=0.1.0"]), ["*"]);
+}
+
+#[test]
+fn test_equivalent() {
+    assert_eq!(simplify(&["^1.2", "1.2", ">=1.2.0, <2.0.0-0"]), ["^1.2"]);
+    assert_eq!(simplify(&["=1.2", "1.2.*", "~1.2"]), ["=1.2"]);
+}
+
+#[test]
+fn test_overlapping() {
+    assert_eq!(
+        simplify(&[">=1.0.0, <1.5", ">=1.3.0, <2"]),
+        [">=1.0.0, <1.5", ">=1.3.0, <2"],
+    );
+}
+
+#[test]
+fn test_prerelease() {
+    // ^1.2 does not match any pre-release.
+    assert_eq!(simplify(&["=1.4.0-rc.1", "^1.2"]), ["=1.4.0-rc.1", "^1.2"]);
+    assert_eq!(simplify(&["=1.4.0-rc.1", "^1.4.0-alpha"]), ["^1.4.0-alpha"],);
+    // Only pre-releases of 1.4.0 match ^1.4.0-alpha, not those of 1.5.0.
+    assert_eq!(
+        simplify(&["^1.4.0-alpha", ">=1.4.0-alpha, <1.5.0-beta"]),
+        ["^1.4.0-alpha", ">=1.4.0-alpha, <1.5.0-beta"],
+    );
+    assert_eq!(
+        simplify(&[">=1.4.0-alpha, <1.5.0-beta", "^1.4.0-alpha, <1.5.0"]),
+        [">=1.4.0-alpha, <1.5.0-beta"],
+    );
+}
+
+#[test]
+fn test_redundant_comparators() {
+    let redundant = |text: &str| req(text).redundant_comparators();
+    assert_eq!(redundant(">=1.0, ^1.2"), [0]);
+    assert_eq!(redundant("^1.2, >=1.0"), [1]);
+    assert_eq!(redundant("^1.2, ^1.2"), [1]);
+    assert_eq!(redundant(">=1.2.0, <2.0.0-0, ^1.2"), [2]);
+    assert_eq!(redundant("~1.2.3, <1.5, >1.0"), [1, 2]);
+    assert!(redundant(">=1.2, <1.8").is_empty());
+    assert!(redundant(">=0.0.0").is_empty());
+    assert!(redundant("*").is_empty());
+
+    assert_eq!(redundant(">=1.0, >=1.2.0-rc.1"), [0]);
+    assert!(redundant(">=1.2.0-rc.1, <1.2.0").is_empty());
+    assert_eq!(redundant(">=1.2.0-rc.1, >=1.2.0-rc.0"), [1]);
+}
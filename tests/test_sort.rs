@@ -0,0 +1,44 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_sort() {
+    let mut all = versions(&["1.0.0+b", "1.0.0", "1.0.0+a", "0.1.0", "1.0.0-rc.1"]);
+    semver::sort(&mut all);
+    assert_eq!(
+        all,
+        versions(&["0.1.0", "1.0.0-rc.1", "1.0.0", "1.0.0+a", "1.0.0+b"]),
+    );
+}
+
+#[test]
+fn test_sort_by_precedence() {
+    let mut all = versions(&["1.0.0+b", "1.0.0", "1.0.0+a", "0.1.0", "1.0.0-rc.1"]);
+    semver::sort_by_precedence(&mut all);
+    assert_eq!(
+        all,
+        versions(&["0.1.0", "1.0.0-rc.1", "1.0.0+b", "1.0.0", "1.0.0+a"]),
+    );
+}
+
+#[test]
+fn test_sort_strs() {
+    let mut strings = ["1.0.0-beta.11", "1.0.0+b", "1.0.0-beta.2", "1.0.0", "0.1.0"];
+    semver::sort_strs(&mut strings).unwrap();
+    assert_eq!(
+        strings,
+        ["0.1.0", "1.0.0-beta.2", "1.0.0-beta.11", "1.0.0+b", "1.0.0"],
+    );
+
+    let mut strings = ["1.0.0", "0.1.0", "1.0", "x"];
+    let (index, error) = semver::sort_strs(&mut strings).unwrap_err();
+    assert_eq!(index, 2);
+    assert_to_string(
+        error,
+        "unexpected end of input while parsing minor version number",
+    );
+    assert_eq!(strings, ["1.0.0", "0.1.0", "1.0", "x"]);
+}
@@ -0,0 +1,59 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{SqlColumns, SqlDialect};
+
+#[test]
+fn test_caret() {
+    let sql = req("^1.2.3").to_sql_predicate(SqlDialect::Postgres, &SqlColumns::default());
+    assert_eq!(
+        sql,
+        r#"(("major" = 1 AND ("minor" > 2 OR ("minor" = 2 AND ("patch" > 3 OR ("patch" = 3 AND "pre" = '')))))) AND ("pre" = '')"#,
+    );
+}
+
+#[test]
+fn test_prerelease() {
+    let sql = req(">1.2.3-alpha").to_sql_predicate(SqlDialect::Sqlite, &SqlColumns::default());
+    assert_eq!(
+        sql,
+        r#"(("major" > 1 OR ("major" = 1 AND ("minor" > 2 OR ("minor" = 2 AND ("patch" > 3 OR ("patch" = 3 AND "pre_key" > '2alpha!0'))))))) AND ("pre" = '' OR ("major" = 1 AND "minor" = 2 AND "patch" = 3))"#,
+    );
+}
+
+#[test]
+fn test_numeric_prerelease() {
+    // Stored as pre = 'beta.10', pre_key = prerelease("beta.10").to_sort_key().
+    let beta10 = prerelease("beta.10").to_sort_key();
+    let beta2 = prerelease("beta.2").to_sort_key();
+    assert!(beta10 > beta2);
+
+    let sql = req(">1.0.0-beta.2").to_sql_predicate(SqlDialect::Postgres, &SqlColumns::default());
+    let bound = format!(r#""pre_key" > '{}'"#, beta2);
+    assert!(sql.contains(&bound), "{}", sql);
+    assert!(!sql.contains(r#""pre" >"#), "{}", sql);
+}
+
+#[test]
+fn test_any() {
+    let sql = req("*").to_sql_predicate(SqlDialect::Postgres, &SqlColumns::default());
+    assert_eq!(sql, r#"("pre" = '')"#);
+}
+
+#[test]
+fn test_columns() {
+    let columns = SqlColumns {
+        major: "v_major",
+        minor: "v_minor",
+        patch: "v_patch",
+        pre: "odd`name",
+        pre_key: "v_pre_key",
+    };
+    let sql = req("1.2.*").to_sql_predicate(SqlDialect::MySql, &columns);
+    assert_eq!(
+        sql,
+        "((`v_major` = 1 AND `v_minor` = 2 AND `odd``name` = '')) AND (`odd``name` = '')",
+    );
+}
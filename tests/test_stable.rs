@@ -0,0 +1,83 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{StableEncoding, Version, VersionReq};
+
+#[test]
+fn test_version_bytes() {
+    let encode = |text: &str| StableEncoding(version(text)).to_bytes();
+
+    assert_eq!(encode("0.0.0"), b"\x01\x00\x00\x00\x00\x00\x00");
+    assert_eq!(
+        encode("1.200.3-rc.1+g8e6aa2a"),
+        b"\x01\x00\x01\xc8\x01\x03\x04rc.1\x08g8e6aa2a",
+    );
+    assert_eq!(
+        encode("18446744073709551615.0.0"),
+        b"\x01\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01\x00\x00\x00\x00",
+    );
+}
+
+#[test]
+fn test_req_bytes() {
+    let encode = |text: &str| StableEncoding(req(text)).to_bytes();
+
+    assert_eq!(encode("*"), b"\x01\x01\x00");
+    assert_eq!(
+        encode("^1.2.3-beta, <2"),
+        b"\x01\x01\x02\x06\x01\x03\x02\x03\x04beta\x00\x03\x02\x00\x00\x00",
+    );
+    assert_eq!(encode("1.*"), b"\x01\x01\x01\x07\x01\x00\x00\x00");
+}
+
+#[test]
+fn test_round_trip() {
+    for text in &[
+        "0.0.0",
+        "1.2.3-alpha.1",
+        "1.2.3+build.5",
+        "3.0.0-rc.1+g8e6aa2a",
+    ] {
+        let bytes = StableEncoding(version(text)).to_bytes();
+        let decoded = StableEncoding::<Version>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.0, version(text));
+    }
+    for text in &["*", "1.*", "=1.2", ">=1.2.3-beta, <2.0.0", "~0.3, ^0.3.1"] {
+        let bytes = StableEncoding(req(text)).to_bytes();
+        let decoded = StableEncoding::<VersionReq>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.0, req(text));
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let version_err = |bytes: &[u8]| StableEncoding::<Version>::from_bytes(bytes).unwrap_err();
+    let req_err = |bytes: &[u8]| StableEncoding::<VersionReq>::from_bytes(bytes).unwrap_err();
+
+    let err = version_err(b"");
+    assert_to_string(err, "invalid or unsupported stable version encoding");
+
+    // Unknown format.
+    version_err(b"\x02\x00\x00\x00\x00\x00\x00");
+    // A requirement decoded as a version, and vice versa.
+    version_err(b"\x01\x01\x00");
+    req_err(b"\x01\x00\x00\x00\x00\x00\x00");
+    // Trailing bytes.
+    version_err(b"\x01\x00\x00\x00\x00\x00\x00\x00");
+    // Integer not in the fewest bytes.
+    version_err(b"\x01\x00\x80\x00\x00\x00\x00\x00");
+    // Invalid pre-release.
+    version_err(b"\x01\x00\x00\x00\x00\x02\x30\x31\x00");
+    // Patch without minor.
+    req_err(b"\x01\x01\x01\x00\x01\x02\x03\x00\x00");
+    // Pre-release without patch.
+    req_err(b"\x01\x01\x01\x00\x01\x01\x02\x04beta\x00");
+    // Build metadata on a comparator.
+    req_err(b"\x01\x01\x01\x02\x01\x03\x02\x03\x00\x02ab");
+    // Wildcard with minor and patch.
+    req_err(b"\x01\x01\x01\x07\x01\x03\x02\x03\x00\x00");
+    // Unknown operator.
+    req_err(b"\x01\x01\x01\x08\x01\x00\x00\x00");
+}
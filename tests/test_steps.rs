@@ -0,0 +1,43 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::Version;
+
+fn strings(iter: impl Iterator<Item = Version>) -> Vec<String> {
+    iter.map(|version| version.to_string()).collect()
+}
+
+#[test]
+fn test_iter_patches_to() {
+    let start = version("1.4.1-rc.2+build");
+    assert_eq!(
+        strings(start.iter_patches_to(&version("1.4.3"))),
+        ["1.4.1", "1.4.2", "1.4.3"],
+    );
+    assert_eq!(
+        strings(start.iter_patches_to(&version("1.4.1-alpha"))),
+        ["1.4.1"],
+    );
+    assert_eq!(start.iter_patches_to(&version("1.4.0")).count(), 0);
+    assert_eq!(start.iter_patches_to(&version("1.5.9")).count(), 0);
+
+    let iter = version("0.0.5").iter_patches_to(&version("0.0.9"));
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+
+    let max = version(&format!("2.0.{}", std::u64::MAX));
+    let tail = version(&format!("2.0.{}", std::u64::MAX - 1)).iter_patches_to(&max);
+    assert_eq!(tail.count(), 2);
+}
+
+#[test]
+fn test_iter_minors_to() {
+    let start = version("1.70.2");
+    assert_eq!(
+        strings(start.iter_minors_to(&version("1.73.0-beta.1"))),
+        ["1.70.0", "1.71.0", "1.72.0", "1.73.0"],
+    );
+    assert_eq!(start.iter_minors_to(&version("1.69.9")).count(), 0);
+    assert_eq!(start.iter_minors_to(&version("2.0.0")).count(), 0);
+}
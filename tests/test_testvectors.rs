@@ -0,0 +1,58 @@
+#![cfg(feature = "testvectors")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::testvectors::{MATCH_CASES, NPM_MATCH_CASES, ORDERING_CASES};
+
+#[test]
+fn test_match_cases() {
+    for case in MATCH_CASES {
+        let matches = req(case.req).matches(&version(case.version));
+        assert_eq!(matches, case.matches, "{} {}", case.req, case.version);
+    }
+}
+
+#[test]
+fn test_ordering_cases() {
+    for case in ORDERING_CASES {
+        let lhs = version(case.lhs);
+        let rhs = version(case.rhs);
+        assert_eq!(lhs.cmp(&rhs), case.ordering, "{} {}", case.lhs, case.rhs);
+        assert_eq!(rhs.cmp(&lhs), case.ordering.reverse());
+        assert_eq!(lhs.cmp_precedence(&rhs), case.precedence);
+    }
+}
+
+#[test]
+fn test_npm_match_cases() {
+    for case in NPM_MATCH_CASES {
+        version(case.version);
+    }
+}
+
+#[test]
+#[cfg(test_node_semver)]
+fn test_npm_match_cases_node() {
+    use std::process::Command;
+
+    for case in NPM_MATCH_CASES {
+        let out = Command::new("node")
+            .arg("-e")
+            .arg(format!(
+                "console.log(require('semver').satisfies('{}', '{}'))",
+                case.version, case.req,
+            ))
+            .output()
+            .unwrap();
+        let expected = if case.matches { "true\n" } else { "false\n" };
+        assert_eq!(
+            out.stdout,
+            expected.as_bytes(),
+            "{} {}",
+            case.req,
+            case.version
+        );
+    }
+}
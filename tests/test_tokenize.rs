@@ -0,0 +1,87 @@
+use semver::parser::Span;
+use semver::{tokenize, TokenKind};
+
+fn tokens(input: &str) -> Vec<(TokenKind, &str)> {
+    tokenize(input)
+        .map(|token| (token.kind, &input[token.span.start..token.span.end]))
+        .collect()
+}
+
+#[test]
+fn test_version() {
+    assert_eq!(
+        tokens("1.0.0-alpha.1+build.5"),
+        [
+            (TokenKind::Number, "1"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Number, "0"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Number, "0"),
+            (TokenKind::Hyphen, "-"),
+            (TokenKind::Prerelease, "alpha"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Prerelease, "1"),
+            (TokenKind::Plus, "+"),
+            (TokenKind::Build, "build"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Build, "5"),
+        ],
+    );
+}
+
+#[test]
+fn test_req() {
+    assert_eq!(
+        tokens(">= 1.2.x, <=2 , ~3-x-y"),
+        [
+            (TokenKind::Operator, ">="),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Number, "1"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Number, "2"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Wildcard, "x"),
+            (TokenKind::Separator, ","),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Operator, "<="),
+            (TokenKind::Number, "2"),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Separator, ","),
+            (TokenKind::Whitespace, " "),
+            (TokenKind::Operator, "~"),
+            (TokenKind::Number, "3"),
+            (TokenKind::Hyphen, "-"),
+            (TokenKind::Prerelease, "x-y"),
+        ],
+    );
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(
+        tokens("v1.é!"),
+        [
+            (TokenKind::Invalid, "v"),
+            (TokenKind::Number, "1"),
+            (TokenKind::Dot, "."),
+            (TokenKind::Invalid, "é"),
+            (TokenKind::Invalid, "!"),
+        ],
+    );
+    assert_eq!(tokenize("").count(), 0);
+}
+
+#[test]
+fn test_spans_cover_input() {
+    for input in &["^1.2.3-rc.1+meta, <2", "  =1 ,, *", "1.2.3-+x", "1 - 2"] {
+        let mut end = 0;
+        for token in tokenize(input) {
+            assert_eq!(token.span.start, end, "{:?}", input);
+            assert!(token.span.end > token.span.start);
+            end = token.span.end;
+        }
+        assert_eq!(end, input.len());
+    }
+    let last = tokenize("1.0").last().unwrap();
+    assert_eq!(last.span, Span { start: 2, end: 3 });
+}
@@ -0,0 +1,42 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_banners() {
+    for &(output, expected) in &[
+        ("git version 2.43.0\n", "2.43.0"),
+        ("git version 2.39.3 (Apple Git-145)\n", "2.39.3"),
+        ("git version 2.45.1.windows.1\n", "2.45.1"),
+        ("v20.11.1\n", "20.11.1"),
+        ("Python 3.12.2\n", "3.12.2"),
+        ("Python 3.13.0rc1\n", "3.13.0-rc1"),
+        (
+            "cmake version 3.28.3\n\nCMake suite maintained and supported by Kitware (kitware.com/cmake).\n",
+            "3.28.3",
+        ),
+        ("go version go1.22.0 linux/amd64\n", "1.22.0"),
+        ("gcc (GCC) 13.2.1 20231205 (Red Hat 13.2.1-6)\n", "13.2.1"),
+        ("rustc 1.78.0-nightly (9c3ad802d 2024-03-07)\n", "1.78.0-nightly"),
+        ("tool 1.2.3-rc.1+b5", "1.2.3-rc.1+b5"),
+        ("tool 1.02", "1.2.0"),
+        ("GNU Make 4.4\nBuilt for x86_64-pc-linux-gnu\n", "4.4.0"),
+        ("tool 1.2.3-_weird", "1.2.3"),
+    ] {
+        assert_eq!(
+            semver::from_tool_output(output),
+            Some(version(expected)),
+            "{}",
+            output,
+        );
+    }
+}
+
+#[test]
+fn test_none() {
+    for output in &["", "tool version unknown\n", "build 42\n", "v.1.2"] {
+        assert_eq!(semver::from_tool_output(output), None, "{}", output);
+    }
+}
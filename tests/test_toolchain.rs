@@ -0,0 +1,82 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{RustChannel, RustToolchain};
+
+#[test]
+fn test_short() {
+    let toolchain = semver::rust_toolchain("rustc 1.78.0 (9b00956e5 2024-04-29)\n").unwrap();
+    assert_eq!(
+        toolchain,
+        RustToolchain {
+            version: version("1.78.0"),
+            channel: RustChannel::Stable,
+            commit_hash: Some("9b00956e5".to_owned()),
+            commit_date: Some("2024-04-29".to_owned()),
+            host: None,
+        },
+    );
+
+    let toolchain = semver::rust_toolchain("1.79.0-beta.3 (a1b2c3d4e 2024-05-10)").unwrap();
+    assert_eq!(toolchain.version, version("1.79.0-beta.3"));
+    assert_eq!(toolchain.channel, RustChannel::Beta);
+
+    let toolchain =
+        semver::rust_toolchain("rustc 1.78.0 (9b00956e5 2024-04-29) (Fedora 1.78.0-1.fc40)")
+            .unwrap();
+    assert_eq!(
+        toolchain.commit_hash.as_ref().map(String::as_str),
+        Some("9b00956e5")
+    );
+
+    let toolchain = semver::rust_toolchain("rustc 1.80.0-dev").unwrap();
+    assert_eq!(toolchain.channel, RustChannel::Dev);
+    assert_eq!(toolchain.commit_hash, None);
+    assert_eq!(toolchain.commit_date, None);
+}
+
+#[test]
+fn test_verbose() {
+    let text = "\
+rustc 1.78.0-nightly (9c3ad802d 2024-03-07)
+binary: rustc
+commit-hash: 9c3ad802d9b9633d60d3a74668eb1be819212d34
+commit-date: 2024-03-07
+host: x86_64-unknown-linux-gnu
+release: 1.78.0-nightly
+LLVM version: 18.1.0
+";
+    let toolchain = semver::rust_toolchain(text).unwrap();
+    assert_eq!(
+        toolchain,
+        RustToolchain {
+            version: version("1.78.0-nightly"),
+            channel: RustChannel::Nightly,
+            commit_hash: Some("9c3ad802d9b9633d60d3a74668eb1be819212d34".to_owned()),
+            commit_date: Some("2024-03-07".to_owned()),
+            host: Some("x86_64-unknown-linux-gnu".to_owned()),
+        },
+    );
+
+    let text = "rustc 1.78.0\ncommit-hash: unknown\ncommit-date: unknown\n";
+    let toolchain = semver::rust_toolchain(text).unwrap();
+    assert_eq!(toolchain.commit_hash, None);
+    assert_eq!(toolchain.commit_date, None);
+}
+
+#[test]
+fn test_errors() {
+    let err = semver::rust_toolchain("rustc 1.78 (9b00956e5 2024-04-29)").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected end of input while parsing minor version number",
+    );
+
+    let err = semver::rust_toolchain("rustc 1.78.0-alpha").unwrap_err();
+    assert_to_string(err, "unrecognized Rust release channel in rustc version");
+
+    let err = semver::rust_toolchain("").unwrap_err();
+    assert_to_string(err, "empty string, expected a semver version");
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "utoipa")]
+
+use semver::{Version, VersionReq};
+use serde_json::json;
+use utoipa::{PartialSchema, ToSchema};
+
+#[test]
+fn test_version() {
+    assert_eq!(Version::name(), "Version");
+
+    let schema = serde_json::to_value(Version::schema()).unwrap();
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["format"], "semver");
+    assert_eq!(schema["examples"][0], "1.4.2");
+
+    let pattern = schema["pattern"].as_str().unwrap();
+    assert!(pattern.starts_with('^') && pattern.ends_with('$'));
+}
+
+#[test]
+fn test_version_req() {
+    assert_eq!(VersionReq::name(), "VersionReq");
+
+    let schema = serde_json::to_value(VersionReq::schema()).unwrap();
+    assert_eq!(schema["type"], json!("string"));
+    assert_eq!(schema["format"], json!("semver-req"));
+    assert_eq!(
+        schema["examples"],
+        json!(["^1.2", ">=1.2.3, <2", "~0.4.1", "*"])
+    );
+    assert!(schema.get("pattern").is_none());
+}
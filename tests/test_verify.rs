@@ -0,0 +1,52 @@
+#![cfg(feature = "verify")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[test]
+fn test_agrees_with_reference() {
+    let reqs = [
+        "*",
+        "1",
+        "^1.2",
+        "=1.2",
+        "~1.2, >=1.2.3-rc.1",
+        ">1.2",
+        ">=1.2, =1.2.0-rc.1",
+        "<1.2, >=1.2.0-rc.1",
+        "<=1.2",
+        "1.*, >=1.2.3-rc.1",
+        "^0.0.3",
+        "~1.2.3-rc.1",
+        ">1.2.3-rc.1, <=1.2.3",
+        ">18446744073709551615.18446744073709551615",
+    ];
+    let versions = [
+        "0.0.3",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.2.0-rc.1",
+        "1.2.0",
+        "1.2.3-rc.1",
+        "1.2.3-rc.2",
+        "1.2.3",
+        "1.3.0",
+        "2.0.0-rc.1",
+        "18446744073709551615.18446744073709551615.1",
+    ];
+
+    // Every call panics if the reference evaluation disagrees.
+    let mut matched = 0;
+    for text in &reqs {
+        let req = req(text);
+        for text in &versions {
+            matched += req.matches(&version(text)) as usize;
+        }
+    }
+    assert!(matched > 0);
+
+    assert!(req("~1.2, >=1.2.3-rc.1").matches(&version("1.2.3")));
+    assert!(!req("~1.2, >=1.2.3-rc.1").matches(&version("1.2.3-rc.2")));
+}
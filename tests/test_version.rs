@@ -8,6 +8,7 @@ mod util;
 
 use crate::util::*;
 use semver::{BuildMetadata, Prerelease, Version};
+use std::cmp::Ordering;
 
 #[test]
 fn test_parse() {
@@ -248,3 +249,236 @@ fn test_align() {
     assert_eq!("*****1.2.3-rc1******", format!("{:*^20}", version));
     assert_eq!("           1.2.3-rc1", format!("{:>20}", version));
 }
+
+#[test]
+fn test_debug() {
+    let version = version("1.2.3-rc.1+build.5");
+    assert_eq!(format!("{:?}", version), r#"Version("1.2.3-rc.1+build.5")"#);
+    assert_eq!(
+        format!("{:#?}", version),
+        r#"Version("1.2.3-rc.1+build.5")"#
+    );
+    // Older compilers print no trailing comma after the last element.
+    let nested = format!("{:#?}", [version]);
+    assert!(nested.contains("\n    Version(\"1.2.3-rc.1+build.5\")"));
+    assert_eq!(nested.lines().count(), 3);
+}
+
+#[test]
+fn test_strict_spec() {
+    for text in &["1.2.3", "1.2.3-alpha.1", "1.2.3+build.01", "0.0.0-0.a-b"] {
+        assert_eq!(Version::parse(text).unwrap().to_string(), *text);
+    }
+    for text in &["v1.2.3", "1.2", "1.2.3-beta_1", "1.2.3-01", " 1.2.3"] {
+        assert!(Version::parse(text).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn test_lines() {
+    let v = version("1.4.2");
+    assert!(v.same_minor_line(&version("1.4.0-rc.1+g8e6aa2a")));
+    assert!(!v.same_minor_line(&version("1.5.2")));
+    assert!(!v.same_minor_line(&version("2.4.2")));
+    assert!(v.same_major_line(&version("1.0.0-alpha")));
+    assert!(!v.same_major_line(&version("0.4.2")));
+
+    assert_eq!(v.cmp_minor_line(&version("1.4.9")), Ordering::Equal);
+    assert_eq!(v.cmp_minor_line(&version("1.10.0")), Ordering::Less);
+    assert_eq!(v.cmp_minor_line(&version("0.99.0")), Ordering::Greater);
+}
+
+#[test]
+fn test_precedence_key() {
+    let vs = [
+        "0.9.0",
+        "1.0.0-0",
+        "1.0.0-9",
+        "1.0.0-10",
+        "1.0.0-18446744073709551616",
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.0+build",
+        "1.0.1",
+        "1.10.0",
+    ];
+    for a in &vs {
+        for b in &vs {
+            let (a, b) = (version(a), version(b));
+            let (ka, kb) = (a.precedence_key(), b.precedence_key());
+            assert_eq!(ka.cmp(&kb), a.cmp_precedence(&b), "{} vs {}", a, b);
+            assert_eq!(ka == kb, a.cmp_precedence(&b) == Ordering::Equal);
+        }
+    }
+}
+
+#[test]
+fn test_delta() {
+    let delta = version("1.4.7-rc.1").delta(&version("1.6.0+build"));
+    assert_eq!((delta.major, delta.minor, delta.patch), (0, 2, -7));
+    assert_eq!(delta.distance(), 9);
+
+    let delta = version("3.0.0").delta(&version("1.2.3"));
+    assert_eq!((delta.major, delta.minor, delta.patch), (-2, 2, 3));
+    assert_eq!(delta.distance(), 7);
+
+    assert_eq!(
+        version("1.0.0-alpha").delta(&version("1.0.0")).distance(),
+        0
+    );
+
+    let max = Version::new(std::u64::MAX, std::u64::MAX, std::u64::MAX);
+    let delta = Version::new(0, 0, 0).delta(&max);
+    assert_eq!(delta.major, std::u64::MAX as i128);
+    assert_eq!(delta.distance(), 3 * std::u64::MAX as u128);
+    assert_eq!(
+        max.delta(&Version::new(0, 0, 0)).distance(),
+        3 * std::u64::MAX as u128
+    );
+}
+
+#[test]
+fn test_checked_setters() {
+    let mut v = version("1.2.3-alpha+build");
+    v.set_pre_str("beta.2").unwrap();
+    v.set_build_str("g8e6aa2a.dirty").unwrap();
+    assert_to_string(&v, "1.2.3-beta.2+g8e6aa2a.dirty");
+    assert!(v.validate().is_ok());
+
+    let err = v.set_pre_str("beta..2").unwrap_err();
+    assert_to_string(err, "empty identifier segment in pre-release identifier");
+    let err = v.set_build_str("a_b").unwrap_err();
+    assert_to_string(err, "unexpected character in build metadata");
+    assert_to_string(&v, "1.2.3-beta.2+g8e6aa2a.dirty");
+
+    v.set_pre_str("").unwrap();
+    v.set_build_str("").unwrap();
+    assert_to_string(&v, "1.2.3");
+    assert!(v.validate().is_ok());
+}
+
+#[test]
+fn test_satisfies() {
+    let v = version("1.4.2");
+    assert!(v.satisfies(&req("^1.2")));
+    assert!(!v.satisfies(&req(">=1.5")));
+    assert!(!version("1.5.0-rc.1").satisfies(&req("^1.2")));
+
+    assert!(v.satisfies_str("~1.4").unwrap());
+    assert!(!v.satisfies_str("~1.3").unwrap());
+    let err = v.satisfies_str("~1.x.3").unwrap_err();
+    assert_to_string(err, "unexpected character after wildcard in version req");
+}
+
+#[test]
+fn test_bump() {
+    let v = version("0.9.9-beta+exp.sha.5114f85");
+    assert_eq!(v.checked_bump_major().unwrap(), version("1.0.0"));
+    assert_eq!(v.checked_bump_minor().unwrap(), version("0.10.0"));
+    assert_eq!(v.checked_bump_patch().unwrap(), version("0.9.10"));
+
+    let max = std::u64::MAX;
+    let v = Version::new(max, max, max);
+    assert_eq!(v.checked_bump_major(), None);
+    assert_eq!(v.checked_bump_minor(), None);
+    assert_eq!(v.checked_bump_patch(), None);
+
+    let v = version("18446744073709551615.3.7-rc.1");
+    assert_eq!(v.saturating_bump_major(), Version::new(max, 3, 7));
+    assert_eq!(v.saturating_bump_minor(), Version::new(max, 4, 0));
+    assert_eq!(v.saturating_bump_patch(), Version::new(max, 3, 8));
+
+    let v = version("1.18446744073709551615.18446744073709551615+build");
+    assert_eq!(v.saturating_bump_minor(), Version::new(1, max, max));
+    assert_eq!(v.saturating_bump_patch(), Version::new(1, max, max));
+    assert_eq!(v.saturating_bump_major(), Version::new(2, 0, 0));
+}
+
+#[test]
+fn test_immediate_successor() {
+    let successor = |text: &str| version(text).immediate_successor().unwrap();
+    assert_eq!(successor("0.0.0"), version("0.0.1-0"));
+    assert_eq!(successor("1.2.3+build"), version("1.2.4-0"));
+    assert_eq!(successor("1.2.3-0"), version("1.2.3-0.0"));
+    assert_eq!(successor("1.2.3-alpha.1"), version("1.2.3-alpha.1.0"));
+    assert_eq!(successor("1.2.18446744073709551615"), version("1.3.0-0"));
+    assert_eq!(
+        successor("1.18446744073709551615.18446744073709551615"),
+        version("2.0.0-0"),
+    );
+
+    let max = std::u64::MAX;
+    assert_eq!(Version::new(max, max, max).immediate_successor(), None);
+    let v = version("18446744073709551615.18446744073709551615.18446744073709551615-rc");
+    assert_eq!(v.immediate_successor().unwrap().pre.as_str(), "rc.0");
+
+    for text in ["1.2.3", "1.2.3-rc", "1.2.3-rc.9", "1.2.3-1"].iter() {
+        let v = version(text);
+        let next = v.immediate_successor().unwrap();
+        assert_eq!(v.cmp_precedence(&next), Ordering::Less);
+    }
+}
+
+#[test]
+fn test_immediate_predecessor() {
+    let predecessor = |text: &str| version(text).immediate_predecessor();
+    assert_eq!(predecessor("1.2.3-0").unwrap(), version("1.2.2"));
+    assert_eq!(
+        predecessor("1.2.0-0").unwrap(),
+        version("1.1.18446744073709551615"),
+    );
+    assert_eq!(
+        predecessor("1.0.0-0").unwrap(),
+        version("0.18446744073709551615.18446744073709551615"),
+    );
+    assert_eq!(predecessor("1.2.3-0.0+build").unwrap(), version("1.2.3-0"));
+    assert_eq!(predecessor("1.2.3-rc.1.0").unwrap(), version("1.2.3-rc.1"));
+    assert_eq!(predecessor("0.0.0-0"), None);
+    assert_eq!(predecessor("1.2.3"), None);
+    assert_eq!(predecessor("1.2.3-rc.1"), None);
+    assert_eq!(predecessor("1.2.3-rc.10"), None);
+    assert_eq!(predecessor("1.2.3-rc0"), None);
+
+    for text in ["0.0.0", "1.2.3", "1.2.3-rc.1", "1.0.0-0"].iter() {
+        let v = version(text);
+        let next = v.immediate_successor().unwrap();
+        assert_eq!(next.immediate_predecessor().unwrap(), v);
+    }
+}
+
+#[test]
+fn test_promote() {
+    assert_eq!(version("1.4.0-rc.3").promote(), version("1.4.0"));
+    assert_eq!(version("1.4.0-rc.3+g1a2b").promote(), version("1.4.0"));
+    assert_eq!(version("1.4.0+g1a2b").promote(), version("1.4.0"));
+
+    let next = |text: &str, channel: &str| version(text).to_next_channel(channel);
+    assert_eq!(next("1.4.0-beta.2", "rc").unwrap(), version("1.4.0-rc.1"));
+    assert_eq!(
+        next("1.4.0-alpha+build", "beta").unwrap(),
+        version("1.4.0-beta.1")
+    );
+    assert_eq!(
+        next("1.4.0-beta.2", "beta.hotfix").unwrap(),
+        version("1.4.0-beta.hotfix.1")
+    );
+
+    let err = "pre-release channel does not come after the version's current one";
+    assert_to_string(next("1.4.0-rc.1", "beta").unwrap_err(), err);
+    assert_to_string(next("1.4.0-rc.3", "rc").unwrap_err(), err);
+    assert_to_string(next("1.4.0", "rc").unwrap_err(), err);
+    assert_to_string(
+        next("1.4.0-beta", "r_c").unwrap_err(),
+        "unexpected character in pre-release identifier",
+    );
+    assert_to_string(
+        next("1.4.0-beta", "").unwrap_err(),
+        "empty identifier segment in pre-release identifier",
+    );
+}
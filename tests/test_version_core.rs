@@ -0,0 +1,38 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Version, VersionCore};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_from_version() {
+    let core = VersionCore::from(&version("1.2.3-rc.1+g8e6aa2a"));
+    assert_eq!(core, VersionCore::new(1, 2, 3));
+    assert_eq!(Version::from(core), version("1.2.3"));
+    assert_eq!(core.to_string(), "1.2.3");
+    assert_eq!(format!("{:>8}", core), "   1.2.3");
+}
+
+#[test]
+fn test_ord() {
+    let mut map = BTreeMap::new();
+    for string in &["1.10.0", "1.2.3", "0.9.9", "1.2.3-beta", "1.2.10"] {
+        map.entry(VersionCore::from(&version(string)))
+            .or_insert_with(Vec::new)
+            .push(*string);
+    }
+    let keys: Vec<String> = map.keys().map(VersionCore::to_string).collect();
+    assert_eq!(keys, ["0.9.9", "1.2.3", "1.2.10", "1.10.0"]);
+    assert_eq!(map[&VersionCore::new(1, 2, 3)], ["1.2.3", "1.2.3-beta"]);
+}
+
+#[test]
+fn test_matches() {
+    let core = VersionCore::new(1, 2, 3);
+    assert!(core.matches(&comparator("^1.2")));
+    assert!(core.matches(&comparator("=1.2.3")));
+    assert!(!core.matches(&comparator("<1.2.3")));
+    assert!(!core.matches(&comparator("=1.2.3-rc.1")));
+}
@@ -9,8 +9,10 @@ mod node;
 mod util;
 
 use crate::util::*;
+use semver::{Comparator, ComparatorKind, VersionRange, WildcardPosition};
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+use std::ops::Bound;
 
 #[cfg(test_node_semver)]
 use node::{req, VersionReq};
@@ -48,6 +50,21 @@ fn test_default() {
     assert_eq!(r, &VersionReq::STAR);
 }
 
+#[test]
+fn test_any_of() {
+    let ref r = VersionReq::any_of_major(1);
+    assert_eq!(*r, req("1.*"));
+    assert_to_string(r, "1.*");
+    assert_match_all(r, &["1.0.0", "1.2.3", "1.99.99"]);
+    assert_match_none(r, &["0.9.9", "2.0.0", "1.2.3-beta"]);
+
+    let ref r = VersionReq::any_of_minor(1, 2);
+    assert_eq!(*r, req("1.2.*"));
+    assert_to_string(r, "1.2.*");
+    assert_match_all(r, &["1.2.0", "1.2.99"]);
+    assert_match_none(r, &["1.1.9", "1.3.0"]);
+}
+
 #[test]
 fn test_exact() {
     let ref r = req("=1.0.0");
@@ -483,3 +500,568 @@ fn test_wildcard_and_another() {
         "wildcard req (*) must be the only comparator in the version req",
     );
 }
+
+#[test]
+pub fn test_wildcard_position() {
+    for &(text, position) in &[
+        ("1.*", Some(WildcardPosition::Minor)),
+        ("1.x.X", Some(WildcardPosition::Minor)),
+        ("1.2.*", Some(WildcardPosition::Patch)),
+        ("1.2.x", Some(WildcardPosition::Patch)),
+        ("=1.2", None),
+        ("1.2", None),
+        ("^1", None),
+    ] {
+        let cmp = comparator(text);
+        assert_eq!(cmp.wildcard_position(), position, "{}", text);
+
+        let reparsed = comparator(&cmp.to_string());
+        assert_eq!(reparsed.wildcard_position(), position, "{}", text);
+    }
+}
+
+#[test]
+pub fn test_strict_spec() {
+    for text in &[
+        "*",
+        "1.*",
+        "1.2.*",
+        ">=1.2.3-beta.1, <2.0.0-0",
+        "~1.2",
+        "^0.1.2",
+    ] {
+        assert_eq!(VersionReq::parse_strict_spec(text).unwrap(), req(text));
+    }
+
+    let err = VersionReq::parse_strict_spec("1.2.x").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'x' while parsing patch version number",
+    );
+
+    let err = VersionReq::parse_strict_spec("X").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'X' while parsing major version number",
+    );
+
+    let err = VersionReq::parse_strict_spec("=1.2.3+build.5").unwrap_err();
+    assert_to_string(err, "unexpected character '+' after patch version number");
+
+    let err = VersionReq::parse_strict_spec("^1.2.3-rc+build.5").unwrap_err();
+    assert_to_string(err, "unexpected character '+' after pre-release identifier");
+
+    let err = VersionReq::parse_strict_spec(">= 1.2.3").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character ' ' while parsing major version number",
+    );
+}
+
+#[test]
+pub fn test_parse_with_default_op() {
+    let exact = semver::ParseConfig::new().default_op(semver::Op::Exact);
+    let ref r = VersionReq::parse_with("1.2.3", &exact).unwrap();
+    assert_to_string(r, "=1.2.3");
+    assert_match_all(r, &["1.2.3"]);
+    assert_match_none(r, &["1.2.4", "1.3.0"]);
+
+    let ref r = VersionReq::parse_with("1.2, ^1.2.5, 1.*", &exact).unwrap();
+    assert_to_string(r, "=1.2, ^1.2.5, 1.*");
+
+    let tilde = semver::ParseConfig::new().default_op(semver::Op::Tilde);
+    let ref r = VersionReq::parse_with("1.2.3", &tilde).unwrap();
+    assert_to_string(r, "~1.2.3");
+    assert_match_all(r, &["1.2.3", "1.2.9"]);
+    assert_match_none(r, &["1.3.0"]);
+    assert_eq!(tilde, semver::ParseConfig::pessimistic());
+
+    let default = semver::ParseConfig::new();
+    assert_eq!(
+        VersionReq::parse_with("1.2.3", &default).unwrap(),
+        req("1.2.3")
+    );
+}
+
+#[test]
+pub fn test_parse_pessimistic() {
+    let pessimistic = semver::ParseConfig::pessimistic();
+    let ref r = VersionReq::parse_with("0.4, >=1.0.2, <1.1", &pessimistic).unwrap();
+    assert_to_string(r, "~0.4, >=1.0.2, <1.1");
+
+    let ref r = VersionReq::parse_with("1.4.7, ^1", &pessimistic).unwrap();
+    assert_match_all(r, &["1.4.7", "1.4.12"]);
+    assert_match_none(r, &["1.5.0", "1.4.6"]);
+
+    let ref r = VersionReq::parse_with("1.*", &pessimistic).unwrap();
+    assert_match_all(r, &["1.0.0", "1.9.0"]);
+}
+
+#[test]
+pub fn test_canonical_string() {
+    for (text, canonical) in &[
+        ("*", "*"),
+        (">=0.0.0", "*"),
+        ("1.2.3", ">=1.2.3, <2.0.0-0"),
+        ("^0.2.3", ">=0.2.3, <0.3.0-0"),
+        ("^0.0.3", ">=0.0.3, <0.0.4-0"),
+        ("~1.2.3-beta", ">=1.2.3-beta, <1.3.0-0"),
+        ("~1", ">=1.0.0, <2.0.0-0"),
+        ("1.2.*", ">=1.2.0, <1.3.0-0"),
+        ("=1.2", ">=1.2.0, <1.3.0-0"),
+        ("=1.2.3-rc.1", "=1.2.3-rc.1"),
+        (">1.2", ">=1.3.0"),
+        (">1.2.3", ">1.2.3"),
+        (">=1", ">=1.0.0"),
+        ("<1.2", "<1.2.0"),
+        ("<=1.2", "<1.3.0-0"),
+        ("<=1.2.3", "<=1.2.3"),
+        ("<2, >=1.2.3, <2, >=1.2.3", ">=1.2.3, <2.0.0"),
+        (">=1.0.0, <1.0.0, =1.0.0", "=1.0.0, >=1.0.0, <1.0.0"),
+        ("^18446744073709551615", ">=18446744073709551615.0.0"),
+    ] {
+        assert_eq!(req(text).canonical_string(), *canonical, "{}", text);
+    }
+
+    assert_eq!(req("1.*").canonical_string(), req("=1").canonical_string());
+    assert_eq!(
+        req("^1.2, <1.8").canonical_string(),
+        req("<1.8.0, >=1.2.0, <2.0.0-0").canonical_string(),
+    );
+}
+
+#[test]
+pub fn test_debug() {
+    let ref r = req(">= 1.2, <2.0.0-0");
+    assert_eq!(format!("{:?}", r), r#"VersionReq(">=1.2, <2.0.0-0")"#);
+    assert_eq!(format!("{:#?}", r), r#"VersionReq(">=1.2, <2.0.0-0")"#);
+    assert_eq!(format!("{:?}", req("*")), r#"VersionReq("*")"#);
+
+    let cmp = comparator("~1.2.3-beta");
+    assert_eq!(format!("{:?}", cmp), r#"Comparator("~1.2.3-beta")"#);
+    assert_eq!(
+        format!("{:?}", r.comparators),
+        r#"[Comparator(">=1.2"), Comparator("<2.0.0-0")]"#,
+    );
+}
+
+#[test]
+pub fn test_parse_with_separators() {
+    use semver::{ParseConfig, Separator};
+
+    let npm = ParseConfig::new().separators(&[Separator::Whitespace]);
+    let ref r = VersionReq::parse_with(">=1.2.3  <2.0.0-0 ~1", &npm).unwrap();
+    assert_to_string(r, ">=1.2.3, <2.0.0-0, ~1");
+    let ref r = VersionReq::parse_with(">= 1.2.3 < 2", &npm).unwrap();
+    assert_to_string(r, ">=1.2.3, <2");
+    let err = VersionReq::parse_with(">=1.2.3, <2", &npm).unwrap_err();
+    assert_to_string(err, "expected comma after patch version number, found ','");
+
+    let ampersand = ParseConfig::new().separators(&[Separator::DoubleAmpersand]);
+    let ref r = VersionReq::parse_with(">=1.2.3&&<2 &&  =1.5", &ampersand).unwrap();
+    assert_to_string(r, ">=1.2.3, <2, =1.5");
+    let err = VersionReq::parse_with(">=1.2.3 <2", &ampersand).unwrap_err();
+    assert_to_string(err, "expected comma after patch version number, found '<'");
+
+    let single = ParseConfig::new().separators(&[]);
+    assert!(VersionReq::parse_with(">=1.2.3", &single).is_ok());
+    assert!(VersionReq::parse_with(">=1.2.3, <2", &single).is_err());
+
+    let tight = ParseConfig::new().whitespace(false);
+    assert!(VersionReq::parse_with(">=1.2.3 , <2", &tight).is_ok());
+    let err = VersionReq::parse_with(">= 1.2.3", &tight).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character ' ' while parsing major version number",
+    );
+}
+
+#[test]
+pub fn test_matches_ignore_prerelease_case() {
+    let options = semver::MatchOptions::new().ignore_prerelease_case(true);
+
+    let ref r = req("=1.2.3-rc.1");
+    assert!(!r.matches(&version("1.2.3-RC.1")));
+    assert!(r.matches_with(&version("1.2.3-RC.1"), &options));
+
+    let ref r = req(">=1.2.3-Beta.2, <1.2.3-RC");
+    assert!(r.matches_with(&version("1.2.3-beta.10"), &options));
+    assert!(r.matches_with(&version("1.2.3-BETA.2"), &options));
+    assert!(!r.matches_with(&version("1.2.3-beta.1"), &options));
+    assert!(!r.matches_with(&version("1.2.3-rc"), &options));
+
+    let ref r = req("^1.2.3-ALPHA");
+    assert!(r.matches_with(&version("1.2.3-alpha.1"), &options));
+    let ref r = req("~1.2.3-ALPHA");
+    assert!(!r.matches_with(&version("1.2.3-Aaa"), &options));
+
+    let beta = options.channel(Some("beta"));
+    assert!(req("*").matches_with(&version("1.0.0-BETA.3"), &beta.include_prerelease(true)));
+}
+
+#[test]
+pub fn test_parse_with_build() {
+    let config = semver::ParseConfig::new();
+    let (r, build) =
+        VersionReq::parse_with_build(">=1.2.3+build.5, <2.0.0-rc+x, <3", &config).unwrap();
+    assert_eq!(build.len(), 3);
+    assert_eq!(build[0].as_str(), "build.5");
+    assert_eq!(build[1].as_str(), "x");
+    assert!(build[2].is_empty());
+    assert_to_string(&r, ">=1.2.3, <2.0.0-rc, <3");
+    assert_eq!(r, req(">=1.2.3, <2.0.0-rc, <3"));
+    assert_match_all(&r, &["1.2.3", "1.2.3+other", "1.9.0"]);
+
+    let (r, build) = VersionReq::parse_with_build("*", &config).unwrap();
+    assert_eq!(r, VersionReq::default());
+    assert!(build.is_empty());
+
+    let (cmp, build) = Comparator::parse_with_build("=1.2.3-rc+g8e6aa2a").unwrap();
+    assert_eq!(cmp, comparator("=1.2.3-rc"));
+    assert_eq!(build.as_str(), "g8e6aa2a");
+
+    let err = VersionReq::parse_with_build("1.2.3+", &config).unwrap_err();
+    assert_to_string(err, "empty identifier segment in build metadata");
+}
+
+#[test]
+pub fn test_display_with() {
+    use semver::{DisplayStyle, Separator};
+
+    let ref r = req(">=1.2.3-rc.1, <2, 1.*, ~0.4");
+    let default = DisplayStyle::new();
+    assert_eq!(r.display_with(&default).to_string(), r.to_string());
+
+    let style = DisplayStyle::new()
+        .separator(Separator::Whitespace)
+        .space_after_op(true);
+    assert_eq!(
+        r.display_with(&style).to_string(),
+        ">= 1.2.3-rc.1 < 2 1.* ~ 0.4",
+    );
+
+    let style = DisplayStyle::new().desugar(true);
+    assert_eq!(
+        r.display_with(&style).to_string(),
+        ">=1.2.3-rc.1, <2.0.0, >=1.0.0, <2.0.0-0, >=0.4.0, <0.5.0-0",
+    );
+
+    let star = DisplayStyle::new().desugar(true).space_after_op(true);
+    assert_eq!(req("*").display_with(&star).to_string(), "*");
+    assert_eq!(req("=1.2.3").display_with(&star).to_string(), "= 1.2.3");
+}
+
+#[test]
+pub fn test_parse_v_prefix() {
+    let config = semver::ParseConfig::new().v_prefix(true);
+    let ref r = VersionReq::parse_with("v1.2.3, >= V1.0, ~v0.4.1-rc", &config).unwrap();
+    assert_to_string(r, "^1.2.3, >=1.0, ~0.4.1-rc");
+
+    let err = VersionReq::parse_with("vv1", &config).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'v' while parsing major version number",
+    );
+    let err = VersionReq::parse_with(">=v", &config).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected end of input while parsing major version number",
+    );
+
+    let err = VersionReq::parse(">=v1.2.0").unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character 'v' while parsing major version number",
+    );
+}
+
+#[test]
+pub fn test_comparator_fragments() {
+    for &(text, op, version) in &[
+        ("=1.2.3", "=", "1.2.3"),
+        (">1.2", ">", "1.2"),
+        (">= 1.2.3-rc.1", ">=", "1.2.3-rc.1"),
+        ("<1", "<", "1"),
+        ("<=0.4", "<=", "0.4"),
+        ("~0.4.1", "~", "0.4.1"),
+        ("1.2.3", "^", "1.2.3"),
+        ("1.*", "", "1.*"),
+        ("1.2.x", "", "1.2.*"),
+    ] {
+        let cmp = comparator(text);
+        assert_eq!(cmp.op_str(), op, "{}", text);
+        assert_eq!(cmp.op.as_str(), op, "{}", text);
+        assert_eq!(cmp.version_str(), version, "{}", text);
+        assert_eq!(
+            format!("{}{}", cmp.op_str(), cmp.version_str()),
+            cmp.to_string(),
+        );
+    }
+}
+
+#[test]
+pub fn test_comparator_bounds() {
+    use std::ops::Bound::{self, Excluded, Included, Unbounded};
+
+    let v = |text: &str| version(text);
+    let cases: Vec<(&str, Bound<semver::Version>, Bound<semver::Version>)> = vec![
+        ("=1.2.3", Included(v("1.2.3")), Included(v("1.2.3"))),
+        ("=1.2", Included(v("1.2.0")), Excluded(v("1.3.0-0"))),
+        (">1.2.3-rc.1", Excluded(v("1.2.3-rc.1")), Unbounded),
+        (">1.2", Included(v("1.3.0")), Unbounded),
+        (">=1.2.3+build", Included(v("1.2.3")), Unbounded),
+        ("<1.2", Unbounded, Excluded(v("1.2.0"))),
+        ("<=1.2.3", Unbounded, Included(v("1.2.3"))),
+        ("<=1", Unbounded, Excluded(v("2.0.0-0"))),
+        ("~1.2.3", Included(v("1.2.3")), Excluded(v("1.3.0-0"))),
+        ("~1", Included(v("1.0.0")), Excluded(v("2.0.0-0"))),
+        ("^1.2.3", Included(v("1.2.3")), Excluded(v("2.0.0-0"))),
+        ("^0.2.3", Included(v("0.2.3")), Excluded(v("0.3.0-0"))),
+        ("^0.0.3", Included(v("0.0.3")), Excluded(v("0.0.4-0"))),
+        ("1.*", Included(v("1.0.0")), Excluded(v("2.0.0-0"))),
+    ];
+    for (text, lower, upper) in cases {
+        let cmp = comparator(text);
+        assert_eq!(cmp.lower_bound(), lower, "{}", text);
+        assert_eq!(cmp.upper_bound(), upper, "{}", text);
+    }
+}
+
+#[test]
+pub fn test_boundaries() {
+    use semver::BoundarySide::{Lower, Upper};
+
+    let summary = |text: &str| {
+        req(text)
+            .boundaries()
+            .iter()
+            .map(|event| {
+                (
+                    event.version.to_string(),
+                    event.inclusive,
+                    event.side,
+                    event.comparator,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(summary("*"), []);
+    assert_eq!(
+        summary(">=1.2.3+build, <=1.5"),
+        [
+            ("1.2.3".to_owned(), true, Lower, 0),
+            ("1.6.0-0".to_owned(), false, Upper, 1),
+        ],
+    );
+    assert_eq!(
+        summary("~1.4, >1.2.3-rc.1"),
+        [
+            ("1.2.3-rc.1".to_owned(), false, Lower, 1),
+            ("1.4.0".to_owned(), true, Lower, 0),
+            ("1.5.0-0".to_owned(), false, Upper, 0),
+        ],
+    );
+    assert_eq!(
+        summary("=1.0.0, =1.0.0"),
+        [
+            ("1.0.0".to_owned(), true, Lower, 0),
+            ("1.0.0".to_owned(), true, Upper, 0),
+            ("1.0.0".to_owned(), true, Lower, 1),
+            ("1.0.0".to_owned(), true, Upper, 1),
+        ],
+    );
+}
+
+#[test]
+fn test_comparator_classify() {
+    let kind = |text: &str| comparator(text).classify();
+
+    assert_eq!(kind("=1.2.3-rc.1"), ComparatorKind::ExactFull);
+    assert_eq!(kind("=1.2"), ComparatorKind::ExactPartial);
+    assert_eq!(kind("=1"), ComparatorKind::ExactPartial);
+    assert_eq!(kind(">1.2.3"), ComparatorKind::RangeLower);
+    assert_eq!(kind(">=1"), ComparatorKind::RangeLower);
+    assert_eq!(kind("<1.2"), ComparatorKind::RangeUpper);
+    assert_eq!(kind("<=1.2.3"), ComparatorKind::RangeUpper);
+    assert_eq!(kind("~1.2"), ComparatorKind::Tilde);
+    assert_eq!(kind("1.2.3"), ComparatorKind::Caret);
+    assert_eq!(kind("1.*"), ComparatorKind::Wildcard);
+}
+
+#[test]
+fn test_comparator_is_satisfiable() {
+    let max = u64::max_value();
+    let satisfiable = |text: &str| comparator(text).is_satisfiable();
+
+    assert!(satisfiable("<0.0.1"));
+    assert!(satisfiable("<=0.0.0"));
+    assert!(satisfiable("<0.0.0-beta"));
+    assert!(satisfiable("=0.0.0-0"));
+    assert!(satisfiable(">=0.0.0"));
+    assert!(satisfiable(&format!(">{}.{}.{}-rc", max, max, max)));
+    assert!(satisfiable(&format!("^{}", max)));
+
+    assert!(!satisfiable("<0.0.0"));
+    assert!(!satisfiable("<0.0"));
+    assert!(!satisfiable("<0"));
+    assert!(!satisfiable("<0.0.0-0"));
+    assert!(!satisfiable(&format!(">{}.{}.{}", max, max, max)));
+    assert!(!satisfiable(&format!(">{}.{}", max, max)));
+    assert!(!satisfiable(&format!(">{}", max)));
+}
+
+#[test]
+fn test_preview() {
+    let corpus = [
+        "0.9.0",
+        "1.2.0+b",
+        "1.2.0+a",
+        "1.2.5-rc.1",
+        "1.2.5-rc.2",
+        "1.2.5",
+        "2.0.0-alpha",
+    ]
+    .iter()
+    .map(|text| version(text))
+    .collect::<Vec<_>>();
+
+    let preview = req(">=1.2.5-rc.2, <2").preview(&corpus);
+    assert_eq!(preview.matched, 2);
+    assert_eq!(preview.newest, Some(version("1.2.5")));
+    assert_eq!(preview.oldest, Some(version("1.2.5-rc.2")));
+    assert_eq!(preview.excluded_prerelease, []);
+
+    let preview = req("^1.2").preview(&corpus);
+    assert_eq!(preview.matched, 3);
+    assert_eq!(preview.newest, Some(version("1.2.5")));
+    assert_eq!(preview.oldest, Some(version("1.2.0+b")));
+    assert_eq!(
+        preview.excluded_prerelease,
+        [version("1.2.5-rc.1"), version("1.2.5-rc.2")],
+    );
+
+    let preview = req("^3").preview(&corpus);
+    assert_eq!(preview.matched, 0);
+    assert_eq!(preview.newest, None);
+    assert_eq!(preview.oldest, None);
+}
+
+#[test]
+fn test_iter() {
+    let ref r = req(">=1.2.3, <2");
+    assert_eq!(r.len(), 2);
+    assert!(!r.is_empty());
+    assert!(VersionReq::default().is_empty());
+    assert_eq!(VersionReq::default().len(), 0);
+
+    let ops = r.iter().map(|cmp| cmp.op).collect::<Vec<_>>();
+    assert_eq!(ops, [semver::Op::GreaterEq, semver::Op::Less]);
+
+    let mut majors = Vec::new();
+    for cmp in r {
+        majors.push(cmp.major);
+    }
+    assert_eq!(majors, [1, 2]);
+
+    let rebuilt = r.clone().into_iter().collect::<VersionReq>();
+    assert_eq!(rebuilt, *r);
+}
+
+#[test]
+fn test_error_input() {
+    let err = req_err(">=1.2, <1.x.3");
+    assert_eq!(err.input(), None);
+
+    let config = semver::ParseConfig::new().keep_input(true);
+    let err = VersionReq::parse_with(">=1.2, <1.x.3", &config).unwrap_err();
+    assert_eq!(err.input(), Some(">=1.2, <1.x.3"));
+    assert_to_string(&err, "unexpected character after wildcard in version req");
+
+    let long = format!(">={}é", "1".repeat(61));
+    let err = VersionReq::parse_with(&long, &config).unwrap_err();
+    assert_eq!(err.input(), Some(&long[..63]));
+
+    let err = semver::Version::parse("1.2").unwrap_err().with_input("1.2");
+    assert_eq!(err.input(), Some("1.2"));
+}
+
+#[test]
+fn test_iter_matching() {
+    let matching = |req_text: &str, start: &str| -> Vec<String> {
+        req(req_text)
+            .iter_matching(version(start))
+            .take(5)
+            .map(|v| v.to_string())
+            .collect()
+    };
+    assert_eq!(matching("=1.2.3", "0.0.0"), ["1.2.3"]);
+    assert_eq!(
+        matching("~1.2.3", "1.2.5"),
+        ["1.2.5", "1.2.6", "1.2.7", "1.2.8", "1.2.9"]
+    );
+    assert_eq!(
+        matching(">=1.0.0-rc.1, <1.0.2", "0.1.0"),
+        ["1.0.0", "1.0.1"]
+    );
+    assert_eq!(matching(">1.2.3-rc.1, <1.2.5", "0.0.0"), ["1.2.3", "1.2.4"]);
+    assert_eq!(matching("<0.0.2", "0.0.0-alpha+build"), ["0.0.0", "0.0.1"]);
+    assert_eq!(matching("^1.2", "2.0.0"), Vec::<String>::new());
+    assert_eq!(matching(">1.2.3, <1.2.4", "0.0.0"), Vec::<String>::new());
+    assert_eq!(
+        matching(">=1.2.18446744073709551614", "0.0.0"),
+        [
+            "1.2.18446744073709551614",
+            "1.2.18446744073709551615",
+            "1.3.0",
+            "1.3.1",
+            "1.3.2"
+        ],
+    );
+    assert_eq!(
+        matching(
+            ">18446744073709551615.18446744073709551615.18446744073709551614",
+            "0.0.0"
+        ),
+        ["18446744073709551615.18446744073709551615.18446744073709551615"],
+    );
+}
+
+#[test]
+fn test_from_range() {
+    let v1 = version("1.2.0");
+    let v2 = version("1.5.0-rc.1+build");
+
+    assert_to_string(
+        VersionReq::from_range(v1.clone()..v2.clone()),
+        ">=1.2.0, <1.5.0-rc.1",
+    );
+    assert_to_string(
+        VersionReq::from_range(v1.clone()..=v2.clone()),
+        ">=1.2.0, <=1.5.0-rc.1",
+    );
+    assert_to_string(VersionReq::from_range(v1.clone()..), ">=1.2.0");
+    assert_to_string(VersionReq::from_range(..=v1.clone()), "<=1.2.0");
+    assert_to_string(VersionReq::from_range(..), "*");
+
+    let req = VersionReq::from_range((Bound::Excluded(v1.clone()), Bound::Unbounded));
+    assert_to_string(&req, ">1.2.0");
+    assert!(!req.matches(&v1));
+    assert!(req.matches(&version("1.2.1")));
+
+    let req = VersionReq::from_range(v1..v2);
+    assert!(req.matches(&version("1.4.9")));
+    assert!(req.matches(&version("1.5.0-beta")));
+    assert!(!req.matches(&version("1.4.9-beta")));
+    assert!(!req.matches(&version("1.5.0-rc.1")));
+
+    let range = comparator("^1.2").range();
+    assert_eq!(
+        range,
+        VersionRange::new(
+            Bound::Included(version("1.2.0")),
+            Bound::Excluded(version("2.0.0-0")),
+        ),
+    );
+    assert_to_string(VersionReq::from_range(range), ">=1.2.0, <2.0.0-0");
+}
@@ -8,6 +8,11 @@ pub(super) fn version(text: &str) -> Version {
     Version::parse(text).unwrap()
 }
 
+#[cfg_attr(not(no_track_caller), track_caller)]
+pub(super) fn versions(texts: &[&str]) -> Vec<Version> {
+    texts.iter().map(|text| version(text)).collect()
+}
+
 #[cfg_attr(not(no_track_caller), track_caller)]
 pub(super) fn version_err(text: &str) -> Error {
     Version::parse(text).unwrap_err()